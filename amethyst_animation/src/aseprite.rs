@@ -0,0 +1,196 @@
+//! Loading of sprite sheets and animations exported by Aseprite.
+
+use std::fmt;
+
+use amethyst_assets::{Result, ResultExt};
+use amethyst_renderer::{Sprite, SpriteSheet};
+use fnv::FnvHashMap;
+use serde::de::{Deserialize, Deserializer, MapAccess, SeqAccess, Visitor};
+use serde_json;
+
+use sprite::SpriteAnimation;
+
+/// A sprite sheet and its animations, loaded from an Aseprite export.
+#[derive(Debug, Clone)]
+pub struct AsepriteSheet {
+    /// The frames of the export, in order.
+    pub sheet: SpriteSheet,
+    /// An animation for each tag of the export, by tag name.
+    pub animations: FnvHashMap<String, SpriteAnimation>,
+}
+
+/// Load the JSON data exported by Aseprite alongside a sprite sheet image.
+///
+/// Both the "Array" and "Hash" JSON formats are supported. The sprite sheet uses
+/// `texture_index` as its index in the `MaterialTextureSet`, in which the image needs to be
+/// inserted. Frames keep their own durations, and tags play their frames in their direction.
+pub fn load(json: &[u8], texture_index: usize) -> Result<AsepriteSheet> {
+    let export: Export =
+        serde_json::from_slice(json).chain_err(|| "Failed to parse Aseprite JSON")?;
+    let (width, height) = (export.meta.size.w as f32, export.meta.size.h as f32);
+    let sprites = export
+        .frames
+        .0
+        .iter()
+        .map(|frame| Sprite {
+            left: frame.frame.x as f32 / width,
+            right: (frame.frame.x + frame.frame.w) as f32 / width,
+            top: frame.frame.y as f32 / height,
+            bottom: (frame.frame.y + frame.frame.h) as f32 / height,
+        })
+        .collect();
+    let sheet = SpriteSheet {
+        index: texture_index,
+        texture: None,
+        sprites,
+        names: FnvHashMap::default(),
+    };
+
+    let frame_count = export.frames.0.len();
+    let mut animations = FnvHashMap::default();
+    for tag in &export.meta.frame_tags {
+        if tag.from > tag.to || tag.to >= frame_count {
+            return Err(format!("Aseprite tag {:?} is out of the frame range", tag.name).into());
+        }
+        let frames = tag_frames(tag)
+            .into_iter()
+            .map(|index| (index, export.frames.0[index].duration as f32 / 1000.))
+            .collect::<Vec<_>>();
+        animations.insert(
+            tag.name.clone(),
+            SpriteAnimation::from_frames_timed(&sheet, &frames),
+        );
+    }
+
+    Ok(AsepriteSheet { sheet, animations })
+}
+
+/// Frame indices of a tag, in playing order.
+fn tag_frames(tag: &FrameTag) -> Vec<usize> {
+    let forward = (tag.from..tag.to + 1).collect::<Vec<_>>();
+    match tag.direction.as_str() {
+        "reverse" => forward.into_iter().rev().collect(),
+        "pingpong" => {
+            let back = forward[1..].iter().rev().skip(1).cloned().collect::<Vec<_>>();
+            forward.into_iter().chain(back).collect()
+        }
+        _ => forward,
+    }
+}
+
+#[derive(Deserialize)]
+struct Export {
+    frames: Frames,
+    meta: Meta,
+}
+
+#[derive(Deserialize)]
+struct Frame {
+    frame: Rect,
+    duration: u32,
+}
+
+#[derive(Deserialize)]
+struct Rect {
+    x: u32,
+    y: u32,
+    w: u32,
+    h: u32,
+}
+
+#[derive(Deserialize)]
+struct Size {
+    w: u32,
+    h: u32,
+}
+
+#[derive(Deserialize)]
+struct Meta {
+    size: Size,
+    #[serde(rename = "frameTags", default)]
+    frame_tags: Vec<FrameTag>,
+}
+
+#[derive(Deserialize)]
+struct FrameTag {
+    name: String,
+    from: usize,
+    to: usize,
+    #[serde(default)]
+    direction: String,
+}
+
+/// Frames from either an array, or a map keyed by file name which keeps the frame order.
+struct Frames(Vec<Frame>);
+
+impl<'de> Deserialize<'de> for Frames {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        struct FramesVisitor;
+
+        impl<'de> Visitor<'de> for FramesVisitor {
+            type Value = Frames;
+
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("an array or a map of frames")
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> ::std::result::Result<Frames, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let mut frames = Vec::new();
+                while let Some(frame) = seq.next_element()? {
+                    frames.push(frame);
+                }
+                Ok(Frames(frames))
+            }
+
+            fn visit_map<A>(self, mut map: A) -> ::std::result::Result<Frames, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut frames = Vec::new();
+                while let Some((_, frame)) = map.next_entry::<String, Frame>()? {
+                    frames.push(frame);
+                }
+                Ok(Frames(frames))
+            }
+        }
+
+        deserializer.deserialize_any(FramesVisitor)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    static JSON: &str = r#"{
+        "frames": {
+            "walk 0.aseprite": { "frame": { "x": 0, "y": 0, "w": 16, "h": 16 }, "duration": 125 },
+            "walk 1.aseprite": { "frame": { "x": 16, "y": 0, "w": 16, "h": 16 }, "duration": 250 },
+            "walk 2.aseprite": { "frame": { "x": 0, "y": 16, "w": 16, "h": 16 }, "duration": 500 }
+        },
+        "meta": {
+            "size": { "w": 32, "h": 32 },
+            "frameTags": [
+                { "name": "walk", "from": 0, "to": 2, "direction": "pingpong" }
+            ]
+        }
+    }"#;
+
+    #[test]
+    fn load_frames_and_tags() {
+        let export = load(JSON.as_bytes(), 2).unwrap();
+        assert_eq!(export.sheet.index, 2);
+        assert_eq!(export.sheet.sprites.len(), 3);
+        assert_eq!(export.sheet.sprites[1].left, 0.5);
+        assert_eq!(export.sheet.sprites[2].top, 0.5);
+
+        let walk = &export.animations["walk"];
+        assert_eq!(walk.offsets.input, vec![0., 0.125, 0.375, 0.875, 1.125]);
+    }
+}
@@ -2,13 +2,14 @@ use std::hash::Hash;
 use std::marker;
 
 use amethyst_assets::{AssetStorage, Handle};
-use amethyst_core::specs::{Component, DispatcherBuilder, World};
-use amethyst_core::{ECSBundle, Result};
+use amethyst_core::specs::{Component, World};
+use amethyst_core::{Disabled, ECSBundle, Result, ScheduleBuilder};
 use amethyst_renderer::JointTransforms;
+use shrev::EventChannel;
 
 use material::MaterialTextureSet;
-use resources::{Animation, AnimationControlSet, AnimationHierarchy, AnimationSampling,
-                AnimationSet, RestState, Sampler, SamplerControlSet};
+use resources::{Animation, AnimationControlSet, AnimationEnded, AnimationHierarchy,
+                AnimationSampling, AnimationSet, RestState, Sampler, SamplerControlSet};
 use skinning::{Joint, Skin, VertexSkinningSystem};
 use systems::{AnimationControlSystem, AnimationProcessor, SamplerInterpolationSystem,
               SamplerProcessor};
@@ -39,8 +40,8 @@ impl<'a, 'b, 'c> ECSBundle<'a, 'b> for VertexSkinningBundle<'c> {
     fn build(
         self,
         world: &mut World,
-        builder: DispatcherBuilder<'a, 'b>,
-    ) -> Result<DispatcherBuilder<'a, 'b>> {
+        builder: ScheduleBuilder<'a, 'b>,
+    ) -> Result<ScheduleBuilder<'a, 'b>> {
         world.register::<Joint>();
         world.register::<Skin>();
         world.register::<JointTransforms>();
@@ -95,13 +96,14 @@ where
     fn build(
         self,
         world: &mut World,
-        builder: DispatcherBuilder<'a, 'b>,
-    ) -> Result<DispatcherBuilder<'a, 'b>> {
+        builder: ScheduleBuilder<'a, 'b>,
+    ) -> Result<ScheduleBuilder<'a, 'b>> {
         world
             .res
             .entry()
             .or_insert_with(AssetStorage::<Sampler<T::Primitive>>::new);
         world.register::<SamplerControlSet<T>>();
+        world.register::<Disabled>();
         world.add_resource(MaterialTextureSet::default());
         Ok(builder
             .add(SamplerProcessor::<T::Primitive>::new(), "", &[])
@@ -114,12 +116,13 @@ where
 /// This will also add `SamplingBundle`, because it is a dependency of this bundle.
 ///
 /// Will add `AnimationControlSystem<T>` with the given name.
-/// Will also add `AnimationProcessor<T>`.
+/// Will also add `AnimationProcessor<T>`, and the `EventChannel<AnimationEnded<I>>` resource.
 ///
 /// ### Type parameters:
 ///
 /// - `I`: identifier type for running animations, only one animation can be run at the same time
-///        with the same id (per entity)
+///        with the same id (per entity). Any `Copy + Eq + Hash` type works, such as an enum of
+///        the animations of a character, see `get_animation_set`.
 /// - `T`: the component type that sampling should be applied to
 #[derive(Default)]
 pub struct AnimationBundle<'a, I, T> {
@@ -160,9 +163,13 @@ where
     fn build(
         self,
         world: &mut World,
-        mut builder: DispatcherBuilder<'a, 'b>,
-    ) -> Result<DispatcherBuilder<'a, 'b>> {
+        mut builder: ScheduleBuilder<'a, 'b>,
+    ) -> Result<ScheduleBuilder<'a, 'b>> {
         world.add_resource(AssetStorage::<Animation<T>>::new());
+        world
+            .res
+            .entry()
+            .or_insert_with(EventChannel::<AnimationEnded<I>>::new);
         world.register::<AnimationControlSet<I, T>>();
         world.register::<AnimationHierarchy<T>>();
         world.register::<RestState<T>>();
@@ -0,0 +1,221 @@
+//! Loading of animated GIFs as sprite animations.
+
+use amethyst_assets::{Result, ResultExt};
+use amethyst_renderer::{SpriteSheet, SpriteSheetBuilder, TextureData};
+use gif_decoder::{ColorOutput, Decoder, DisposalMethod, SetParameter};
+
+use sprite::SpriteAnimation;
+
+/// Delay used for frames without one, in seconds, like web browsers do.
+const DEFAULT_DELAY: f32 = 0.1;
+
+/// The frames of an animated GIF packed in a sprite sheet, and the animation playing them.
+#[derive(Debug, Clone)]
+pub struct GifSheet {
+    /// Texture data of the sprite sheet, with a sprite for each frame.
+    pub texture: TextureData,
+    /// The frames of the GIF, in order.
+    pub sheet: SpriteSheet,
+    /// Animation showing each frame for its delay.
+    pub animation: SpriteAnimation,
+}
+
+/// Decode an animated GIF into a sprite sheet and its animation.
+///
+/// The sprite sheet uses `texture_index` as its index in the `MaterialTextureSet`, in which the
+/// texture needs to be inserted once loaded. Each frame is composited over the previous ones
+/// following its disposal method, so the sprites are complete images, and transparent pixels of
+/// the GIF are transparent in the texture.
+///
+/// ## Example
+///
+/// ```rust,ignore
+/// let gif = gif::load(&bytes_of_spinner_gif, 0)?;
+/// let texture = loader.load_from_data(gif.texture, (), &texture_storage);
+/// texture_set.insert(0, texture);
+/// let animation = gif.animation.load(&loader, &sampler_storage, &animation_storage);
+/// get_animation_set(&mut controls, entity).add_animation(
+///     0,
+///     &animation,
+///     EndControl::Loop(None),
+///     1.0,
+///     AnimationCommand::Start,
+/// );
+/// ```
+pub fn load(bytes: &[u8], texture_index: usize) -> Result<GifSheet> {
+    let mut decoder = Decoder::new(bytes);
+    decoder.set(ColorOutput::RGBA);
+    let mut reader = decoder.read_info().chain_err(|| "Failed to read GIF header")?;
+    let (width, height) = (reader.width() as u32, reader.height() as u32);
+
+    let mut frames = Vec::new();
+    while let Some(frame) = reader
+        .read_next_frame()
+        .chain_err(|| "Failed to decode GIF frame")?
+    {
+        let disposal = match frame.dispose {
+            DisposalMethod::Background => Disposal::Background,
+            DisposalMethod::Previous => Disposal::Previous,
+            _ => Disposal::Keep,
+        };
+        frames.push(Frame {
+            left: frame.left as u32,
+            top: frame.top as u32,
+            width: frame.width as u32,
+            height: frame.height as u32,
+            pixels: frame.buffer.to_vec(),
+            disposal,
+            delay: frame.delay,
+        });
+    }
+    if frames.is_empty() {
+        return Err("GIF has no frames".into());
+    }
+
+    let images = composite(width, height, &frames);
+    let columns = (images.len() as f32).sqrt().ceil() as u32;
+    let (texture, sheet) = images
+        .into_iter()
+        .fold(SpriteSheetBuilder::new(width * columns), |builder, image| {
+            builder.with_sprite(width, height, image)
+        })
+        .build(texture_index);
+
+    let timed = frames
+        .iter()
+        .enumerate()
+        .map(|(index, frame)| (index, delay_seconds(frame.delay)))
+        .collect::<Vec<_>>();
+    let animation = SpriteAnimation::from_frames_timed(&sheet, &timed);
+    Ok(GifSheet {
+        texture,
+        sheet,
+        animation,
+    })
+}
+
+/// What happens to the area of a frame once it has been shown.
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum Disposal {
+    /// The next frame is drawn over it
+    Keep,
+    /// The area is cleared to transparent
+    Background,
+    /// The area is restored to what it was before the frame
+    Previous,
+}
+
+/// A decoded frame, covering a part of the GIF, with RGBA8 pixels.
+struct Frame {
+    left: u32,
+    top: u32,
+    width: u32,
+    height: u32,
+    pixels: Vec<u8>,
+    disposal: Disposal,
+    /// Delay in hundredths of a second
+    delay: u16,
+}
+
+fn delay_seconds(delay: u16) -> f32 {
+    if delay <= 1 {
+        DEFAULT_DELAY
+    } else {
+        delay as f32 / 100.
+    }
+}
+
+/// Draw the frames over each other, returning the full RGBA8 image shown for each frame.
+fn composite(width: u32, height: u32, frames: &[Frame]) -> Vec<Vec<u8>> {
+    let mut canvas = vec![0u8; (width * height * 4) as usize];
+    let mut images = Vec::with_capacity(frames.len());
+    for frame in frames {
+        let previous = match frame.disposal {
+            Disposal::Previous => Some(canvas.clone()),
+            _ => None,
+        };
+        let area = frame_area(width, height, frame);
+        for (x, y) in area.clone() {
+            let src = (((y - frame.top) * frame.width + x - frame.left) * 4) as usize;
+            // Transparent pixels of a frame show what is below them
+            if frame.pixels.get(src + 3).map(|&alpha| alpha != 0).unwrap_or(false) {
+                let dst = ((y * width + x) * 4) as usize;
+                canvas[dst..dst + 4].copy_from_slice(&frame.pixels[src..src + 4]);
+            }
+        }
+        images.push(canvas.clone());
+
+        match (frame.disposal, previous) {
+            (Disposal::Previous, Some(previous)) => canvas = previous,
+            (Disposal::Background, _) => {
+                for (x, y) in area {
+                    let dst = ((y * width + x) * 4) as usize;
+                    for byte in &mut canvas[dst..dst + 4] {
+                        *byte = 0;
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+    images
+}
+
+/// Pixels of the GIF covered by a frame.
+fn frame_area(width: u32, height: u32, frame: &Frame) -> Vec<(u32, u32)> {
+    let right = (frame.left + frame.width).min(width);
+    let bottom = (frame.top + frame.height).min(height);
+    (frame.top..bottom)
+        .flat_map(|y| (frame.left..right).map(move |x| (x, y)))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const RED: [u8; 4] = [255, 0, 0, 255];
+    const BLUE: [u8; 4] = [0, 0, 255, 255];
+    const CLEAR: [u8; 4] = [0, 0, 0, 0];
+
+    fn frame(left: u32, pixels: &[[u8; 4]], disposal: Disposal) -> Frame {
+        Frame {
+            left,
+            top: 0,
+            width: pixels.len() as u32,
+            height: 1,
+            pixels: pixels.iter().flat_map(|p| p.iter().cloned()).collect(),
+            disposal,
+            delay: 10,
+        }
+    }
+
+    fn pixels(image: &[u8]) -> Vec<[u8; 4]> {
+        image
+            .chunks(4)
+            .map(|p| [p[0], p[1], p[2], p[3]])
+            .collect()
+    }
+
+    #[test]
+    fn frames_follow_disposal_and_transparency() {
+        let frames = [
+            frame(0, &[RED, RED], Disposal::Keep),
+            frame(1, &[BLUE], Disposal::Previous),
+            frame(0, &[CLEAR, BLUE], Disposal::Background),
+            frame(0, &[CLEAR, CLEAR], Disposal::Keep),
+        ];
+        let images = composite(2, 1, &frames);
+        assert_eq!(pixels(&images[0]), vec![RED, RED]);
+        assert_eq!(pixels(&images[1]), vec![RED, BLUE]);
+        // The second frame was disposed, and transparent pixels keep the first frame.
+        assert_eq!(pixels(&images[2]), vec![RED, BLUE]);
+        assert_eq!(pixels(&images[3]), vec![CLEAR, CLEAR]);
+    }
+
+    #[test]
+    fn frames_without_delay_use_default() {
+        assert_eq!(delay_seconds(0), DEFAULT_DELAY);
+        assert_eq!(delay_seconds(25), 0.25);
+    }
+}
@@ -48,7 +48,9 @@
 extern crate amethyst_assets;
 extern crate amethyst_core;
 extern crate amethyst_renderer;
+extern crate amethyst_ui;
 extern crate fnv;
+extern crate gif as gif_decoder;
 extern crate hibitset;
 extern crate itertools;
 #[macro_use]
@@ -56,6 +58,8 @@ extern crate log;
 extern crate minterpolate;
 #[macro_use]
 extern crate serde;
+extern crate serde_json;
+extern crate shrev;
 
 #[macro_use]
 #[cfg(feature = "profiler")]
@@ -64,20 +68,27 @@ extern crate thread_profiler;
 pub use self::bundle::{AnimationBundle, SamplingBundle, VertexSkinningBundle};
 pub use self::material::{MaterialChannel, MaterialPrimitive, MaterialTextureSet};
 pub use self::resources::{Animation, AnimationCommand, AnimationControl, AnimationControlSet,
-                          AnimationHierarchy, AnimationSampling, AnimationSet, ApplyData,
-                          BlendMethod, ControlState, DeferStartRelation, EndControl, Sampler,
-                          SamplerControl, SamplerControlSet, StepDirection};
+                          AnimationEnded, AnimationHierarchy, AnimationSampling, AnimationSet,
+                          ApplyData, BlendMethod, ControlState, DeferStartRelation, EndControl,
+                          Sampler, SamplerControl, SamplerControlSet, StepDirection};
 pub use self::skinning::{Joint, Skin, VertexSkinningSystem};
+pub use self::sprite::{SpriteAnimation, SpriteClip, SpriteClips};
 pub use self::systems::{AnimationControlSystem, AnimationProcessor, SamplerInterpolationSystem,
                         SamplerProcessor};
 pub use self::transform::TransformChannel;
+pub use self::ui::{UiTintChannel, UiTransformChannel};
 pub use self::util::{get_animation_set, SamplerPrimitive};
 pub use minterpolate::{InterpolationFunction, InterpolationPrimitive};
 
+pub mod aseprite;
+pub mod gif;
+
 mod bundle;
 mod resources;
 mod skinning;
 mod systems;
 mod material;
+mod sprite;
 mod transform;
+mod ui;
 mod util;
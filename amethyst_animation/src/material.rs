@@ -2,7 +2,7 @@ use fnv::FnvHashMap;
 
 use amethyst_assets::Handle;
 use amethyst_core::specs::Fetch;
-use amethyst_renderer::{Material, Sprite, Texture, TextureOffset};
+use amethyst_renderer::{Material, Sprite, SpriteSheet, Texture, TextureOffset};
 use minterpolate::InterpolationPrimitive;
 
 use {AnimationSampling, ApplyData, BlendMethod};
@@ -45,43 +45,86 @@ impl MaterialTextureSet {
         self.textures.clear();
         self.texture_inverse.clear();
     }
+
+    /// Insert the texture of a sprite sheet at the index of the sheet.
+    ///
+    /// Does nothing if the sheet has no texture.
+    pub fn insert_sprite_sheet(&mut self, sheet: &SpriteSheet) {
+        if let Some(ref texture) = sheet.texture {
+            self.insert(sheet.index, texture.clone());
+        }
+    }
 }
 
 /// Sampler primitive for Material animations
-/// Note that material can only ever be animated with `Step`, or a panic will occur.
+/// Note that textures and offsets can only ever be animated with `Step`, or a panic will occur.
+/// Colors can be interpolated with any function.
 #[derive(Debug, Clone, Copy, Serialize, Deserialize)]
 pub enum MaterialPrimitive {
     Texture(usize),
     Offset((f32, f32), (f32, f32)),
+    Color([f32; 4]),
 }
 
 impl InterpolationPrimitive for MaterialPrimitive {
-    fn add(&self, _: &Self) -> Self {
-        panic!("Cannot add MaterialPrimitive")
+    fn add(&self, other: &Self) -> Self {
+        match (*self, *other) {
+            (MaterialPrimitive::Color(a), MaterialPrimitive::Color(b)) => {
+                MaterialPrimitive::Color([a[0] + b[0], a[1] + b[1], a[2] + b[2], a[3] + b[3]])
+            }
+            _ => panic!("Cannot add MaterialPrimitive"),
+        }
     }
 
-    fn sub(&self, _: &Self) -> Self {
-        panic!("Cannot sub MaterialPrimitive")
+    fn sub(&self, other: &Self) -> Self {
+        match (*self, *other) {
+            (MaterialPrimitive::Color(a), MaterialPrimitive::Color(b)) => {
+                MaterialPrimitive::Color([a[0] - b[0], a[1] - b[1], a[2] - b[2], a[3] - b[3]])
+            }
+            _ => panic!("Cannot sub MaterialPrimitive"),
+        }
     }
 
-    fn mul(&self, _: f32) -> Self {
-        panic!("Cannot mul MaterialPrimitive")
+    fn mul(&self, scalar: f32) -> Self {
+        match *self {
+            MaterialPrimitive::Color(c) => MaterialPrimitive::Color([
+                c[0] * scalar,
+                c[1] * scalar,
+                c[2] * scalar,
+                c[3] * scalar,
+            ]),
+            _ => panic!("Cannot mul MaterialPrimitive"),
+        }
     }
 
-    fn dot(&self, _: &Self) -> f32 {
-        panic!("Cannot dot MaterialPrimitive")
+    fn dot(&self, other: &Self) -> f32 {
+        match (*self, *other) {
+            (MaterialPrimitive::Color(a), MaterialPrimitive::Color(b)) => {
+                a[0] * b[0] + a[1] * b[1] + a[2] * b[2] + a[3] * b[3]
+            }
+            _ => panic!("Cannot dot MaterialPrimitive"),
+        }
     }
 
     fn magnitude2(&self) -> f32 {
-        panic!("Cannot magnitude2 MaterialPrimitive")
+        match *self {
+            MaterialPrimitive::Color(_) => self.dot(self),
+            _ => panic!("Cannot magnitude2 MaterialPrimitive"),
+        }
     }
 
     fn magnitude(&self) -> f32 {
-        panic!("Cannot magnitude MaterialPrimitive")
+        match *self {
+            MaterialPrimitive::Color(_) => self.magnitude2().sqrt(),
+            _ => panic!("Cannot magnitude MaterialPrimitive"),
+        }
     }
 
     fn normalize(&self) -> Self {
-        panic!("Cannot normalize MaterialPrimitive")
+        match *self {
+            MaterialPrimitive::Color(_) => self.mul(1. / self.magnitude()),
+            _ => panic!("Cannot normalize MaterialPrimitive"),
+        }
     }
 }
 
@@ -102,6 +145,7 @@ impl<'a> From<&'a Sprite> for MaterialPrimitive {
 pub enum MaterialChannel {
     AlbedoTexture,
     AlbedoOffset,
+    AlbedoColor,
     EmissionTexture,
     EmissionOffset,
     NormalTexture,
@@ -178,6 +222,9 @@ impl AnimationSampling for Material {
             (MaterialChannel::AlbedoOffset, MaterialPrimitive::Offset(u, v)) => {
                 self.albedo_offset = texture_offset(u, v)
             }
+            (MaterialChannel::AlbedoColor, MaterialPrimitive::Color(color)) => {
+                self.albedo_color = color
+            }
             (MaterialChannel::EmissionOffset, MaterialPrimitive::Offset(u, v)) => {
                 self.emission_offset = texture_offset(u, v)
             }
@@ -229,6 +276,7 @@ impl AnimationSampling for Material {
                 MaterialPrimitive::Texture(extra.index(&self.caveat).unwrap())
             }
             MaterialChannel::AlbedoOffset => offset(&self.albedo_offset),
+            MaterialChannel::AlbedoColor => MaterialPrimitive::Color(self.albedo_color),
             MaterialChannel::EmissionOffset => offset(&self.emission_offset),
             MaterialChannel::NormalOffset => offset(&self.normal_offset),
             MaterialChannel::MetallicOffset => offset(&self.metallic_offset),
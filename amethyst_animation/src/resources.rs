@@ -319,6 +319,9 @@ impl ControlState {
 #[derive(Debug, Clone)]
 pub enum EndControl {
     /// Loop the requested number of iterations, None = loop infinitely
+    ///
+    /// `Loop(Some(3))` plays the animation three times, then goes back to rest state like
+    /// `Normal`, sending an `AnimationEnded` event.
     Loop(Option<u32>),
     /// When duration of sampler/animation is reached, go back to rest state
     Normal,
@@ -326,6 +329,22 @@ pub enum EndControl {
     Stay,
 }
 
+/// Event sent to the `EventChannel<AnimationEnded<I>>` by the `AnimationControlSystem` when an
+/// animation reaches its end and is removed from the `AnimationControlSet` of the entity.
+///
+/// Aborted animations don't send it.
+///
+/// ### Type parameters:
+///
+/// - `I`: identifier type for running animations
+#[derive(Clone, Debug, PartialEq)]
+pub struct AnimationEnded<I> {
+    /// Entity of the `AnimationControlSet` that ran the animation
+    pub entity: Entity,
+    /// Id of the animation
+    pub id: I,
+}
+
 /// Control a single active sampler
 ///
 /// ### Type parameters:
@@ -465,13 +484,37 @@ where
         self.samplers
             .iter_mut()
             .filter(|t| t.control_id == control_id)
-            .for_each(|sampler| {
-                if let ControlState::Running(_) = sampler.state {
-                    sampler.state = ControlState::Running(dur);
-                }
+            .for_each(|sampler| match sampler.state {
+                ControlState::Running(_) => sampler.state = ControlState::Running(dur),
+                ControlState::Paused(_) => sampler.state = ControlState::Paused(dur),
+                _ => {}
             });
     }
 
+    /// Forcibly set the input value to the input value of the key frame at `index` of the longest
+    /// sampler, or of its last key frame if `index` is out of range
+    pub fn set_frame(
+        &mut self,
+        control_id: u64,
+        index: usize,
+        samplers: &AssetStorage<Sampler<T::Primitive>>,
+    ) where
+        T: AnimationSampling,
+    {
+        let input = self.samplers
+            .iter()
+            .filter(|t| t.control_id == control_id)
+            .filter_map(|t| samplers.get(&t.sampler))
+            .max_by_key(|sampler| sampler.input.len())
+            .and_then(|sampler| {
+                let last = sampler.input.len().saturating_sub(1);
+                sampler.input.get(index.min(last)).cloned()
+            });
+        if let Some(input) = input {
+            self.set_input(control_id, input);
+        }
+    }
+
     /// Check if a control set can be terminated
     pub fn check_termination(&self, control_id: u64) -> bool {
         self.samplers
@@ -521,6 +564,47 @@ where
             })
             .max_by(|a, b| a.partial_cmp(&b).unwrap_or(Ordering::Equal))
     }
+
+    /// Get the progress of the control set, between 0 and 1, relative to its longest sampler
+    pub fn get_progress(
+        &self,
+        control_id: u64,
+        samplers: &AssetStorage<Sampler<T::Primitive>>,
+    ) -> Option<f32> {
+        let mut controls = self.samplers
+            .iter()
+            .filter(|t| t.control_id == control_id)
+            .peekable();
+        if controls.peek().is_none() {
+            return None;
+        }
+        let mut duration = 0.;
+        let mut total = 0.;
+        let mut done = true;
+        for control in controls {
+            match control.state {
+                ControlState::Running(dur) | ControlState::Paused(dur) => {
+                    duration = f32::max(duration, duration_to_secs(dur));
+                    done = false;
+                }
+                ControlState::Done => (),
+                _ => done = false,
+            }
+            if let Some(last) = samplers
+                .get(&control.sampler)
+                .and_then(|s| s.input.last().cloned())
+            {
+                total = f32::max(total, last);
+            }
+        }
+        if done {
+            Some(1.)
+        } else if total > 0. {
+            Some((duration / total).min(1.))
+        } else {
+            Some(0.)
+        }
+    }
 }
 
 fn set_step_state<T>(
@@ -577,6 +661,9 @@ where
     Step(StepDirection),
     /// Forcibly set current interpolation point for the animation, value in seconds
     SetInputValue(f32),
+    /// Forcibly set current interpolation point for the animation to the input value of a key
+    /// frame of its longest sampler
+    SetFrame(usize),
     /// Set blend weights
     SetBlendWeights(Vec<(usize, T::Channel, f32)>),
     /// Pause the animation
@@ -610,6 +697,8 @@ where
     pub command: AnimationCommand<T>,
     /// Control the rate of animation, default is 1.0
     pub rate_multiplier: f32,
+    /// Progress of the animation, between 0 and 1, updated by the control system
+    pub(crate) progress: f32,
     m: marker::PhantomData<T>,
 }
 
@@ -631,6 +720,7 @@ where
             state,
             command,
             rate_multiplier,
+            progress: 0.,
             m: marker::PhantomData,
         }
     }
@@ -769,6 +859,23 @@ where
         self.set_command(id, AnimationCommand::SetInputValue(input));
     }
 
+    /// Jump to the given time of the animation, in seconds.
+    ///
+    /// Works on running and paused animations, a paused animation stays paused and is sampled at
+    /// the new time on the next frame.
+    pub fn set_time(&mut self, id: I, seconds: f32) {
+        self.set_input(id, seconds);
+    }
+
+    /// Jump to the key frame at `index` of the longest sampler of the animation, such as a frame of
+    /// a `SpriteAnimation`.
+    ///
+    /// Works on running and paused animations, a paused animation stays paused and is sampled at
+    /// the new frame on the next frame.
+    pub fn set_frame(&mut self, id: I, index: usize) {
+        self.set_command(id, AnimationCommand::SetFrame(index));
+    }
+
     /// Set blend weights
     pub fn set_blend_weight(&mut self, id: I, weights: Vec<(usize, T::Channel, f32)>) {
         self.set_command(id, AnimationCommand::SetBlendWeights(weights));
@@ -830,6 +937,43 @@ where
         });
     }
 
+    /// Queue an animation with the given id to start when the animation `after` ends, unless it
+    /// already exists.
+    ///
+    /// `after` can itself be queued, so several animations can be chained:
+    ///
+    /// ```rust,ignore
+    /// set.add_animation(
+    ///     Attack::Windup,
+    ///     &windup,
+    ///     EndControl::Normal,
+    ///     1.0,
+    ///     AnimationCommand::Start,
+    /// );
+    /// set.queue(Attack::Windup, Attack::Strike, &strike, EndControl::Normal, 1.0);
+    /// set.queue(Attack::Strike, Attack::Recovery, &recovery, EndControl::Normal, 1.0);
+    /// ```
+    ///
+    /// If `after` is not in the set, the animation starts right away.
+    pub fn queue(
+        &mut self,
+        after: I,
+        id: I,
+        animation: &Handle<Animation<T>>,
+        end: EndControl,
+        rate_multiplier: f32,
+    ) {
+        self.add_deferred_animation(
+            id,
+            animation,
+            end,
+            rate_multiplier,
+            AnimationCommand::Start,
+            after,
+            DeferStartRelation::End,
+        );
+    }
+
     /// Insert an animation directly
     pub fn insert(&mut self, id: I, control: AnimationControl<T>) {
         if let Some(_) = self.animations.iter().find(|a| a.0 == id) {
@@ -846,6 +990,45 @@ where
             false
         }
     }
+
+    /// Check if the animation with the given id is currently running
+    pub fn is_playing(&self, id: I) -> bool {
+        self.animations
+            .iter()
+            .find(|a| a.0 == id)
+            .map(|a| a.1.state.is_running())
+            .unwrap_or(false)
+    }
+
+    /// Check if the animation with the given id has finished.
+    ///
+    /// Finished animations are removed from the set, so this is also true for ids that are not in
+    /// the set at all.
+    pub fn is_finished(&self, id: I) -> bool {
+        match self.animations.iter().find(|a| a.0 == id) {
+            Some(&(_, ref control)) => control.state == ControlState::Done,
+            None => !self.deferred_animations
+                .iter()
+                .any(|a| a.animation_id == id),
+        }
+    }
+
+    /// Get the progress of the animation with the given id, between 0 and 1.
+    ///
+    /// Looping animations report the progress of the current loop. Returns `None` if there is no
+    /// animation with the given id in the set.
+    pub fn progress(&self, id: I) -> Option<f32> {
+        if let Some(&(_, ref control)) = self.animations.iter().find(|a| a.0 == id) {
+            Some(control.progress)
+        } else if self.deferred_animations
+            .iter()
+            .any(|a| a.animation_id == id)
+        {
+            Some(0.)
+        } else {
+            None
+        }
+    }
 }
 
 impl<I, T> Component for AnimationControlSet<I, T>
@@ -861,6 +1044,7 @@ where
 ///
 /// ### Type parameters:
 ///
+/// - `I`: identifier type of the animations, such as an enum of the animations of a character
 /// - `T`: the component type that the animation should be applied to
 pub struct AnimationSet<I, T>
 where
@@ -46,6 +46,11 @@ impl<'a> System<'a> for VertexSkinningSystem {
         }
 
         for (_id, skin) in (&self.updated, &skins).join() {
+            // Skip skins with deleted joints
+            if !joints_alive(skin, &joints, &transforms) {
+                continue;
+            }
+
             // Compute the joint transforms
             self.joint_matrices.clear();
             self.joint_matrices.extend(
@@ -77,7 +82,10 @@ impl<'a> System<'a> for VertexSkinningSystem {
 
         for (mesh_global, mut joint_transform) in (transforms.open().1, &mut matrices).join() {
             if let Some(global_inverse) = mesh_global.0.invert() {
-                let skin = skins.get(joint_transform.skin).unwrap();
+                let skin = match skins.get(joint_transform.skin) {
+                    Some(skin) if joints_alive(skin, &joints, &transforms) => skin,
+                    _ => continue,
+                };
                 let joint_matrices = skin.joints
                     .iter()
                     .map(|joint_entity| {
@@ -100,3 +108,13 @@ impl<'a> System<'a> for VertexSkinningSystem {
         }
     }
 }
+
+fn joints_alive(
+    skin: &Skin,
+    joints: &ReadStorage<Joint>,
+    transforms: &ReadStorage<GlobalTransform>,
+) -> bool {
+    skin.joints
+        .iter()
+        .all(|joint| joints.get(*joint).is_some() && transforms.get(*joint).is_some())
+}
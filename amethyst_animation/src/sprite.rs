@@ -0,0 +1,262 @@
+use amethyst_assets::{AssetStorage, Handle, Loader};
+use amethyst_core::specs::{Component, DenseVecStorage};
+use amethyst_renderer::{Material, SpriteSheet};
+use fnv::FnvHashMap;
+use minterpolate::InterpolationFunction;
+
+use material::{MaterialChannel, MaterialPrimitive};
+use resources::{Animation, AnimationCommand, AnimationControlSet, EndControl, Sampler};
+
+/// Samplers for a frame by frame `Material` animation of sprites in a sprite sheet.
+///
+/// The texture of the sprite sheet is looked up in the `MaterialTextureSet` by the index of the
+/// sheet, so it needs to be inserted there.
+#[derive(Debug, Clone)]
+pub struct SpriteAnimation {
+    /// Sampler setting the sprite sheet texture
+    pub texture: Sampler<MaterialPrimitive>,
+    /// Sampler setting the texture offset of each frame
+    pub offsets: Sampler<MaterialPrimitive>,
+}
+
+impl SpriteAnimation {
+    /// Create an animation showing each sprite for the same duration, in seconds.
+    ///
+    /// ## Panics
+    ///
+    /// If a sprite index is out of range for the sheet.
+    pub fn from_frames(sheet: &SpriteSheet, frames: &[usize], frame_duration: f32) -> Self {
+        let frames = frames
+            .iter()
+            .map(|&index| (index, frame_duration))
+            .collect::<Vec<_>>();
+        Self::from_frames_timed(sheet, &frames)
+    }
+
+    /// Create an animation from `(sprite_index, duration)` pairs, showing each sprite for its own
+    /// duration in seconds, as exported by tools like Aseprite.
+    ///
+    /// ## Panics
+    ///
+    /// If a sprite index is out of range for the sheet.
+    pub fn from_frames_timed(sheet: &SpriteSheet, frames: &[(usize, f32)]) -> Self {
+        let mut input = Vec::with_capacity(frames.len() + 1);
+        let mut output = Vec::with_capacity(frames.len() + 1);
+        let mut time = 0.;
+        for &(index, duration) in frames {
+            input.push(time);
+            output.push((&sheet.sprites[index]).into());
+            time += duration;
+        }
+        // Hold the last frame for its whole duration.
+        if let Some(&last) = output.last() {
+            input.push(time);
+            output.push(last);
+        }
+
+        let texture = MaterialPrimitive::Texture(sheet.index);
+        SpriteAnimation {
+            texture: Sampler {
+                input: vec![0., time],
+                function: InterpolationFunction::Step,
+                output: vec![texture, texture],
+            },
+            offsets: Sampler {
+                input,
+                function: InterpolationFunction::Step,
+                output,
+            },
+        }
+    }
+
+    /// Total duration of the animation, in seconds
+    pub fn duration(&self) -> f32 {
+        self.offsets.input.last().cloned().unwrap_or(0.)
+    }
+
+    /// Load the samplers and create the animation, targeting node 0.
+    pub fn load(
+        self,
+        loader: &Loader,
+        sampler_storage: &AssetStorage<Sampler<MaterialPrimitive>>,
+        animation_storage: &AssetStorage<Animation<Material>>,
+    ) -> Handle<Animation<Material>> {
+        let texture = loader.load_from_data(self.texture, (), sampler_storage);
+        let offsets = loader.load_from_data(self.offsets, (), sampler_storage);
+        let animation = Animation {
+            nodes: vec![
+                (0, MaterialChannel::AlbedoTexture, texture),
+                (0, MaterialChannel::AlbedoOffset, offsets),
+            ],
+        };
+        loader.load_from_data(animation, (), animation_storage)
+    }
+}
+
+/// A named range of frames of a sprite sheet, such as a row of a character sheet.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SpriteClip {
+    /// Name used to play the clip
+    pub name: String,
+    /// Index of the first sprite
+    pub start: usize,
+    /// Index of the last sprite, included. The frames play backwards if it is before `start`.
+    pub end: usize,
+    /// Frames per second
+    pub fps: f32,
+    /// Loop the clip, instead of staying on its last frame
+    pub looping: bool,
+}
+
+impl SpriteClip {
+    /// Create a new clip
+    pub fn new<N>(name: N, start: usize, end: usize, fps: f32, looping: bool) -> Self
+    where
+        N: Into<String>,
+    {
+        SpriteClip {
+            name: name.into(),
+            start,
+            end,
+            fps,
+            looping,
+        }
+    }
+
+    fn frames(&self) -> Vec<usize> {
+        if self.start <= self.end {
+            (self.start..self.end + 1).collect()
+        } else {
+            (self.end..self.start + 1).rev().collect()
+        }
+    }
+}
+
+/// Named clips of a sprite sheet, played by name on the `AnimationControlSet<usize, Material>`
+/// of the entity.
+///
+/// The clips are run with the index of the clip as animation id, in the order they were given to
+/// `SpriteClips::load`.
+///
+/// ## Example
+///
+/// ```rust,ignore
+/// let clips = SpriteClips::load(
+///     &sheet,
+///     &[
+///         SpriteClip::new("idle", 0, 3, 8., true),
+///         SpriteClip::new("walk", 4, 11, 12., true),
+///     ],
+///     &loader,
+///     &sampler_storage,
+///     &animation_storage,
+/// );
+///
+/// // Later, in a system
+/// clips.play("walk", get_animation_set(&mut controls, entity));
+/// ```
+#[derive(Debug, Clone)]
+pub struct SpriteClips {
+    names: FnvHashMap<String, usize>,
+    clips: Vec<(Handle<Animation<Material>>, EndControl)>,
+}
+
+impl SpriteClips {
+    /// Load an animation for each clip.
+    ///
+    /// ## Panics
+    ///
+    /// If a sprite index is out of range for the sheet.
+    pub fn load(
+        sheet: &SpriteSheet,
+        clips: &[SpriteClip],
+        loader: &Loader,
+        sampler_storage: &AssetStorage<Sampler<MaterialPrimitive>>,
+        animation_storage: &AssetStorage<Animation<Material>>,
+    ) -> Self {
+        let names = clips
+            .iter()
+            .enumerate()
+            .map(|(id, clip)| (clip.name.clone(), id))
+            .collect();
+        let clips = clips
+            .iter()
+            .map(|clip| {
+                let animation = SpriteAnimation::from_frames(sheet, &clip.frames(), 1. / clip.fps)
+                    .load(loader, sampler_storage, animation_storage);
+                let end = if clip.looping {
+                    EndControl::Loop(None)
+                } else {
+                    EndControl::Stay
+                };
+                (animation, end)
+            })
+            .collect();
+        SpriteClips { names, clips }
+    }
+
+    /// Get the animation id of a clip
+    pub fn id(&self, name: &str) -> Option<usize> {
+        self.names.get(name).cloned()
+    }
+
+    /// Get the animation of a clip
+    pub fn get(&self, name: &str) -> Option<&Handle<Animation<Material>>> {
+        self.id(name).map(|id| &self.clips[id].0)
+    }
+
+    /// Play the clip with the given name, aborting the other clips.
+    ///
+    /// Resumes the clip if it was paused. Returns false if there is no clip with this name.
+    pub fn play(&self, name: &str, controls: &mut AnimationControlSet<usize, Material>) -> bool {
+        let id = match self.id(name) {
+            Some(id) => id,
+            None => return false,
+        };
+        for other in (0..self.clips.len()).filter(|other| *other != id) {
+            controls.abort(other);
+        }
+        if controls.has_animation(id) {
+            controls.start(id);
+        } else {
+            let (ref animation, ref end) = self.clips[id];
+            controls.add_animation(id, animation, end.clone(), 1.0, AnimationCommand::Start);
+        }
+        true
+    }
+}
+
+impl Component for SpriteClips {
+    type Storage = DenseVecStorage<Self>;
+}
+
+#[cfg(test)]
+mod tests {
+    use amethyst_renderer::{Sprite, SpriteSheet};
+
+    use super::*;
+
+    #[test]
+    fn timed_frames_hold_last_frame() {
+        let sheet = SpriteSheet {
+            index: 3,
+            texture: None,
+            sprites: vec![
+                Sprite::from([0., 0.5, 0., 1.]),
+                Sprite::from([0.5, 1., 0., 1.]),
+            ],
+            names: FnvHashMap::default(),
+        };
+        let animation = SpriteAnimation::from_frames_timed(&sheet, &[(1, 0.25), (0, 0.5)]);
+        assert_eq!(animation.offsets.input, vec![0., 0.25, 0.75]);
+        assert_eq!(animation.offsets.output.len(), 3);
+        assert_eq!(animation.duration(), 0.75);
+        assert_eq!(animation.texture.input, vec![0., 0.75]);
+    }
+
+    #[test]
+    fn clip_frames_in_both_directions() {
+        assert_eq!(SpriteClip::new("walk", 2, 4, 10., true).frames(), vec![2, 3, 4]);
+        assert_eq!(SpriteClip::new("back", 4, 2, 10., false).frames(), vec![4, 3, 2]);
+    }
+}
@@ -3,14 +3,16 @@ use std::marker;
 use std::time::Duration;
 
 use amethyst_assets::{AssetStorage, Handle};
-use amethyst_core::specs::{Component, Entities, Entity, Fetch, Join, ReadStorage, System,
-                           WriteStorage};
+use amethyst_core::Disabled;
+use amethyst_core::specs::{Component, Entities, Entity, Fetch, FetchMut, Join, ReadStorage,
+                           System, WriteStorage};
 use amethyst_core::timing::secs_to_duration;
-use fnv::FnvHashMap;
+use fnv::{FnvHashMap, FnvHashSet};
 use minterpolate::InterpolationPrimitive;
+use shrev::EventChannel;
 
 use resources::{Animation, AnimationCommand, AnimationControl, AnimationControlSet,
-                AnimationHierarchy, AnimationSampling, ApplyData, ControlState,
+                AnimationEnded, AnimationHierarchy, AnimationSampling, ApplyData, ControlState,
                 DeferStartRelation, RestState, Sampler, SamplerControl, SamplerControlSet,
                 StepDirection};
 
@@ -20,6 +22,9 @@ use resources::{Animation, AnimationCommand, AnimationControl, AnimationControlS
 /// animations they describe. If an animation only targets a single node/entity, there is no need
 /// for `AnimationHierarchy`.
 ///
+/// Sends an `AnimationEnded` event to the `EventChannel<AnimationEnded<I>>` resource for each
+/// animation that reached its end.
+///
 /// ### Type parameters:
 ///
 /// - `I`: identifier type for running animations, only one animation can be run at the same time
@@ -35,6 +40,8 @@ where
     remove_ids: Vec<I>,
     state_set: FnvHashMap<I, f32>,
     deferred_start: Vec<(I, f32)>,
+    running_ids: FnvHashSet<u64>,
+    previous_ids: FnvHashSet<u64>,
 }
 
 impl<I, T> AnimationControlSystem<I, T>
@@ -48,6 +55,8 @@ where
             remove_ids: Vec::default(),
             state_set: FnvHashMap::default(),
             deferred_start: Vec::default(),
+            running_ids: FnvHashSet::default(),
+            previous_ids: FnvHashSet::default(),
         }
     }
 }
@@ -66,6 +75,8 @@ where
         ReadStorage<'a, AnimationHierarchy<T>>,
         ReadStorage<'a, T>,
         WriteStorage<'a, RestState<T>>,
+        ReadStorage<'a, Disabled>,
+        FetchMut<'a, EventChannel<AnimationEnded<I>>>,
         <T as ApplyData<'a>>::ApplyData,
     );
 
@@ -79,10 +90,12 @@ where
             hierarchies,
             transforms,
             mut rest_states,
+            disabled,
+            mut ended,
             apply_data,
         ) = data;
         let mut remove_sets = Vec::default();
-        for (entity, control_set) in (&*entities, &mut controls).join() {
+        for (entity, control_set, _) in (&*entities, &mut controls, !&disabled).join() {
             self.remove_ids.clear();
             self.state_set.clear();
             let hierarchy = hierarchies.get(entity);
@@ -110,12 +123,30 @@ where
                 if let AnimationCommand::Step(_) = control.command {
                     control.command = AnimationCommand::Start;
                 }
-                if let AnimationCommand::SetInputValue(_) = control.command {
-                    control.command = AnimationCommand::Start;
+                match control.command {
+                    AnimationCommand::SetInputValue(_) | AnimationCommand::SetFrame(_) => {
+                        control.command = if control.state.is_paused() {
+                            AnimationCommand::Pause
+                        } else {
+                            AnimationCommand::Start
+                        };
+                    }
+                    _ => {}
                 }
                 if remove {
+                    // Aborted animations are moved out of the running state before removal
+                    if control.state.is_running() {
+                        ended.single_write(AnimationEnded { entity, id: *id });
+                    }
                     self.remove_ids.push(*id);
                 } else {
+                    control.progress = get_progress(
+                        &entity,
+                        control,
+                        hierarchies.get(entity),
+                        &*sampler_storage,
+                        &samplers,
+                    );
                     self.state_set.insert(
                         *id,
                         get_running_duration(&entity, control, hierarchies.get(entity), &samplers),
@@ -193,6 +224,29 @@ where
         for entity in remove_sets {
             controls.remove(entity);
         }
+
+        // Samplers of animations whose control set went away without stopping them, because the
+        // controlling entity was deleted, would otherwise keep running on the hierarchy nodes.
+        self.running_ids.clear();
+        for control_set in (&controls).join() {
+            self.running_ids
+                .extend(control_set.animations.iter().map(|&(_, ref control)| control.id));
+        }
+        if self.previous_ids.difference(&self.running_ids).next().is_some() {
+            let mut empty = Vec::default();
+            for (entity, sampler_set) in (&*entities, &mut samplers).join() {
+                for id in self.previous_ids.difference(&self.running_ids) {
+                    sampler_set.clear(*id);
+                }
+                if sampler_set.is_empty() {
+                    empty.push(entity);
+                }
+            }
+            for entity in empty {
+                samplers.remove(entity);
+            }
+        }
+        ::std::mem::swap(&mut self.previous_ids, &mut self.running_ids);
     }
 }
 
@@ -216,6 +270,28 @@ where
     }
 }
 
+fn get_progress<T>(
+    entity: &Entity,
+    control: &AnimationControl<T>,
+    hierarchy: Option<&AnimationHierarchy<T>>,
+    sampler_storage: &AssetStorage<Sampler<T::Primitive>>,
+    samplers: &WriteStorage<SamplerControlSet<T>>,
+) -> f32
+where
+    T: AnimationSampling,
+{
+    match &control.state {
+        &ControlState::Running(_) | &ControlState::Paused(_) => samplers
+            .get(*hierarchy
+                .and_then(|h| h.nodes.values().next())
+                .unwrap_or(entity))
+            .and_then(|set| set.get_progress(control.id, sampler_storage))
+            .unwrap_or(0.),
+        &ControlState::Done => 1.,
+        _ => 0.,
+    }
+}
+
 fn find_max_duration<T>(control_id: u64, samplers: Option<&SamplerControlSet<T>>) -> f32
 where
     T: AnimationSampling,
@@ -359,11 +435,18 @@ where
             None
         }
 
-        (&ControlState::Running(..), &AnimationCommand::SetInputValue(value)) => {
+        (&ControlState::Running(..), &AnimationCommand::SetInputValue(value))
+        | (&ControlState::Paused(..), &AnimationCommand::SetInputValue(value)) => {
             set_animation_input(control.id, hierarchy, samplers, value);
             None
         }
 
+        (&ControlState::Running(..), &AnimationCommand::SetFrame(index))
+        | (&ControlState::Paused(..), &AnimationCommand::SetFrame(index)) => {
+            set_animation_frame(control.id, hierarchy, samplers, sampler_storage, index);
+            None
+        }
+
         (&ControlState::Running(..), &AnimationCommand::SetBlendWeights(ref weights)) => {
             set_blend_weights(control.id, hierarchy, samplers, weights);
             None
@@ -426,13 +509,15 @@ fn start_animation<T>(
 where
     T: AnimationSampling + Component + Clone,
 {
-    // check that hierarchy is valid, and all samplers exist
+    // check that hierarchy is valid, all node entities still have the animated component, and all
+    // samplers exist
     if animation
         .nodes
         .iter()
         .any(|&(ref node_index, _, ref sampler_handle)| {
-            hierarchy.nodes.get(node_index).is_none()
-                || sampler_storage.get(sampler_handle).is_none()
+            hierarchy.nodes.get(node_index).map_or(true, |node_entity| {
+                rest_states.get(*node_entity).is_none() && targets.get(*node_entity).is_none()
+            }) || sampler_storage.get(sampler_handle).is_none()
         }) {
         return false;
     }
@@ -537,6 +622,22 @@ fn set_animation_input<T>(
     }
 }
 
+fn set_animation_frame<T>(
+    control_id: u64,
+    hierarchy: &AnimationHierarchy<T>,
+    controls: &mut WriteStorage<SamplerControlSet<T>>,
+    sampler_storage: &AssetStorage<Sampler<T::Primitive>>,
+    index: usize,
+) where
+    T: AnimationSampling,
+{
+    for (_, node_entity) in &hierarchy.nodes {
+        if let Some(ref mut s) = controls.get_mut(*node_entity) {
+            s.set_frame(control_id, index, sampler_storage);
+        }
+    }
+}
+
 fn set_blend_weights<T>(
     control_id: u64,
     hierarchy: &AnimationHierarchy<T>,
@@ -2,8 +2,9 @@ use std::marker;
 use std::time::Duration;
 
 use amethyst_assets::AssetStorage;
-use amethyst_core::{duration_to_nanos, duration_to_secs, nanos_to_duration, secs_to_duration, Time};
-use amethyst_core::specs::{Component, Fetch, Join, System, WriteStorage};
+use amethyst_core::{duration_to_nanos, duration_to_secs, nanos_to_duration, secs_to_duration,
+                    Disabled, Time};
+use amethyst_core::specs::{Component, Fetch, Join, ReadStorage, System, WriteStorage};
 use itertools::Itertools;
 use minterpolate::InterpolationPrimitive;
 
@@ -53,11 +54,15 @@ where
         Fetch<'a, AssetStorage<Sampler<T::Primitive>>>,
         WriteStorage<'a, SamplerControlSet<T>>,
         WriteStorage<'a, T>,
+        ReadStorage<'a, Disabled>,
         <T as ApplyData<'a>>::ApplyData,
     );
 
-    fn run(&mut self, (time, samplers, mut control_sets, mut comps, apply_data): Self::SystemData) {
-        for (control_set, comp) in (&mut control_sets, &mut comps).join() {
+    fn run(
+        &mut self,
+        (time, samplers, mut control_sets, mut comps, disabled, apply_data): Self::SystemData,
+    ) {
+        for (control_set, comp, _) in (&mut control_sets, &mut comps, !&disabled).join() {
             self.inner.clear();
             for control in control_set.samplers.iter_mut() {
                 if let Some(ref sampler) = samplers.get(&control.sampler) {
@@ -207,22 +212,10 @@ where
                 // Check end conditions
                 match control.end {
                     // Do loop control
-                    EndControl::Loop(Some(i)) if i <= 1 => (Done, Some(EndControl::Normal)),
                     EndControl::Loop(None) => {
                         (Running(next_duration(last_frame, current_dur).0), None)
                     }
-                    EndControl::Loop(Some(i)) => {
-                        let (next_dur, loops_removed) = next_duration(last_frame, current_dur);
-                        let remaining_loops = i - loops_removed;
-                        if remaining_loops <= 1 {
-                            (Done, Some(EndControl::Normal))
-                        } else {
-                            (
-                                Running(next_dur),
-                                Some(EndControl::Loop(Some(remaining_loops))),
-                            )
-                        }
-                    }
+                    EndControl::Loop(Some(i)) => next_loop(i, last_frame, current_dur),
                     // All other end cases will be handled during sampling
                     _ => (Done, None),
                 }
@@ -237,6 +230,25 @@ where
     }
 }
 
+/// Count down the remaining loops of a sampler that went past its last frame, `remaining` being
+/// the number of loops left including the one that just ended.
+fn next_loop(
+    remaining: u32,
+    last_frame: Duration,
+    current_dur: Duration,
+) -> (ControlState, Option<EndControl>) {
+    let (next_dur, loops_removed) = next_duration(last_frame, current_dur);
+    let remaining_loops = remaining.saturating_sub(loops_removed);
+    if remaining_loops == 0 {
+        (ControlState::Done, Some(EndControl::Normal))
+    } else {
+        (
+            ControlState::Running(next_dur),
+            Some(EndControl::Loop(Some(remaining_loops))),
+        )
+    }
+}
+
 fn next_duration(last_frame: Duration, duration: Duration) -> (Duration, u32) {
     let animation_duration = duration_to_nanos(last_frame);
     let current_duration = duration_to_nanos(duration);
@@ -277,3 +289,36 @@ where
 {
     primitive.mul(*weight / total)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn loops_the_requested_number_of_times() {
+        let last_frame = Duration::from_millis(500);
+        let mut remaining = 3;
+        let mut cycles = 0;
+        loop {
+            cycles += 1;
+            match next_loop(remaining, last_frame, Duration::from_millis(600)) {
+                (ControlState::Running(dur), Some(EndControl::Loop(Some(i)))) => {
+                    assert_eq!(dur, Duration::from_millis(100));
+                    remaining = i;
+                }
+                (ControlState::Done, Some(EndControl::Normal)) => break,
+                _ => panic!("unexpected loop state"),
+            }
+        }
+        assert_eq!(cycles, 3);
+    }
+
+    #[test]
+    fn skipped_loops_do_not_underflow() {
+        let end = next_loop(1, Duration::from_millis(100), Duration::from_millis(350));
+        match end {
+            (ControlState::Done, Some(EndControl::Normal)) => {}
+            _ => panic!("loops should be over"),
+        }
+    }
+}
@@ -0,0 +1,126 @@
+use amethyst_ui::{UiTint, UiTransform};
+
+use resources::{AnimationSampling, ApplyData, BlendMethod};
+use util::SamplerPrimitive;
+
+/// Channels that can be animated on `UiTransform`
+///
+/// Both channels sample `SamplerPrimitive::Vec2` values in pixels, see `UiTransform`.
+///
+/// A menu sliding in from the left of the screen, with the `AnimationBundle::<u32, UiTransform>`:
+///
+/// ```rust,ignore
+/// let sampler = loader.load_from_data(
+///     Sampler {
+///         input: vec![0., 0.5],
+///         output: vec![
+///             SamplerPrimitive::Vec2([-200., 300.]),
+///             SamplerPrimitive::Vec2([200., 300.]),
+///         ],
+///         function: InterpolationFunction::Linear,
+///     },
+///     (),
+///     &sampler_storage,
+/// );
+/// let slide_in = loader.load_from_data(
+///     Animation::new_single(0, UiTransformChannel::Translation, sampler),
+///     (),
+///     &animation_storage,
+/// );
+/// get_animation_set::<u32, UiTransform>(&mut controls, menu).add_animation(
+///     0,
+///     &slide_in,
+///     EndControl::Stay,
+///     1.0,
+///     AnimationCommand::Start,
+/// );
+/// ```
+#[derive(Debug, Clone, Copy, Hash, Eq, PartialEq, Serialize, Deserialize)]
+pub enum UiTransformChannel {
+    /// The local position, `local_x` and `local_y`
+    Translation,
+    /// The `width` and `height`
+    Size,
+}
+
+impl<'a> ApplyData<'a> for UiTransform {
+    type ApplyData = ();
+}
+
+impl AnimationSampling for UiTransform {
+    type Primitive = SamplerPrimitive<f32>;
+    type Channel = UiTransformChannel;
+
+    fn apply_sample(&mut self, channel: &Self::Channel, data: &SamplerPrimitive<f32>, _: &()) {
+        use self::UiTransformChannel::*;
+        use util::SamplerPrimitive::*;
+        match (channel, *data) {
+            (&Translation, Vec2(ref d)) => {
+                self.local_x = d[0];
+                self.local_y = d[1];
+            }
+            (&Size, Vec2(ref d)) => {
+                self.width = d[0];
+                self.height = d[1];
+            }
+            _ => panic!("Attempt to apply invalid sample to UiTransform"),
+        }
+    }
+
+    fn current_sample(&self, channel: &Self::Channel, _: &()) -> SamplerPrimitive<f32> {
+        use self::UiTransformChannel::*;
+        match channel {
+            &Translation => SamplerPrimitive::Vec2([self.local_x, self.local_y]),
+            &Size => SamplerPrimitive::Vec2([self.width, self.height]),
+        }
+    }
+
+    fn default_primitive(_: &Self::Channel) -> Self::Primitive {
+        SamplerPrimitive::Vec2([0.; 2])
+    }
+
+    fn blend_method(&self, _: &Self::Channel) -> Option<BlendMethod> {
+        Some(BlendMethod::Linear)
+    }
+}
+
+/// Channels that can be animated on `UiTint`
+///
+/// The color is sampled as a `SamplerPrimitive::Vec4` of RGBA components, so animating the alpha
+/// fades the element in or out.
+#[derive(Debug, Clone, Copy, Hash, Eq, PartialEq, Serialize, Deserialize)]
+pub enum UiTintChannel {
+    /// The RGBA `color`
+    Color,
+}
+
+impl<'a> ApplyData<'a> for UiTint {
+    type ApplyData = ();
+}
+
+impl AnimationSampling for UiTint {
+    type Primitive = SamplerPrimitive<f32>;
+    type Channel = UiTintChannel;
+
+    fn apply_sample(&mut self, channel: &Self::Channel, data: &SamplerPrimitive<f32>, _: &()) {
+        use util::SamplerPrimitive::*;
+        match (channel, *data) {
+            (&UiTintChannel::Color, Vec4(ref d)) => self.color = *d,
+            _ => panic!("Attempt to apply invalid sample to UiTint"),
+        }
+    }
+
+    fn current_sample(&self, channel: &Self::Channel, _: &()) -> SamplerPrimitive<f32> {
+        match channel {
+            &UiTintChannel::Color => SamplerPrimitive::Vec4(self.color),
+        }
+    }
+
+    fn default_primitive(_: &Self::Channel) -> Self::Primitive {
+        SamplerPrimitive::Vec4([0.; 4])
+    }
+
+    fn blend_method(&self, _: &Self::Channel) -> Option<BlendMethod> {
+        Some(BlendMethod::Linear)
+    }
+}
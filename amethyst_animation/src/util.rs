@@ -12,6 +12,25 @@ use resources::{AnimationControlSet, AnimationSampling};
 /// - `I`: identifier type for running animations, only one animation can be run at the same time
 ///        with the same id
 /// - `T`: the component type that the animation applies to
+///
+/// Both are inferred from the storage, so an enum can be used as a type safe animation id. The
+/// `AnimationBundle` needs it to be `Copy + Eq + Hash`:
+///
+/// ```rust,ignore
+/// #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+/// enum AnimId {
+///     Walk,
+///     Jump,
+/// }
+///
+/// // When building the application
+/// .with_bundle(AnimationBundle::<AnimId, Transform>::new("animation_control", "sampler"))?
+///
+/// // In a system with `WriteStorage<'a, AnimationControlSet<AnimId, Transform>>`
+/// let set = get_animation_set(&mut controls, entity);
+/// set.abort(AnimId::Walk);
+/// set.add_animation(AnimId::Jump, &jump, EndControl::Normal, 1.0, AnimationCommand::Start);
+/// ```
 pub fn get_animation_set<'a, I, T>(
     controls: &'a mut WriteStorage<AnimationControlSet<I, T>>,
     entity: Entity,
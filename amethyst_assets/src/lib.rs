@@ -21,6 +21,7 @@ extern crate hibitset;
 extern crate log;
 extern crate parking_lot;
 extern crate rayon;
+extern crate shrev;
 
 #[macro_use]
 #[cfg(feature = "profiler")]
@@ -31,7 +32,8 @@ pub use cache::Cache;
 pub use error::{Error, ErrorKind, Result, ResultExt};
 pub use loader::Loader;
 pub use progress::{Completion, Progress, ProgressCounter, Tracker};
-pub use reload::{HotReloadBundle, HotReloadStrategy, HotReloadSystem, Reload, SingleFile};
+pub use reload::{AssetReloaded, HotReloadBundle, HotReloadStrategy, HotReloadSystem, Reload,
+                 SingleFile};
 pub use source::{Directory, Source};
 pub use storage::{AssetStorage, Handle, Processor, WeakHandle};
 
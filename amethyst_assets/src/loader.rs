@@ -6,7 +6,7 @@ use std::sync::Arc;
 use fnv::FnvHashMap;
 use rayon::ThreadPool;
 
-use {Asset, Directory, ErrorKind, Format, FormatValue, Progress, ResultExt, Source};
+use {Asset, Directory, ErrorKind, Format, FormatValue, Progress, ResultExt, SimpleFormat, Source};
 use storage::{AssetStorage, Handle, Processed};
 
 /// The asset loader, holding the sources and a reference to the `ThreadPool`.
@@ -188,6 +188,61 @@ impl Loader {
         handle
     }
 
+    /// Loads an asset from bytes already in memory, e.g. embedded with `include_bytes!` or
+    /// downloaded, instead of a file of a source.
+    /// Like with `load_from`, the bytes are decoded by `format` in a worker thread and this
+    /// method immediately returns a handle.
+    pub fn load_from_bytes<A, F, B, P>(
+        &self,
+        bytes: B,
+        format: F,
+        options: F::Options,
+        mut progress: P,
+        storage: &AssetStorage<A>,
+    ) -> Handle<A>
+    where
+        A: Asset,
+        F: SimpleFormat<A> + Send + 'static,
+        B: Into<Vec<u8>>,
+        P: Progress,
+    {
+        use progress::Tracker;
+
+        let bytes = bytes.into();
+        let handle = storage.allocate();
+        debug!(
+            "{:?}: Loading asset from {} bytes with format {:?} (handle id: {:?})",
+            A::NAME,
+            bytes.len(),
+            F::NAME,
+            handle,
+        );
+
+        progress.add_assets(1);
+        let tracker = progress.create_tracker();
+
+        let handle_clone = handle.clone();
+        let processed = storage.processed.clone();
+
+        let cl = move || {
+            let data = format
+                .import(bytes, options)
+                .map(FormatValue::data)
+                .chain_err(|| ErrorKind::Format(F::NAME));
+            let tracker = Box::new(tracker) as Box<Tracker>;
+
+            processed.push(Processed::NewAsset {
+                data,
+                handle,
+                name: "<Bytes>".into(),
+                tracker,
+            });
+        };
+        self.pool.spawn(cl);
+
+        handle_clone
+    }
+
     fn source(&self, source: &str) -> Arc<Source> {
         self.sources
             .get(source)
@@ -4,10 +4,10 @@ use std::sync::Arc;
 use std::time::Instant;
 
 use amethyst_core as core;
-use amethyst_core::{ECSBundle, Time};
-use amethyst_core::specs::{DispatcherBuilder, Fetch, FetchMut, System, World};
+use amethyst_core::{ECSBundle, ScheduleBuilder, Time};
+use amethyst_core::specs::{Fetch, FetchMut, System, World};
 
-use {Asset, Format, FormatValue, Loader, Result, Source};
+use {Asset, Format, FormatValue, Handle, Loader, Result, Source};
 
 /// This bundle activates hot reload for the `Loader`,
 /// adds a `HotReloadStrategy` and the `HotReloadSystem`.
@@ -27,8 +27,8 @@ impl<'a, 'b> ECSBundle<'a, 'b> for HotReloadBundle {
     fn build(
         self,
         world: &mut World,
-        dispatcher: DispatcherBuilder<'a, 'b>,
-    ) -> core::Result<DispatcherBuilder<'a, 'b>> {
+        dispatcher: ScheduleBuilder<'a, 'b>,
+    ) -> core::Result<ScheduleBuilder<'a, 'b>> {
         world.write_resource::<Loader>().set_hot_reload(true);
         world.add_resource(self.strategy);
 
@@ -36,6 +36,18 @@ impl<'a, 'b> ECSBundle<'a, 'b> for HotReloadBundle {
     }
 }
 
+/// Event written when an asset has been hot-reloaded.
+///
+/// Add an `EventChannel<AssetReloaded<A>>` resource to the world to receive
+/// these events; the asset's processor writes to it after the asset storage
+/// has been updated. This allows systems to rebuild data derived from the asset.
+#[derive(Derivative)]
+#[derivative(Clone(bound = ""), Debug(bound = ""))]
+pub struct AssetReloaded<A> {
+    /// Handle of the reloaded asset.
+    pub handle: Handle<A>,
+}
+
 /// An ECS resource which allows to configure hot reloading.
 ///
 /// ## Examples
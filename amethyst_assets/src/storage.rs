@@ -7,6 +7,7 @@ use amethyst_core::specs::{Component, Fetch, FetchMut, System, UnprotectedStorag
 use crossbeam::sync::MsQueue;
 use hibitset::BitSet;
 use rayon::ThreadPool;
+use shrev::EventChannel;
 
 #[cfg(feature = "profiler")]
 use thread_profiler::{register_thread_with_profiler, write_profile};
@@ -14,7 +15,7 @@ use thread_profiler::{register_thread_with_profiler, write_profile};
 use asset::{Asset, FormatValue};
 use error::{ErrorKind, Result, ResultExt};
 use progress::Tracker;
-use reload::{HotReloadStrategy, Reload};
+use reload::{AssetReloaded, HotReloadStrategy, Reload};
 
 /// An `Allocator`, holding a counter for producing unique IDs.
 #[derive(Debug, Default)]
@@ -38,6 +39,7 @@ pub struct AssetStorage<A: Asset> {
     handle_alloc: Allocator,
     pub(crate) processed: Arc<MsQueue<Processed<A>>>,
     reloads: Vec<(WeakHandle<A>, Box<Reload<A>>)>,
+    reloaded: Vec<Handle<A>>,
     unused_handles: MsQueue<Handle<A>>,
 }
 
@@ -108,6 +110,18 @@ impl<A: Asset> AssetStorage<A> {
         }
     }
 
+    /// Get the handles of the assets which have been hot-reloaded by the last call to `process`.
+    pub fn reloaded(&self) -> &[Handle<A>] {
+        &self.reloaded
+    }
+
+    /// Write an `AssetReloaded` event for every asset hot-reloaded by the last call to `process`.
+    pub fn write_reload_events(&self, channel: &mut EventChannel<AssetReloaded<A>>) {
+        channel.iter_write(self.reloaded.iter().map(|handle| AssetReloaded {
+            handle: handle.clone(),
+        }));
+    }
+
     /// Process finished asset data and maintain the storage.
     pub fn process<F>(
         &mut self,
@@ -134,11 +148,13 @@ impl<A: Asset> AssetStorage<A> {
         D: FnMut(A),
         F: FnMut(A::Data) -> Result<A>,
     {
+        self.reloaded.clear();
         while let Some(processed) = self.processed.try_pop() {
             let assets = &mut self.assets;
             let bitset = &mut self.bitset;
             let handles = &mut self.handles;
             let reloads = &mut self.reloads;
+            let reloaded = &mut self.reloaded;
 
             let f = &mut f;
             let (reload_obj, handle) = match processed {
@@ -238,6 +254,7 @@ impl<A: Asset> AssetStorage<A> {
                         let old = assets.get_mut(id);
                         *old = asset;
                     }
+                    reloaded.push(handle.clone());
 
                     (reload_obj, handle)
                 }
@@ -331,6 +348,7 @@ impl<A: Asset> Default for AssetStorage<A> {
             handle_alloc: Default::default(),
             processed: Arc::new(MsQueue::new()),
             reloads: Default::default(),
+            reloaded: Default::default(),
             unused_handles: MsQueue::new(),
         }
     }
@@ -349,6 +367,9 @@ impl<A: Asset> Drop for AssetStorage<A> {
 ///
 /// This system can only be used if the asset data implements
 /// `Into<Result<A, BoxedErr>>`.
+///
+/// If there is an `EventChannel<AssetReloaded<A>>` resource, an event
+/// will be written to it for every hot-reloaded asset.
 pub struct Processor<A> {
     marker: PhantomData<A>,
 }
@@ -373,9 +394,10 @@ where
         Fetch<'a, Arc<ThreadPool>>,
         Fetch<'a, Time>,
         Option<Fetch<'a, HotReloadStrategy>>,
+        Option<FetchMut<'a, EventChannel<AssetReloaded<A>>>>,
     );
 
-    fn run(&mut self, (mut storage, pool, time, strategy): Self::SystemData) {
+    fn run(&mut self, (mut storage, pool, time, strategy, reload_events): Self::SystemData) {
         use std::ops::Deref;
 
         storage.process(
@@ -384,6 +406,10 @@ where
             &**pool,
             strategy.as_ref().map(Deref::deref),
         );
+
+        if let Some(mut reload_events) = reload_events {
+            storage.write_reload_events(&mut reload_events);
+        }
     }
 }
 
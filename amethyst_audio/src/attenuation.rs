@@ -0,0 +1,171 @@
+//! Attenuation of spatial sounds with the distance to the listener.
+
+/// How the volume of a spatial sound decreases as it gets further from the `AudioListener`.
+///
+/// Sounds play at full volume up to `reference_distance`, then fade following the curve, and are
+/// silent beyond `max_distance`. The curve only applies to the volume, the balance between the
+/// ears still follows their positions.
+///
+/// The default is an inverse square rolloff from 1 unit without a maximum distance.
+///
+/// ## Example
+///
+/// ```rust,ignore
+/// let mut listener = AudioListener::new(output);
+/// listener.attenuation = AttenuationModel::InverseSquare {
+///     reference_distance: 1.0,
+///     max_distance: 500.0,
+/// };
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum AttenuationModel {
+    /// The volume decreases linearly, from full volume at `reference_distance` to silence at
+    /// `max_distance`.
+    Linear {
+        /// Distance up to which the sound plays at full volume.
+        reference_distance: f32,
+        /// Distance from which the sound is silent.
+        max_distance: f32,
+    },
+    /// The volume is inversely proportional to the distance.
+    Inverse {
+        /// Distance up to which the sound plays at full volume, 1.0 is used if it isn't positive.
+        reference_distance: f32,
+        /// Distance from which the sound is silent.
+        max_distance: f32,
+    },
+    /// The volume is inversely proportional to the square of the distance, like sound in the
+    /// open air.
+    InverseSquare {
+        /// Distance up to which the sound plays at full volume, 1.0 is used if it isn't positive.
+        reference_distance: f32,
+        /// Distance from which the sound is silent.
+        max_distance: f32,
+    },
+}
+
+impl Default for AttenuationModel {
+    fn default() -> Self {
+        AttenuationModel::InverseSquare {
+            reference_distance: 1.0,
+            max_distance: ::std::f32::INFINITY,
+        }
+    }
+}
+
+impl AttenuationModel {
+    /// Volume of a sound at the given distance from the listener, between 0.0 and 1.0.
+    pub fn gain(&self, distance: f32) -> f32 {
+        let (reference, max) = match *self {
+            AttenuationModel::Linear {
+                reference_distance,
+                max_distance,
+            }
+            | AttenuationModel::Inverse {
+                reference_distance,
+                max_distance,
+            }
+            | AttenuationModel::InverseSquare {
+                reference_distance,
+                max_distance,
+            } => (reference_distance.max(0.0), max_distance),
+        };
+        let reference = match *self {
+            AttenuationModel::Linear { .. } => reference,
+            // The inverse curves would be silent at any distance from a reference of 0.
+            _ if reference == 0.0 => 1.0,
+            _ => reference,
+        };
+        if distance >= max {
+            return 0.0;
+        }
+        if distance <= reference {
+            return 1.0;
+        }
+        match *self {
+            AttenuationModel::Linear { .. } => 1.0 - (distance - reference) / (max - reference),
+            AttenuationModel::Inverse { .. } => reference / distance,
+            AttenuationModel::InverseSquare { .. } => (reference / distance).powi(2),
+        }
+    }
+
+    /// Volume to give a `SpatialSink` playing at the given positions so the sound follows this
+    /// model.
+    ///
+    /// rodio already attenuates spatial sounds by the inverse square of the distance to each ear,
+    /// which the volume compensates for.
+    pub(crate) fn sink_volume(
+        &self,
+        emitter: [f32; 3],
+        left_ear: [f32; 3],
+        right_ear: [f32; 3],
+    ) -> f32 {
+        let center = [
+            (left_ear[0] + right_ear[0]) / 2.0,
+            (left_ear[1] + right_ear[1]) / 2.0,
+            (left_ear[2] + right_ear[2]) / 2.0,
+        ];
+        let distance_squared = (0..3)
+            .map(|i| (emitter[i] - center[i]).powi(2))
+            .sum::<f32>();
+        self.gain(distance_squared.sqrt()) * distance_squared.max(1.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::AttenuationModel;
+
+    const MODELS: [AttenuationModel; 3] = [
+        AttenuationModel::Linear {
+            reference_distance: 2.0,
+            max_distance: 10.0,
+        },
+        AttenuationModel::Inverse {
+            reference_distance: 2.0,
+            max_distance: 10.0,
+        },
+        AttenuationModel::InverseSquare {
+            reference_distance: 2.0,
+            max_distance: 10.0,
+        },
+    ];
+
+    #[test]
+    fn full_volume_up_to_the_reference_distance() {
+        for model in &MODELS {
+            assert_eq!(model.gain(0.0), 1.0);
+            assert_eq!(model.gain(2.0), 1.0);
+        }
+    }
+
+    #[test]
+    fn silent_from_the_max_distance() {
+        for model in &MODELS {
+            assert_eq!(model.gain(10.0), 0.0);
+            assert_eq!(model.gain(20.0), 0.0);
+        }
+    }
+
+    #[test]
+    fn curves_between_the_distances() {
+        assert_eq!(MODELS[0].gain(6.0), 0.5);
+        assert_eq!(MODELS[1].gain(4.0), 0.5);
+        assert_eq!(MODELS[2].gain(4.0), 0.25);
+    }
+
+    #[test]
+    fn inverse_curves_fall_back_to_a_reference_of_one() {
+        let inverse = AttenuationModel::Inverse {
+            reference_distance: 0.0,
+            max_distance: 10.0,
+        };
+        let inverse_square = AttenuationModel::InverseSquare {
+            reference_distance: -1.0,
+            max_distance: 10.0,
+        };
+        assert_eq!(inverse.gain(0.5), 1.0);
+        assert_eq!(inverse.gain(4.0), 0.25);
+        assert_eq!(inverse_square.gain(4.0), 0.0625);
+    }
+}
@@ -4,18 +4,23 @@ use std::marker::PhantomData;
 
 use amethyst_assets::{AssetStorage, Processor};
 use amethyst_core::bundle::{ECSBundle, Result};
+use amethyst_core::schedule::ScheduleBuilder;
 use amethyst_core::shred::ResourceId;
-use amethyst_core::specs::{DispatcherBuilder, World};
+use amethyst_core::specs::World;
+use shrev::EventChannel;
+use winit::Event;
 
 use output::{default_output, Output};
 use sink::AudioSink;
 use source::*;
-use systems::DjSystem;
+use spatial::SpatialAudio;
+use systems::{AudioFocusSystem, DjSystem};
 
 /// Audio bundle
 ///
 /// Will only register the `AudioSink` and the `DjSystem` if an audio output is found.
 /// `DjSystem` will be registered with name "dj_system".
+/// With `with_auto_pause`, the `AudioFocusSystem` is registered with name "audio_focus_system".
 ///
 /// This will also add the asset processor for `Source`.
 ///
@@ -31,6 +36,7 @@ pub struct AudioBundle<'a, F, R> {
     dep: &'a [&'a str],
     marker: PhantomData<R>,
     picker: F,
+    auto_pause: bool,
 }
 
 impl<'a, F, R> AudioBundle<'a, F, R> {
@@ -40,6 +46,7 @@ impl<'a, F, R> AudioBundle<'a, F, R> {
             dep: &[],
             marker: PhantomData,
             picker,
+            auto_pause: false,
         }
     }
 
@@ -48,6 +55,12 @@ impl<'a, F, R> AudioBundle<'a, F, R> {
         self.dep = dep;
         self
     }
+
+    /// Pause all audio while the window doesn't have the focus
+    pub fn with_auto_pause(mut self) -> Self {
+        self.auto_pause = true;
+        self
+    }
 }
 
 impl<'a, 'b, 'c, F, R> ECSBundle<'a, 'b> for AudioBundle<'c, F, R>
@@ -58,8 +71,8 @@ where
     fn build(
         self,
         world: &mut World,
-        mut builder: DispatcherBuilder<'a, 'b>,
-    ) -> Result<DispatcherBuilder<'a, 'b>> {
+        mut builder: ScheduleBuilder<'a, 'b>,
+    ) -> Result<ScheduleBuilder<'a, 'b>> {
         // Remove option here when specs get support for optional fetch in
         // released version
         if !world.res.has_value(ResourceId::new::<Option<Output>>()) {
@@ -72,12 +85,19 @@ where
             .map(|audio_output| AudioSink::new(audio_output));
 
         world.add_resource(AssetStorage::<Source>::new());
+        world.add_resource(SpatialAudio::new());
 
         if let Some(sink) = sink {
             world.add_resource(sink);
             builder = builder
                 .add(Processor::<Source>::new(), "source_processor", &[])
                 .add(DjSystem::new(self.picker), "dj_system", self.dep);
+            if self.auto_pause {
+                let reader = world
+                    .write_resource::<EventChannel<Event>>()
+                    .register_reader();
+                builder = builder.add(AudioFocusSystem::new(reader), "audio_focus_system", &[]);
+            }
         }
 
         Ok(builder)
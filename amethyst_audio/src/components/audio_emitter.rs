@@ -7,6 +7,7 @@ use rodio::{Decoder, SpatialSink};
 use smallvec::SmallVec;
 
 use DecoderError;
+use attenuation::AttenuationModel;
 use source::Source;
 
 /// An audio source, add this component to anything that emits sound.
@@ -15,6 +16,8 @@ pub struct AudioEmitter {
     pub(crate) sinks: SmallVec<[(SpatialSink, Arc<AtomicBool>); 4]>,
     pub(crate) sound_queue: SmallVec<[Decoder<Cursor<Source>>; 4]>,
     pub(crate) picker: Option<Box<FnMut(&mut AudioEmitter) -> bool + Send + Sync>>,
+    /// Attenuation of the sounds of this emitter, instead of the one of the `AudioListener`.
+    pub attenuation: Option<AttenuationModel>,
 }
 
 impl AudioEmitter {
@@ -25,6 +28,13 @@ impl AudioEmitter {
         Default::default()
     }
 
+    /// Attenuates the sounds of this emitter with the given model, instead of the one of the
+    /// `AudioListener`.
+    pub fn with_attenuation(mut self, attenuation: AttenuationModel) -> Self {
+        self.attenuation = Some(attenuation);
+        self
+    }
+
     /// Plays an audio source from this emitter.
     pub fn play(&mut self, source: &Source) -> Result<(), DecoderError> {
         self.sound_queue
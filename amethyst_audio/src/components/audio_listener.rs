@@ -1,6 +1,7 @@
 use amethyst_core::cgmath::Point3;
 use amethyst_core::specs::{Component, HashMapStorage};
 
+use attenuation::AttenuationModel;
 use output::Output;
 
 /// An audio listener, add this component to the local player character.
@@ -12,6 +13,22 @@ pub struct AudioListener {
     pub left_ear: Point3<f32>,
     /// Position of the right ear relative to the global transform on this entity.
     pub right_ear: Point3<f32>,
+    /// How the volume of the sounds heard by this listener decreases with their distance,
+    /// unless their `AudioEmitter` overrides it.
+    pub attenuation: AttenuationModel,
+}
+
+impl AudioListener {
+    /// Creates a new listener emitting to the given output, with ears 0.1 units apart along the
+    /// x axis, and the default attenuation.
+    pub fn new(output: Output) -> Self {
+        AudioListener {
+            output,
+            left_ear: Point3::new(-0.05, 0.0, 0.0),
+            right_ear: Point3::new(0.05, 0.0, 0.0),
+            attenuation: AttenuationModel::default(),
+        }
+    }
 }
 
 impl Component for AudioListener {
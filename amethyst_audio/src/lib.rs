@@ -5,17 +5,21 @@ extern crate cpal;
 #[macro_use]
 extern crate log;
 extern crate rodio;
+extern crate shrev;
 extern crate smallvec;
+extern crate winit;
 
 #[macro_use]
 #[cfg(feature = "profiler")]
 extern crate thread_profiler;
 
+pub use self::attenuation::AttenuationModel;
 pub use self::bundle::AudioBundle;
 pub use self::components::*;
 pub use self::formats::{FlacFormat, OggFormat, WavFormat};
 pub use self::sink::AudioSink;
 pub use self::source::{Source, SourceHandle};
+pub use self::spatial::SpatialAudio;
 pub use self::systems::*;
 
 pub mod output;
@@ -23,10 +27,12 @@ pub mod output;
 use std::error::Error;
 use std::fmt::{Display, Formatter, Result as FmtResult};
 
+mod attenuation;
 mod end_signal;
 mod formats;
 mod sink;
 mod source;
+mod spatial;
 mod components;
 mod systems;
 mod bundle;
@@ -3,6 +3,8 @@
 // We have to use types from this to provide an output iterator type.
 use std::fmt::{Debug, Formatter, Result as FmtResult};
 use std::io::Cursor;
+use std::sync::{Arc, Mutex};
+use std::sync::atomic::{AtomicBool, Ordering};
 
 use cpal::{default_endpoint, endpoints};
 use cpal::EndpointsIterator;
@@ -14,17 +16,80 @@ use source::Source;
 /// A speaker(s) through which audio can be played.
 ///
 /// By convention, the default output is stored as a resource in the `World`.
-#[derive(Clone, Eq, PartialEq)]
+///
+/// Clones of an `Output` share their pause state, so pausing the output resource also pauses
+/// the sounds played through the `AudioListener`s using it.
+#[derive(Clone)]
 pub struct Output {
     pub(crate) endpoint: Endpoint,
+    pub(crate) state: Arc<OutputState>,
+}
+
+/// Pause state shared by the clones of an `Output`.
+#[derive(Default)]
+pub(crate) struct OutputState {
+    paused: AtomicBool,
+    // One-shot sounds, kept to be able to pause them.
+    sinks: Mutex<Vec<Sink>>,
+}
+
+impl OutputState {
+    pub(crate) fn is_paused(&self) -> bool {
+        self.paused.load(Ordering::Relaxed)
+    }
 }
 
 impl Output {
+    fn new(endpoint: Endpoint) -> Self {
+        Output {
+            endpoint,
+            state: Arc::new(OutputState::default()),
+        }
+    }
+
     /// Gets the name of the output
     pub fn name(&self) -> String {
         self.endpoint.name()
     }
 
+    /// Pauses all the sounds played on this output, including the music of the `AudioSink` and
+    /// the sounds of `AudioEmitter`s and `SpatialAudio`.
+    ///
+    /// Sounds started while the output is paused are paused too. The sinks and emitters are
+    /// synced by the `DjSystem` and `AudioSystem`, on their next run.
+    pub fn pause_all(&self) {
+        self.state.paused.store(true, Ordering::Relaxed);
+        for sink in self.state.sinks.lock().unwrap().iter() {
+            sink.pause();
+        }
+    }
+
+    /// Resumes all the sounds paused by `Output::pause_all`, from where they were paused.
+    ///
+    /// An `AudioSink` paused with `AudioSink::pause` stays paused.
+    pub fn resume_all(&self) {
+        self.state.paused.store(false, Ordering::Relaxed);
+        for sink in self.state.sinks.lock().unwrap().iter() {
+            sink.play();
+        }
+    }
+
+    /// Returns true if the output is paused by `Output::pause_all`.
+    pub fn is_paused(&self) -> bool {
+        self.state.is_paused()
+    }
+
+    /// Keeps a one-shot sink playing until it is empty, pausing it with the output.
+    pub(crate) fn play_sink(&self, sink: Sink) {
+        let mut sinks = self.state.sinks.lock().unwrap();
+        // Remove all sinks whose sounds have ended.
+        sinks.retain(|s| !s.empty());
+        if self.is_paused() {
+            sink.pause();
+        }
+        sinks.push(sink);
+    }
+
     /// Play a sound once.  A volume of 1.0 is unchanged, while 0.0 is silent.
     ///
     /// This will return an Error if the loaded audio file in source could not be decoded.
@@ -65,15 +130,24 @@ impl Output {
                     .amplify(volume),
             );
         }
-        sink.detach();
+        self.play_sink(sink);
         Ok(())
     }
 }
 
+impl PartialEq for Output {
+    fn eq(&self, other: &Output) -> bool {
+        self.endpoint == other.endpoint
+    }
+}
+
+impl Eq for Output {}
+
 impl Debug for Output {
     fn fmt(&self, f: &mut Formatter) -> FmtResult {
         f.debug_struct("Output")
             .field("endpoint", &self.name())
+            .field("paused", &self.is_paused())
             .finish()
     }
 }
@@ -87,13 +161,13 @@ impl Iterator for OutputIterator {
     type Item = Output;
 
     fn next(&mut self) -> Option<Output> {
-        self.input.next().map(|re| Output { endpoint: re })
+        self.input.next().map(Output::new)
     }
 }
 
 /// Get the default output, returns none if no outputs are available.
 pub fn default_output() -> Option<Output> {
-    default_endpoint().map(|re| Output { endpoint: re })
+    default_endpoint().map(Output::new)
 }
 
 /// Get a list of outputs available to the system.
@@ -1,14 +1,20 @@
 use std::io::Cursor;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, Ordering};
 
 use rodio::{Decoder, Sink};
 
 use DecoderError;
-use output::Output;
+use output::{Output, OutputState};
 use source::Source;
 
 /// This structure provides a way to programmatically pick and play music.
+///
+/// The music is paused while its `Output` is paused by `Output::pause_all`.
 pub struct AudioSink {
     sink: Sink,
+    output: Arc<OutputState>,
+    paused: AtomicBool,
 }
 
 impl AudioSink {
@@ -16,6 +22,8 @@ impl AudioSink {
     pub fn new(output: &Output) -> AudioSink {
         AudioSink {
             sink: Sink::new(&output.endpoint),
+            output: output.state.clone(),
+            paused: AtomicBool::new(false),
         }
     }
 
@@ -42,12 +50,18 @@ impl AudioSink {
     }
 
     /// Resumes playback of a paused sink. Has no effect if this sink was never paused.
+    ///
+    /// If the output is paused, playback resumes once the output is resumed.
     pub fn play(&self) {
-        self.sink.play();
+        self.paused.store(false, Ordering::Relaxed);
+        if !self.output.is_paused() {
+            self.sink.play();
+        }
     }
 
     /// Pauses playback, this can be resumed with `AudioSink::play`
     pub fn pause(&self) {
+        self.paused.store(true, Ordering::Relaxed);
         self.sink.pause()
     }
 
@@ -56,6 +70,15 @@ impl AudioSink {
         self.sink.is_paused()
     }
 
+    /// Pauses or resumes playback to follow the pause state of the output.
+    pub(crate) fn sync_pause(&self) {
+        if self.output.is_paused() {
+            self.sink.pause();
+        } else if !self.paused.load(Ordering::Relaxed) {
+            self.sink.play();
+        }
+    }
+
     /// Empties the sink's queue of all music.
     pub fn stop(&self) {
         self.sink.stop();
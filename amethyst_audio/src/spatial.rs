@@ -0,0 +1,89 @@
+//! Positional sounds which aren't attached to an `AudioEmitter`.
+
+use std::io::Cursor;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, Ordering};
+
+use amethyst_core::cgmath::Point3;
+use rodio::{Decoder, Sink, SpatialSink};
+use smallvec::SmallVec;
+
+use DecoderError;
+use attenuation::AttenuationModel;
+use end_signal::EndSignalSource;
+use output::Output;
+use source::Source;
+
+/// This resource allows playing one-shot sounds at a position in the world, without
+/// creating an entity with an `AudioEmitter`.
+///
+/// Sounds are played by the `AudioSystem`, relative to the selected `AudioListener`. If there
+/// is no listener, sounds are played without spatialization on the default `Output`.
+/// Sounds that shouldn't be spatialized, like UI sounds, should be played with `Output` directly.
+#[derive(Default)]
+pub struct SpatialAudio {
+    queue: SmallVec<[(Decoder<Cursor<Source>>, [f32; 3]); 4]>,
+    sinks: Vec<(SpatialSink, [f32; 3], Arc<AtomicBool>)>,
+}
+
+impl SpatialAudio {
+    /// Creates a new `SpatialAudio` resource.
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Plays an audio source at the given position, starting next frame.
+    pub fn play_at(&mut self, source: &Source, position: Point3<f32>) -> Result<(), DecoderError> {
+        let decoder = Decoder::new(Cursor::new(source.clone())).map_err(|_| DecoderError)?;
+        self.queue.push((decoder, position.into()));
+        Ok(())
+    }
+
+    /// Starts the queued sounds and moves the ears of the playing ones.
+    pub(crate) fn update(
+        &mut self,
+        output: &Output,
+        attenuation: &AttenuationModel,
+        left_ear: [f32; 3],
+        right_ear: [f32; 3],
+    ) {
+        // Remove all sinks whose sounds have ended.
+        self.sinks.retain(|s| !s.2.load(Ordering::Relaxed));
+        let paused = output.is_paused();
+        for &mut (ref mut sink, position, _) in &mut self.sinks {
+            sink.set_left_ear_position(left_ear);
+            sink.set_right_ear_position(right_ear);
+            sink.set_volume(attenuation.sink_volume(position, left_ear, right_ear));
+            sync_pause(sink, paused);
+        }
+        for (source, position) in self.queue.drain() {
+            let mut sink = SpatialSink::new(&output.endpoint, position, left_ear, right_ear);
+            sink.set_volume(attenuation.sink_volume(position, left_ear, right_ear));
+            sync_pause(&sink, paused);
+            let atomic_bool = Arc::new(AtomicBool::new(false));
+            let clone = atomic_bool.clone();
+            sink.append(EndSignalSource::new(source, move || {
+                clone.store(true, Ordering::Relaxed);
+            }));
+            self.sinks.push((sink, position, atomic_bool));
+        }
+    }
+
+    /// Starts the queued sounds without spatialization.
+    pub(crate) fn update_non_spatial(&mut self, output: &Output) {
+        for (source, _) in self.queue.drain() {
+            let sink = Sink::new(&output.endpoint);
+            sink.append(source);
+            output.play_sink(sink);
+        }
+    }
+}
+
+/// Pauses or resumes a spatial sink to follow the pause state of its output.
+pub(crate) fn sync_pause(sink: &SpatialSink, paused: bool) {
+    if paused {
+        sink.pause();
+    } else {
+        sink.play();
+    }
+}
@@ -4,14 +4,19 @@ use std::sync::Arc;
 use std::sync::atomic::{AtomicBool, Ordering};
 
 use amethyst_core::cgmath::Transform;
-use amethyst_core::specs::{Entities, Entity, Fetch, Join, ReadStorage, System, WriteStorage};
+use amethyst_core::specs::{Entities, Entity, Fetch, FetchMut, Join, ReadStorage, System,
+                           WriteStorage};
 use amethyst_core::transform::GlobalTransform;
 use rodio::SpatialSink;
 
 use components::{AudioEmitter, AudioListener};
 use end_signal::EndSignalSource;
+use output::Output;
+use spatial::{sync_pause, SpatialAudio};
 
 /// Syncs 3D transform data with the audio engine to provide 3D audio.
+///
+/// Also plays the sounds queued in the `SpatialAudio` resource, if present.
 #[derive(Default)]
 pub struct AudioSystem;
 
@@ -34,11 +39,21 @@ impl<'a> System<'a> for AudioSystem {
         ReadStorage<'a, GlobalTransform>,
         ReadStorage<'a, AudioListener>,
         WriteStorage<'a, AudioEmitter>,
+        Option<FetchMut<'a, SpatialAudio>>,
+        Option<Fetch<'a, Option<Output>>>,
     );
 
     fn run(
         &mut self,
-        (select_listener, entities, transform, listener, mut audio_emitter): Self::SystemData,
+        (
+            select_listener,
+            entities,
+            transform,
+            listener,
+            mut audio_emitter,
+            mut spatial_audio,
+            output,
+        ): Self::SystemData,
     ) {
         #[cfg(feature = "profiler")]
         profile_scope!("audio_system");
@@ -59,6 +74,7 @@ impl<'a> System<'a> for AudioSystem {
                 let right_ear_position = listener_transform
                     .transform_point(listener.right_ear)
                     .into();
+                let paused = listener.output.is_paused();
                 for (transform, mut audio_emitter) in (&transform, &mut audio_emitter).join() {
                     let x = transform.0[3][0];
                     let y = transform.0[3][1];
@@ -66,10 +82,17 @@ impl<'a> System<'a> for AudioSystem {
                     let emitter_position = [x, y, z];
                     // Remove all sinks whose sounds have ended.
                     audio_emitter.sinks.retain(|s| !s.1.load(Ordering::Relaxed));
+                    let volume = audio_emitter
+                        .attenuation
+                        .as_ref()
+                        .unwrap_or(&listener.attenuation)
+                        .sink_volume(emitter_position, left_ear_position, right_ear_position);
                     for &mut (ref mut sink, _) in &mut audio_emitter.sinks {
                         sink.set_emitter_position(emitter_position);
                         sink.set_left_ear_position(left_ear_position);
                         sink.set_right_ear_position(right_ear_position);
+                        sink.set_volume(volume);
+                        sync_pause(sink, paused);
                     }
                     if audio_emitter.sinks.is_empty() {
                         if let Some(mut picker) = replace(&mut audio_emitter.picker, None) {
@@ -79,12 +102,14 @@ impl<'a> System<'a> for AudioSystem {
                         }
                     }
                     while let Some(source) = audio_emitter.sound_queue.pop() {
-                        let sink = SpatialSink::new(
+                        let mut sink = SpatialSink::new(
                             &listener.output.endpoint,
                             emitter_position,
                             left_ear_position,
                             right_ear_position,
                         );
+                        sink.set_volume(volume);
+                        sync_pause(&sink, paused);
                         let atomic_bool = Arc::new(AtomicBool::new(false));
                         let clone = atomic_bool.clone();
                         sink.append(EndSignalSource::new(source, move || {
@@ -93,7 +118,23 @@ impl<'a> System<'a> for AudioSystem {
                         audio_emitter.sinks.push((sink, atomic_bool));
                     }
                 }
+                if let Some(ref mut spatial_audio) = spatial_audio {
+                    spatial_audio.update(
+                        &listener.output,
+                        &listener.attenuation,
+                        left_ear_position,
+                        right_ear_position,
+                    );
+                }
             }
         }
+
+        // Without a listener, fall back to non spatial audio.
+        if let (Some(spatial_audio), Some(output)) = (
+            spatial_audio.as_mut(),
+            output.as_ref().and_then(|output| output.as_ref()),
+        ) {
+            spatial_audio.update_non_spatial(output);
+        }
     }
 }
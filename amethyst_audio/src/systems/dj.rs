@@ -9,6 +9,8 @@ use sink::AudioSink;
 use source::{Source, SourceHandle};
 
 /// Calls a closure if the `AudioSink` is empty.
+///
+/// Also pauses and resumes the `AudioSink` with its `Output`.
 pub struct DjSystem<F, R> {
     f: F,
     marker: PhantomData<R>,
@@ -42,6 +44,7 @@ where
     fn run(&mut self, (storage, errors, sink, mut res): Self::SystemData) {
         #[cfg(feature = "profiler")]
         profile_scope!("dj_system");
+        sink.sync_pause();
         if sink.empty() {
             if let Some(source) = (&mut self.f)(&mut res).and_then(|h| storage.get(&h)) {
                 errors.execute(|| sink.append(source));
@@ -0,0 +1,56 @@
+use amethyst_core::specs::{Fetch, System};
+use shrev::{EventChannel, ReaderId};
+use winit::{Event, WindowEvent};
+
+use output::Output;
+
+/// Pauses all audio of the default `Output` when the window loses focus, and resumes it when
+/// the window gets the focus back.
+///
+/// Audio paused with `Output::pause_all` before the focus was lost stays paused.
+pub struct AudioFocusSystem {
+    reader: ReaderId<Event>,
+    paused_by_focus: bool,
+}
+
+impl AudioFocusSystem {
+    /// Creates a new `AudioFocusSystem` reading window events with the given reader.
+    pub fn new(reader: ReaderId<Event>) -> Self {
+        AudioFocusSystem {
+            reader,
+            paused_by_focus: false,
+        }
+    }
+}
+
+impl<'a> System<'a> for AudioFocusSystem {
+    type SystemData = (Fetch<'a, EventChannel<Event>>, Fetch<'a, Option<Output>>);
+
+    fn run(&mut self, (events, output): Self::SystemData) {
+        #[cfg(feature = "profiler")]
+        profile_scope!("audio_focus_system");
+        for event in events.read(&mut self.reader) {
+            let output = match *output {
+                Some(ref output) => output,
+                None => continue,
+            };
+            match *event {
+                Event::WindowEvent {
+                    event: WindowEvent::Focused(false),
+                    ..
+                } => if !output.is_paused() {
+                    output.pause_all();
+                    self.paused_by_focus = true;
+                },
+                Event::WindowEvent {
+                    event: WindowEvent::Focused(true),
+                    ..
+                } => if self.paused_by_focus {
+                    output.resume_all();
+                    self.paused_by_focus = false;
+                },
+                _ => {}
+            }
+        }
+    }
+}
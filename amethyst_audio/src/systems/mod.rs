@@ -1,7 +1,9 @@
 //! `amethyst` audio ecs systems
 
-pub use self::audio::AudioSystem;
+pub use self::audio::{AudioSystem, SelectedListener};
 pub use self::dj::DjSystem;
+pub use self::focus::AudioFocusSystem;
 
 mod audio;
 mod dj;
+mod focus;
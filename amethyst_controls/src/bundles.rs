@@ -1,6 +1,7 @@
 use super::*;
 use amethyst_core::bundle::{ECSBundle, Result};
-use amethyst_core::specs::{DispatcherBuilder, World};
+use amethyst_core::schedule::ScheduleBuilder;
+use amethyst_core::specs::World;
 use amethyst_renderer::WindowMessages;
 use amethyst_renderer::mouse::*;
 use std::hash::Hash;
@@ -56,8 +57,8 @@ where
     fn build(
         self,
         world: &mut World,
-        builder: DispatcherBuilder<'a, 'b>,
-    ) -> Result<DispatcherBuilder<'a, 'b>> {
+        builder: ScheduleBuilder<'a, 'b>,
+    ) -> Result<ScheduleBuilder<'a, 'b>> {
         world.register::<FlyControlTag>();
 
         let mut msg = world.res.entry().or_insert_with(|| WindowMessages::new());
@@ -1,13 +1,17 @@
-use specs::{DispatcherBuilder, World};
+use specs::World;
+
+use schedule::ScheduleBuilder;
 
 error_chain!{}
 
 /// A bundle of ECS components, resources and systems.
 pub trait ECSBundle<'a, 'b> {
     /// Build and add ECS resources, register components, add systems etc to the Application.
+    ///
+    /// The systems added to `dispatcher` are recorded, see `Application::schedule_dot`.
     fn build(
         self,
         world: &mut World,
-        dispatcher: DispatcherBuilder<'a, 'b>,
-    ) -> Result<DispatcherBuilder<'a, 'b>>;
+        dispatcher: ScheduleBuilder<'a, 'b>,
+    ) -> Result<ScheduleBuilder<'a, 'b>>;
 }
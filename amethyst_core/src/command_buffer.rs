@@ -0,0 +1,196 @@
+//! Deferred changes to the entities of the world.
+
+use std::mem;
+use std::sync::Mutex;
+
+use shred::ResourceId;
+use specs::{Component, Entities, Entity, MaskedStorage, World};
+
+/// Resource queuing changes to the `World` from systems, applied in order at the end of the frame
+/// once all the systems have run.
+///
+/// Systems can queue entity deletions and component changes while joining over storages, without
+/// invalidating the iteration or fetching the storages mutably. Entities created with `create`
+/// can be referred to right away, their components are added when the commands are applied.
+///
+/// The `Application` applies the commands after dispatching the systems, before the world is
+/// maintained. The updates queued with `LazyUpdate` and the deletions with `Entities::delete`
+/// are applied while maintaining, so they run after the commands queued during the same frame,
+/// whereas the commands take effect right away, in the order they were queued.
+///
+/// Commands changing a component which isn't registered are ignored.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// for (enemy, enemy_collider, _) in (&*entities, &colliders, &enemies).join() {
+///     if enemy_collider.overlaps(player_collider) {
+///         commands.delete(enemy);
+///         let explosion = commands.create(&entities);
+///         commands.insert(explosion, Lifetime::new(0.5));
+///     }
+/// }
+/// ```
+#[derive(Default)]
+pub struct CommandBuffer {
+    commands: Mutex<Vec<Box<Command>>>,
+}
+
+impl CommandBuffer {
+    /// Creates a new command buffer without commands.
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Creates a new entity, whose components are queued with `insert`.
+    pub fn create(&self, entities: &Entities) -> Entity {
+        entities.create()
+    }
+
+    /// Queues the deletion of an entity. Deleting a dead entity does nothing.
+    pub fn delete(&self, entity: Entity) {
+        self.execute(move |world: &mut World| {
+            let _ = world.delete_entity(entity);
+        });
+    }
+
+    /// Queues the insertion of a component on an entity, replacing the previous one.
+    ///
+    /// Nothing is inserted if the entity is deleted by then.
+    pub fn insert<C>(&self, entity: Entity, component: C)
+    where
+        C: Component + Send + Sync,
+    {
+        self.execute(move |world: &mut World| {
+            if registered::<C>(world) && world.is_alive(entity) {
+                world.write::<C>().insert(entity, component);
+            }
+        });
+    }
+
+    /// Queues the removal of a component from an entity.
+    pub fn remove<C>(&self, entity: Entity)
+    where
+        C: Component + Send + Sync,
+    {
+        self.execute(move |world: &mut World| {
+            if registered::<C>(world) {
+                world.write::<C>().remove(entity);
+            }
+        });
+    }
+
+    /// Queues a function changing the world in any other way.
+    pub fn execute<F>(&self, f: F)
+    where
+        F: FnOnce(&mut World) + Send + Sync + 'static,
+    {
+        self.commands.lock().unwrap().push(Box::new(f));
+    }
+
+    /// Returns true if no command is queued.
+    pub fn is_empty(&self) -> bool {
+        self.commands.lock().unwrap().is_empty()
+    }
+
+    /// Applies the commands queued in the `CommandBuffer` resource of the world, in order, if
+    /// there is one.
+    ///
+    /// Commands queued while applying them are applied too.
+    pub fn apply(world: &mut World) {
+        loop {
+            let commands = match world.res.try_fetch::<CommandBuffer>(0) {
+                Some(buffer) => mem::replace(&mut *buffer.commands.lock().unwrap(), Vec::new()),
+                None => return,
+            };
+            if commands.is_empty() {
+                return;
+            }
+            for command in commands {
+                command.apply(world);
+            }
+        }
+    }
+}
+
+/// Returns true if the storage of `C` was registered in the world.
+fn registered<C: Component>(world: &World) -> bool {
+    world.res.has_value(ResourceId::new::<MaskedStorage<C>>())
+}
+
+trait Command: Send + Sync {
+    fn apply(self: Box<Self>, world: &mut World);
+}
+
+impl<F> Command for F
+where
+    F: FnOnce(&mut World) + Send + Sync,
+{
+    fn apply(self: Box<Self>, world: &mut World) {
+        (*self)(world)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use specs::{Component, NullStorage, VecStorage, World};
+
+    use super::CommandBuffer;
+
+    #[derive(Clone, Debug, PartialEq)]
+    struct Health(u32);
+
+    impl Component for Health {
+        type Storage = VecStorage<Self>;
+    }
+
+    #[derive(Default)]
+    struct Unregistered;
+
+    impl Component for Unregistered {
+        type Storage = NullStorage<Self>;
+    }
+
+    #[test]
+    fn commands_apply_in_order() {
+        let mut world = World::new();
+        world.register::<Health>();
+        world.add_resource(CommandBuffer::new());
+        let doomed = world.create_entity().with(Health(1)).build();
+
+        let spawned = {
+            let commands = world.read_resource::<CommandBuffer>();
+            let spawned = commands.create(&world.entities());
+            commands.insert(spawned, Health(10));
+            commands.insert(doomed, Health(2));
+            commands.delete(doomed);
+            commands.insert(doomed, Health(3));
+            spawned
+        };
+        CommandBuffer::apply(&mut world);
+        world.maintain();
+
+        assert!(world.read_resource::<CommandBuffer>().is_empty());
+        assert!(!world.is_alive(doomed));
+        assert_eq!(world.read::<Health>().get(spawned), Some(&Health(10)));
+    }
+
+    #[test]
+    fn remove_takes_the_component() {
+        let mut world = World::new();
+        world.register::<Health>();
+        world.add_resource(CommandBuffer::new());
+        let entity = world.create_entity().with(Health(1)).build();
+
+        {
+            let commands = world.read_resource::<CommandBuffer>();
+            commands.remove::<Health>(entity);
+            commands.remove::<Unregistered>(entity);
+            commands.insert(entity, Unregistered);
+        }
+        CommandBuffer::apply(&mut world);
+
+        assert!(world.is_alive(entity));
+        assert_eq!(world.read::<Health>().get(entity), None);
+    }
+}
@@ -0,0 +1,19 @@
+//! Temporarily deactivated entities.
+
+use specs::{Component, NullStorage};
+
+/// Entities with this component are skipped by the engine systems: they aren't drawn, animated
+/// or moved, but keep all their components, so removing `Disabled` brings them back as they were.
+///
+/// The engine has no collision system, `Collider`s only describe shapes: the systems checking
+/// for collisions, as well as the other game systems, have to skip `Disabled` entities
+/// themselves, for example with `for_each_enabled`.
+///
+/// Useful to pool entities, such as enemies or projectiles, instead of deleting and recreating
+/// them.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Disabled;
+
+impl Component for Disabled {
+    type Storage = NullStorage<Self>;
+}
@@ -21,17 +21,31 @@ compile_error!("the cfg flag \"no_threading\" is required when building for emsc
 //#[cfg(test)]
 //extern crate quickcheck;
 
+pub use batch::{BatchBuilder, CreateEntities};
 pub use bundle::{ECSBundle, Error, ErrorKind, Result};
+pub use command_buffer::CommandBuffer;
+pub use disabled::Disabled;
+pub use prefab::{Instantiate, Prefab};
+pub use query::{for_each_enabled, par_for_each_enabled, EntitiesWith};
+pub use rng::Rng;
+pub use schedule::{Schedule, ScheduleBuilder};
 pub use timing::*;
 pub use transform::*;
 
 use std::sync::Arc;
 
+pub mod batch;
 pub mod bundle;
+pub mod command_buffer;
+pub mod disabled;
 pub mod orientation;
 pub mod transform;
 pub mod timing;
 pub mod frame_limiter;
+pub mod rng;
+pub mod prefab;
+pub mod query;
+pub mod schedule;
 
 /// A rayon thread pool wrapped in an `Arc`. This should be used as resource in `World`.
 pub type ThreadPool = Arc<rayon::ThreadPool>;
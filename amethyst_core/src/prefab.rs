@@ -0,0 +1,135 @@
+//! Templates of entities.
+
+use std::any::TypeId;
+
+use specs::{Component, Entity, EntityBuilder, LazyUpdate, World};
+
+/// A template for entities, made of a set of components.
+///
+/// Instantiating the prefab creates an entity with a copy of each component. The returned
+/// `EntityBuilder` can override some of them, such as the `Transform`, before building the
+/// entity.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// let enemy = Prefab::new().with(mesh).with(material).with(Transform::default());
+/// for i in 0..50 {
+///     let mut transform = Transform::default();
+///     transform.translation.x = i as f32;
+///     world.instantiate(&enemy).with(transform).build();
+/// }
+/// ```
+#[derive(Default)]
+pub struct Prefab {
+    components: Vec<Box<PrefabComponent>>,
+}
+
+impl Prefab {
+    /// Creates a new prefab without components.
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Adds a component to the prefab, replacing the one of the same type if present.
+    pub fn with<C>(mut self, component: C) -> Self
+    where
+        C: Component + Clone + Send + Sync,
+    {
+        self.add(component);
+        self
+    }
+
+    /// Adds a component to the prefab, replacing the one of the same type if present.
+    pub fn add<C>(&mut self, component: C)
+    where
+        C: Component + Clone + Send + Sync,
+    {
+        // Components are inserted in order, so the last one of a type would win anyway, but
+        // there's no need to keep the others around.
+        self.components.retain(|c| c.component_type() != TypeId::of::<C>());
+        self.components.push(Box::new(component));
+    }
+
+    /// Creates a new entity with a copy of the components of the prefab.
+    pub fn instantiate<'a>(&self, world: &'a mut World) -> EntityBuilder<'a> {
+        self.components
+            .iter()
+            .fold(world.create_entity(), |builder, component| component.add_to(builder))
+    }
+
+    /// Queues a copy of the components of the prefab for insertion on an existing entity, for
+    /// systems which can't access the `World`.
+    ///
+    /// The components are added when the `World` is next maintained.
+    pub fn instantiate_lazy(&self, entity: Entity, lazy: &LazyUpdate) {
+        for component in &self.components {
+            component.insert_lazy(entity, lazy);
+        }
+    }
+}
+
+/// Extension trait to instantiate prefabs from the `World`.
+pub trait Instantiate {
+    /// Creates a new entity with a copy of the components of the prefab.
+    fn instantiate(&mut self, prefab: &Prefab) -> EntityBuilder;
+}
+
+impl Instantiate for World {
+    fn instantiate(&mut self, prefab: &Prefab) -> EntityBuilder {
+        prefab.instantiate(self)
+    }
+}
+
+trait PrefabComponent: Send + Sync {
+    fn add_to<'a>(&self, builder: EntityBuilder<'a>) -> EntityBuilder<'a>;
+
+    fn insert_lazy(&self, entity: Entity, lazy: &LazyUpdate);
+
+    fn component_type(&self) -> TypeId;
+}
+
+impl<C> PrefabComponent for C
+where
+    C: Component + Clone + Send + Sync,
+{
+    fn add_to<'a>(&self, builder: EntityBuilder<'a>) -> EntityBuilder<'a> {
+        builder.with(self.clone())
+    }
+
+    fn insert_lazy(&self, entity: Entity, lazy: &LazyUpdate) {
+        lazy.insert(entity, self.clone());
+    }
+
+    fn component_type(&self) -> TypeId {
+        TypeId::of::<C>()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use specs::{Component, VecStorage, World};
+
+    use super::{Instantiate, Prefab};
+
+    #[derive(Clone, Debug, PartialEq)]
+    struct Health(u32);
+
+    impl Component for Health {
+        type Storage = VecStorage<Self>;
+    }
+
+    #[test]
+    fn instantiate_and_override() {
+        let mut world = World::new();
+        world.register::<Health>();
+        let prefab = Prefab::new().with(Health(10));
+
+        let a = world.instantiate(&prefab).build();
+        let b = world.instantiate(&prefab).with(Health(5)).build();
+
+        let healths = world.read::<Health>();
+        assert_eq!(healths.get(a), Some(&Health(10)));
+        assert_eq!(healths.get(b), Some(&Health(5)));
+    }
+}
@@ -0,0 +1,140 @@
+//! Helpers to go through the entities of the world, with less boilerplate than raw joins.
+
+use rayon::iter::ParallelIterator;
+use specs::{Component, Entity, Join, ParJoin, ReadStorage, World};
+
+use disabled::Disabled;
+
+/// Calls `f` with the components of each entity matching `join`, skipping `Disabled` entities.
+///
+/// `join` is anything which can be joined, usually a tuple of storage references.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// impl<'a> System<'a> for MovementSystem {
+///     type SystemData = (
+///         Fetch<'a, Time>,
+///         ReadStorage<'a, Velocity>,
+///         WriteStorage<'a, Transform>,
+///         ReadStorage<'a, Disabled>,
+///     );
+///
+///     fn run(&mut self, (time, velocities, mut transforms, disabled): Self::SystemData) {
+///         let delta = time.delta_seconds();
+///         for_each_enabled((&velocities, &mut transforms), &disabled, |(velocity, transform)| {
+///             transform.translation += velocity.0 * delta;
+///         });
+///     }
+/// }
+/// ```
+pub fn for_each_enabled<J, F>(join: J, disabled: &ReadStorage<Disabled>, mut f: F)
+where
+    J: Join,
+    F: FnMut(J::Type),
+{
+    for (components, _) in (join, !disabled).join() {
+        f(components);
+    }
+}
+
+/// Parallel version of `for_each_enabled`, calling `f` from the threads of the dispatcher.
+///
+/// Worth it for loops over many entities or doing costly work per entity; `for_each_enabled`
+/// is faster for cheap loops over few entities.
+pub fn par_for_each_enabled<J, F>(join: J, disabled: &ReadStorage<Disabled>, f: F)
+where
+    J: ParJoin + Send,
+    J::Mask: Send + Sync,
+    J::Type: Send,
+    J::Value: Send,
+    F: Fn(J::Type) + Send + Sync,
+{
+    (join, !disabled)
+        .par_join()
+        .for_each(|(components, _)| f(components));
+}
+
+/// Extension trait to list entities from the `World` without writing a system.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// for entity in world.entities_with::<Camera>() {
+///     info!("{:?} is a camera", entity);
+/// }
+/// ```
+pub trait EntitiesWith {
+    /// Returns the living entities having a `C` component, ordered by id.
+    ///
+    /// The entities are collected so the storage isn't borrowed anymore once this returns, and
+    /// the world can be modified while going through them.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the component hasn't been registered.
+    fn entities_with<C: Component>(&self) -> Vec<Entity>;
+}
+
+impl EntitiesWith for World {
+    fn entities_with<C: Component>(&self) -> Vec<Entity> {
+        let entities = self.entities();
+        let storage = self.read::<C>();
+        (&*entities, &storage).join().map(|(entity, _)| entity).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use specs::{Component, NullStorage, VecStorage, World};
+
+    use super::{for_each_enabled, par_for_each_enabled, EntitiesWith};
+    use disabled::Disabled;
+
+    #[derive(Clone, Copy, Debug, Default)]
+    struct Marker;
+
+    impl Component for Marker {
+        type Storage = NullStorage<Self>;
+    }
+
+    #[test]
+    fn entities_with_component() {
+        let mut world = World::new();
+        world.register::<Marker>();
+
+        let first = world.create_entity().with(Marker).build();
+        world.create_entity().build();
+        let second = world.create_entity().with(Marker).build();
+
+        assert_eq!(world.entities_with::<Marker>(), vec![first, second]);
+    }
+
+    #[derive(Clone, Copy, Debug, PartialEq)]
+    struct Counter(u32);
+
+    impl Component for Counter {
+        type Storage = VecStorage<Self>;
+    }
+
+    #[test]
+    fn for_each_skips_disabled_entities() {
+        let mut world = World::new();
+        world.register::<Counter>();
+        world.register::<Disabled>();
+
+        let enabled = world.create_entity().with(Counter(0)).build();
+        let skipped = world.create_entity().with(Counter(0)).with(Disabled).build();
+
+        {
+            let mut counters = world.write::<Counter>();
+            let disabled = world.read::<Disabled>();
+            for_each_enabled(&mut counters, &disabled, |counter| counter.0 += 1);
+            par_for_each_enabled(&mut counters, &disabled, |counter| counter.0 += 10);
+        }
+
+        let counters = world.read::<Counter>();
+        assert_eq!(counters.get(enabled), Some(&Counter(11)));
+        assert_eq!(counters.get(skipped), Some(&Counter(0)));
+    }
+}
@@ -0,0 +1,151 @@
+//! Deterministic random number generation.
+
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// A seedable pseudo random number generator, to be used as a resource.
+///
+/// Two generators created with the same seed produce the same sequence of numbers, which makes it
+/// suitable for reproducible procedural generation. It is *not* cryptographically secure.
+#[derive(Clone, Debug)]
+pub struct Rng {
+    seed: u64,
+    state: [u64; 2],
+}
+
+impl Rng {
+    /// Creates a new generator from the given seed.
+    pub fn new(seed: u64) -> Rng {
+        // Expand the seed with splitmix64, so that similar seeds give unrelated sequences and the
+        // xorshift state is never all zeros.
+        let mut sm = seed;
+        let mut splitmix = || {
+            sm = sm.wrapping_add(0x9E37_79B9_7F4A_7C15);
+            let mut z = sm;
+            z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+            z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+            z ^ (z >> 31)
+        };
+        let state = [splitmix(), splitmix()];
+        Rng { seed, state }
+    }
+
+    /// Creates a new generator seeded from the system clock.
+    pub fn from_time() -> Rng {
+        let seed = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|d| d.as_secs() ^ (d.subsec_nanos() as u64) << 32)
+            .unwrap_or(0);
+        Rng::new(seed)
+    }
+
+    /// Gets the seed this generator was created with.
+    pub fn seed(&self) -> u64 {
+        self.seed
+    }
+
+    /// Generates the next random `u64`.
+    pub fn next_u64(&mut self) -> u64 {
+        // xorshift128+
+        let mut s1 = self.state[0];
+        let s0 = self.state[1];
+        self.state[0] = s0;
+        s1 ^= s1 << 23;
+        self.state[1] = s1 ^ s0 ^ (s1 >> 17) ^ (s0 >> 26);
+        self.state[1].wrapping_add(s0)
+    }
+
+    /// Generates a random `f64` in the range [0, 1).
+    pub fn next_f64(&mut self) -> f64 {
+        (self.next_u64() >> 11) as f64 / (1u64 << 53) as f64
+    }
+
+    /// Generates a random value in the range [low, high).
+    ///
+    /// # Panics
+    /// Panics if `low >= high`.
+    pub fn gen_range<T: SampleRange>(&mut self, low: T, high: T) -> T {
+        assert!(low < high, "Rng::gen_range called with low >= high");
+        T::sample(self, low, high)
+    }
+
+    /// Returns `true` with the given probability, between 0 and 1.
+    pub fn gen_bool(&mut self, probability: f64) -> bool {
+        self.next_f64() < probability
+    }
+}
+
+impl Default for Rng {
+    fn default() -> Self {
+        Rng::from_time()
+    }
+}
+
+/// Types that can be uniformly sampled in a range by `Rng::gen_range`.
+pub trait SampleRange: PartialOrd + Sized {
+    /// Samples a value in the range [low, high).
+    fn sample(rng: &mut Rng, low: Self, high: Self) -> Self;
+}
+
+macro_rules! impl_sample_int {
+    ($($ty:ty),*) => {
+        $(
+            impl SampleRange for $ty {
+                fn sample(rng: &mut Rng, low: Self, high: Self) -> Self {
+                    let range = (high as i64).wrapping_sub(low as i64) as u64;
+                    (low as i64).wrapping_add((rng.next_u64() % range) as i64) as $ty
+                }
+            }
+        )*
+    }
+}
+
+impl_sample_int!(i8, i16, i32, i64, isize, u8, u16, u32, usize);
+
+impl SampleRange for u64 {
+    fn sample(rng: &mut Rng, low: Self, high: Self) -> Self {
+        low + rng.next_u64() % (high - low)
+    }
+}
+
+impl SampleRange for f32 {
+    fn sample(rng: &mut Rng, low: Self, high: Self) -> Self {
+        let value = low + (high - low) * rng.next_f64() as f32;
+        // Rounding can land exactly on `high`.
+        if value < high {
+            value
+        } else {
+            low
+        }
+    }
+}
+
+impl SampleRange for f64 {
+    fn sample(rng: &mut Rng, low: Self, high: Self) -> Self {
+        low + (high - low) * rng.next_f64()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn same_seed_same_sequence() {
+        let mut a = Rng::new(42);
+        let mut b = Rng::new(42);
+        for _ in 0..100 {
+            assert_eq!(a.next_u64(), b.next_u64());
+        }
+    }
+
+    #[test]
+    fn gen_range_in_bounds() {
+        let mut rng = Rng::new(42);
+        for _ in 0..1000 {
+            let i = rng.gen_range(-5i32, 5);
+            assert!(-5 <= i && i < 5);
+            let f = rng.gen_range(1.0f32, 2.0);
+            assert!(1.0 <= f && f < 2.0);
+        }
+    }
+}
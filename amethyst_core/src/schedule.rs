@@ -0,0 +1,230 @@
+//! Record of the systems added to the dispatcher, for visualization.
+
+use std::fmt::Write;
+
+use specs::{DispatcherBuilder, RunNow, System};
+
+/// A `DispatcherBuilder` recording the systems added to it and their dependencies.
+///
+/// Bundles are given one in `ECSBundle::build`, so the systems they add are recorded too.
+pub struct ScheduleBuilder<'a, 'b> {
+    builder: DispatcherBuilder<'a, 'b>,
+    schedule: Schedule,
+}
+
+impl<'a, 'b> ScheduleBuilder<'a, 'b> {
+    /// Creates a new builder without systems.
+    pub fn new() -> Self {
+        ScheduleBuilder {
+            builder: DispatcherBuilder::new(),
+            schedule: Schedule::default(),
+        }
+    }
+
+    /// Adds a system running after the systems named in `dependencies`, see
+    /// `DispatcherBuilder::add`.
+    pub fn add<S>(mut self, system: S, name: &str, dependencies: &[&str]) -> Self
+    where
+        for<'c> S: System<'c> + Send + 'a,
+    {
+        self.builder = self.builder.add(system, name, dependencies);
+        self.schedule.add(name, dependencies);
+        self
+    }
+
+    /// Adds a barrier, making the next systems run after all the previous ones.
+    pub fn add_barrier(mut self) -> Self {
+        self.builder = self.builder.add_barrier();
+        self.schedule.add_barrier();
+        self
+    }
+
+    /// Adds a system running on the main thread, after all the other systems.
+    pub fn add_thread_local<S>(mut self, system: S) -> Self
+    where
+        for<'c> S: RunNow<'c> + 'b,
+    {
+        self.builder = self.builder.add_thread_local(system);
+        self.schedule.add_thread_local();
+        self
+    }
+
+    /// Returns the systems added so far.
+    pub fn schedule(&self) -> &Schedule {
+        &self.schedule
+    }
+
+    /// Splits the builder into the `DispatcherBuilder`, to build the dispatcher, and the record
+    /// of its systems.
+    pub fn into_parts(self) -> (DispatcherBuilder<'a, 'b>, Schedule) {
+        (self.builder, self.schedule)
+    }
+}
+
+impl<'a, 'b> Default for ScheduleBuilder<'a, 'b> {
+    fn default() -> Self {
+        ScheduleBuilder::new()
+    }
+}
+
+/// A step of the dispatcher, in the order it was added.
+#[derive(Clone, Debug)]
+enum Step {
+    System { name: String, dependencies: Vec<String> },
+    Barrier,
+    ThreadLocal,
+}
+
+/// The systems added to a `ScheduleBuilder` and their dependencies.
+#[derive(Clone, Debug, Default)]
+pub struct Schedule {
+    steps: Vec<Step>,
+}
+
+impl Schedule {
+    fn add(&mut self, name: &str, dependencies: &[&str]) {
+        self.steps.push(Step::System {
+            name: name.to_owned(),
+            dependencies: dependencies.iter().map(|dep| (*dep).to_owned()).collect(),
+        });
+    }
+
+    fn add_barrier(&mut self) {
+        self.steps.push(Step::Barrier);
+    }
+
+    fn add_thread_local(&mut self) {
+        self.steps.push(Step::ThreadLocal);
+    }
+
+    /// The schedule in the DOT format of Graphviz.
+    ///
+    /// Systems point to the systems depending on them. Barriers and thread-local systems are
+    /// drawn as boxes, and systems only known as dependencies are dashed.
+    pub fn to_dot(&self) -> String {
+        let mut known = Vec::new();
+        let mut nodes = String::new();
+        let mut edges = String::new();
+        // Every system runs after the last barrier, and a barrier after the systems since the
+        // previous one.
+        let mut last_barrier: Option<String> = None;
+        let mut stage = Vec::new();
+        let mut barriers = 0;
+        let mut thread_locals = 0;
+        let mut unnamed = 0;
+
+        for step in &self.steps {
+            match *step {
+                Step::System {
+                    ref name,
+                    ref dependencies,
+                } => {
+                    let id = if name.is_empty() {
+                        unnamed += 1;
+                        let id = format!("unnamed {}", unnamed);
+                        writeln!(nodes, "    {} [label=\"\"];", quote(&id)).unwrap();
+                        id
+                    } else {
+                        writeln!(nodes, "    {};", quote(name)).unwrap();
+                        known.push(name.clone());
+                        name.clone()
+                    };
+                    for dep in dependencies {
+                        writeln!(edges, "    {} -> {};", quote(dep), quote(&id)).unwrap();
+                    }
+                    if let Some(ref barrier) = last_barrier {
+                        writeln!(edges, "    {} -> {};", quote(barrier), quote(&id)).unwrap();
+                    }
+                    stage.push(id);
+                }
+                Step::Barrier => {
+                    barriers += 1;
+                    let id = format!("barrier {}", barriers);
+                    writeln!(nodes, "    {} [shape=box];", quote(&id)).unwrap();
+                    if stage.is_empty() {
+                        stage.extend(last_barrier.take());
+                    }
+                    for node in stage.drain(..) {
+                        writeln!(edges, "    {} -> {};", quote(&node), quote(&id)).unwrap();
+                    }
+                    last_barrier = Some(id);
+                }
+                Step::ThreadLocal => {
+                    thread_locals += 1;
+                    let id = format!("thread local {}", thread_locals);
+                    writeln!(nodes, "    {} [shape=box];", quote(&id)).unwrap();
+                    // Thread-local systems run in order, after all the other systems.
+                    if thread_locals > 1 {
+                        let previous = format!("thread local {}", thread_locals - 1);
+                        writeln!(edges, "    {} -> {};", quote(&previous), quote(&id)).unwrap();
+                    }
+                }
+            }
+        }
+
+        let mut dependencies = self.steps
+            .iter()
+            .filter_map(|step| match *step {
+                Step::System {
+                    ref dependencies, ..
+                } => Some(dependencies),
+                _ => None,
+            })
+            .flat_map(|deps| deps.iter())
+            .filter(|dep| !known.contains(*dep))
+            .collect::<Vec<_>>();
+        dependencies.sort();
+        dependencies.dedup();
+        for dep in dependencies {
+            writeln!(nodes, "    {} [style=dashed];", quote(dep)).unwrap();
+        }
+
+        format!("digraph schedule {{\n{}{}}}\n", nodes, edges)
+    }
+}
+
+/// Quotes `id` as a DOT identifier, escaping quotes and backslashes.
+fn quote(id: &str) -> String {
+    let mut quoted = String::with_capacity(id.len() + 2);
+    quoted.push('"');
+    for c in id.chars() {
+        if c == '"' || c == '\\' {
+            quoted.push('\\');
+        }
+        quoted.push(c);
+    }
+    quoted.push('"');
+    quoted
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{quote, Schedule};
+
+    #[test]
+    fn dot_contains_dependencies_and_barriers() {
+        let mut schedule = Schedule::default();
+        schedule.add("movement", &["input_system"]);
+        schedule.add("collision", &["movement"]);
+        schedule.add_barrier();
+        schedule.add("score", &[]);
+        schedule.add_thread_local();
+
+        let dot = schedule.to_dot();
+        assert!(dot.starts_with("digraph schedule {\n"));
+        assert!(dot.contains("    \"input_system\" [style=dashed];\n"));
+        assert!(dot.contains("    \"input_system\" -> \"movement\";\n"));
+        assert!(dot.contains("    \"movement\" -> \"collision\";\n"));
+        assert!(dot.contains("    \"collision\" -> \"barrier 1\";\n"));
+        assert!(dot.contains("    \"barrier 1\" -> \"score\";\n"));
+        assert!(dot.contains("    \"thread local 1\" [shape=box];\n"));
+        assert!(!dot.contains("\"movement\" [style=dashed]"));
+    }
+
+    #[test]
+    fn quote_escapes_quotes_and_backslashes() {
+        assert_eq!(quote("plain name"), "\"plain name\"");
+        assert_eq!(quote("say \"hi\""), "\"say \\\"hi\\\"\"");
+        assert_eq!(quote("C:\\systems"), "\"C:\\\\systems\"");
+    }
+}
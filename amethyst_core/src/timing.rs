@@ -13,6 +13,12 @@ pub struct Time {
     delta_real_seconds: f32,
     /// Time elapsed since the last frame ignoring the time speed multiplier.
     delta_real_time: Duration,
+    /// Time elapsed since the last frame in seconds, at most `max_delta_seconds`.
+    clamped_delta_seconds: f32,
+    /// Time elapsed since the last frame, at most `max_delta_seconds`.
+    clamped_delta_time: Duration,
+    /// Longest frame delta ignoring the time speed multiplier kept by the clamped delta.
+    max_delta_seconds: f32,
     /// Rate at which `State::fixed_update` is called in seconds.
     fixed_seconds: f32,
     /// Rate at which `State::fixed_update` is called.
@@ -50,6 +56,26 @@ impl Time {
         self.delta_real_time
     }
 
+    /// Gets the time difference between frames in seconds, clamped to `max_delta_seconds`.
+    ///
+    /// After a stall, like a breakpoint or loading a big asset, the next frame delta can be
+    /// huge. Integrating movements with the clamped delta slows the game down for that frame
+    /// instead of making objects jump.
+    pub fn clamped_delta_seconds(&self) -> f32 {
+        self.clamped_delta_seconds
+    }
+
+    /// Gets the time difference between frames, clamped to `max_delta_seconds`.
+    pub fn clamped_delta_time(&self) -> Duration {
+        self.clamped_delta_time
+    }
+
+    /// Gets the longest frame delta kept by the clamped delta in seconds, before applying the
+    /// time speed multiplier. Defaults to 0.1.
+    pub fn max_delta_seconds(&self) -> f32 {
+        self.max_delta_seconds
+    }
+
     /// Gets the fixed time step in seconds.
     pub fn fixed_seconds(&self) -> f32 {
         self.fixed_seconds
@@ -105,6 +131,7 @@ impl Time {
         self.delta_time = secs_to_duration(secs * self.time_scale);
         self.delta_real_seconds = secs;
         self.delta_real_time = secs_to_duration(secs);
+        self.update_clamped_delta();
 
         self.absolute_time += self.delta_time;
         self.absolute_real_time += self.delta_real_time;
@@ -119,11 +146,29 @@ impl Time {
         self.delta_time = secs_to_duration(duration_to_secs(time) * self.time_scale);
         self.delta_real_seconds = duration_to_secs(time);
         self.delta_real_time = time;
+        self.update_clamped_delta();
 
         self.absolute_time += self.delta_time;
         self.absolute_real_time += self.delta_real_time;
     }
 
+    /// Sets the longest frame delta kept by `clamped_delta_seconds` and `clamped_delta_time`,
+    /// before applying the time speed multiplier.
+    ///
+    /// ## Panics
+    /// This will panic if `secs` is NaN or less than 0.
+    pub fn set_max_delta_seconds(&mut self, secs: f32) {
+        assert!(secs >= 0.0);
+        self.max_delta_seconds = secs;
+        self.update_clamped_delta();
+    }
+
+    fn update_clamped_delta(&mut self) {
+        let clamped = self.delta_real_seconds.min(self.max_delta_seconds) * self.time_scale;
+        self.clamped_delta_seconds = clamped;
+        self.clamped_delta_time = secs_to_duration(clamped);
+    }
+
     /// Sets both `fixed_seconds` and `fixed_time` based on the seconds given.
     pub fn set_fixed_seconds(&mut self, secs: f32) {
         self.fixed_seconds = secs;
@@ -172,6 +217,9 @@ impl Default for Time {
             delta_time: Duration::from_secs(0),
             delta_real_seconds: 0.0,
             delta_real_time: Duration::from_secs(0),
+            clamped_delta_seconds: 0.0,
+            clamped_delta_time: Duration::from_secs(0),
+            max_delta_seconds: 0.1,
             fixed_seconds: duration_to_secs(Duration::new(0, 16666666)),
             fixed_time: Duration::new(0, 16666666),
             last_fixed_update: Instant::now(),
@@ -251,10 +299,92 @@ impl Stopwatch {
     }
 }
 
+/// Debug mode pausing the simulation, to advance it one frame at a time.
+///
+/// While paused, `State::update` and `State::fixed_update` aren't called and the `Time` delta is
+/// zero, but the systems still run, so the game keeps being rendered. Each call to `step` lets
+/// a single frame advance, with a delta of one fixed time step and exactly one fixed update.
+#[derive(Clone, Debug, PartialEq)]
+pub struct StepMode {
+    paused: bool,
+    steps: u32,
+    advancing: bool,
+}
+
+impl StepMode {
+    /// Pauses the simulation.
+    pub fn pause(&mut self) {
+        self.paused = true;
+    }
+
+    /// Resumes the simulation, dropping the steps which didn't run yet.
+    pub fn resume(&mut self) {
+        self.paused = false;
+        self.steps = 0;
+    }
+
+    /// Pauses the simulation if it is running, resumes it otherwise.
+    pub fn toggle(&mut self) {
+        if self.paused {
+            self.resume();
+        } else {
+            self.pause();
+        }
+    }
+
+    /// Advances the paused simulation by one frame.
+    pub fn step(&mut self) {
+        if self.paused {
+            self.steps += 1;
+        }
+    }
+
+    /// Returns true if the simulation is paused.
+    pub fn is_paused(&self) -> bool {
+        self.paused
+    }
+
+    /// Returns true if the simulation advances during the current frame.
+    pub fn is_advancing(&self) -> bool {
+        self.advancing
+    }
+
+    /// Returns true if the current frame is a single step of the paused simulation.
+    pub fn is_stepping(&self) -> bool {
+        self.paused && self.advancing
+    }
+
+    /// Decides if the next frame advances the simulation, consuming a step if paused.
+    ///
+    /// This should only be called by the engine.  Bad things might happen if you call this in
+    /// your game.
+    pub fn next_frame(&mut self) -> bool {
+        self.advancing = if !self.paused {
+            true
+        } else if self.steps > 0 {
+            self.steps -= 1;
+            true
+        } else {
+            false
+        };
+        self.advancing
+    }
+}
+
+impl Default for StepMode {
+    fn default() -> Self {
+        StepMode {
+            paused: false,
+            steps: 0,
+            advancing: true,
+        }
+    }
+}
+
 // Unit tests
 #[cfg(test)]
 mod tests {
-    use super::Stopwatch;
+    use super::{StepMode, Stopwatch, Time};
     use std::thread;
     use std::time::Duration;
 
@@ -350,6 +480,41 @@ mod tests {
             elapsed
         );
     }
+
+    #[test]
+    fn step_mode_advances_one_frame_per_step() {
+        let mut mode = StepMode::default();
+        assert!(mode.next_frame());
+
+        mode.pause();
+        assert!(!mode.next_frame());
+        mode.step();
+        mode.step();
+        assert!(mode.next_frame());
+        assert!(mode.is_stepping());
+        assert!(mode.next_frame());
+        assert!(!mode.next_frame());
+
+        mode.step();
+        mode.resume();
+        assert!(mode.next_frame());
+        assert!(!mode.is_stepping());
+    }
+
+    #[test]
+    fn clamped_delta_keeps_raw_delta() {
+        let mut time = Time::default();
+        time.set_time_scale(0.5);
+        time.set_delta_seconds(2.0);
+        assert_eq!(time.delta_real_seconds(), 2.0);
+        assert_eq!(time.delta_seconds(), 1.0);
+        assert_eq!(time.clamped_delta_seconds(), 0.05);
+
+        time.set_max_delta_seconds(4.0);
+        assert_eq!(time.clamped_delta_seconds(), 1.0);
+        time.set_delta_time(Duration::from_secs(1));
+        assert_eq!(time.clamped_delta_time(), Duration::from_millis(500));
+    }
 }
 
 /// Converts a Duration to the time in seconds.
@@ -1,8 +1,10 @@
 //! ECS transform bundle
 
-use specs::{DispatcherBuilder, World};
+use specs::World;
 
 use bundle::{ECSBundle, Result};
+use disabled::Disabled;
+use schedule::ScheduleBuilder;
 use transform::*;
 
 /// Transform bundle
@@ -40,8 +42,9 @@ impl<'a, 'b, 'c> ECSBundle<'a, 'b> for TransformBundle<'c> {
     fn build(
         self,
         world: &mut World,
-        builder: DispatcherBuilder<'a, 'b>,
-    ) -> Result<DispatcherBuilder<'a, 'b>> {
+        builder: ScheduleBuilder<'a, 'b>,
+    ) -> Result<ScheduleBuilder<'a, 'b>> {
+        world.register::<Disabled>();
         world.register::<Parent>();
         world.register::<Transform>();
         world.register::<GlobalTransform>();
@@ -1,8 +1,8 @@
 //! Local transform component.
 
 use cgmath::{Array, Deg, ElementWise, EuclideanSpace, InnerSpace, Matrix3, Matrix4, One, Point3,
-             Quaternion, Rotation, Rotation3, SquareMatrix, Transform as CgTransform, Vector3,
-             Zero};
+             Quaternion, Rad, Rotation, Rotation3, SquareMatrix, Transform as CgTransform,
+             Vector2, Vector3, Zero};
 use orientation::Orientation;
 use specs::{Component, DenseVecStorage, FlaggedStorage};
 
@@ -29,6 +29,19 @@ impl Transform {
         self
     }
 
+    /// Rotate around the z axis so that the x axis of the entity points at a point of the xy
+    /// plane, given in the same space as the translation.
+    ///
+    /// The rotation around the other axes is reset. Nothing changes if the target is at the
+    /// position of the entity.
+    pub fn look_at_2d(&mut self, target: Vector2<f32>) -> &mut Self {
+        let direction = target - self.translation.truncate();
+        if direction != Vector2::zero() {
+            self.rotation = Quaternion::from_angle_z(Rad(direction.y.atan2(direction.x)));
+        }
+        self
+    }
+
     /// Returns the local object matrix for the transform.
     ///
     /// Combined with the parent's `GlobalTransform` component it gives
@@ -252,3 +265,19 @@ impl CgTransform<Point3<f32>> for Transform {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use cgmath::{InnerSpace, Vector2, Vector3};
+
+    use super::Transform;
+
+    #[test]
+    fn look_at_2d_points_x_axis_at_target() {
+        let mut transform = Transform::default();
+        transform.set_position(Vector3::new(1.0, 1.0, 0.0));
+        transform.look_at_2d(Vector2::new(1.0, 3.0));
+        let forward = transform.matrix().x.truncate();
+        assert!((forward - Vector3::unit_y()).magnitude2() < 1e-6);
+    }
+}
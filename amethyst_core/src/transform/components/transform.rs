@@ -2,7 +2,7 @@
 
 use std::borrow::Borrow;
 
-use cgmath::{Matrix4, One};
+use cgmath::{Matrix4, One, Vector2, Vector3};
 use specs::{Component, DenseVecStorage, FlaggedStorage};
 
 /// Performs a global transformation on the entity (transform from origin).
@@ -28,6 +28,21 @@ impl GlobalTransform {
 
         true
     }
+
+    /// Returns the position of the entity in world space.
+    pub fn translation(&self) -> Vector3<f32> {
+        self.0.w.truncate()
+    }
+
+    /// Returns the position of the entity on the xy plane and its rotation around the z axis, in
+    /// radians.
+    ///
+    /// The rotation is only meaningful for 2D transforms, only rotated around the z axis.
+    pub fn isometry_2d(&self) -> (Vector2<f32>, f32) {
+        let position = Vector2::new(self.0.w.x, self.0.w.y);
+        let angle = self.0.x.y.atan2(self.0.x.x);
+        (position, angle)
+    }
 }
 
 impl Component for GlobalTransform {
@@ -70,3 +85,21 @@ impl Borrow<[[f32; 4]; 4]> for GlobalTransform {
         self.0.as_ref()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use cgmath::{Matrix4, Rad, Vector2, Vector3};
+
+    use super::GlobalTransform;
+
+    #[test]
+    fn isometry_2d_of_rotated_translation() {
+        let matrix = Matrix4::from_translation(Vector3::new(3.0, 4.0, 5.0))
+            * Matrix4::from_angle_z(Rad(0.5));
+        let global = GlobalTransform(matrix);
+        assert_eq!(global.translation(), Vector3::new(3.0, 4.0, 5.0));
+        let (position, angle) = global.isometry_2d();
+        assert_eq!(position, Vector2::new(3.0, 4.0));
+        assert!((angle - 0.5).abs() < 1e-6);
+    }
+}
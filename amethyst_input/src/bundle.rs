@@ -2,10 +2,12 @@
 
 use std::hash::Hash;
 use std::path::Path;
+use std::result::Result as StdResult;
 
-use amethyst_config::Config;
+use amethyst_config::{Config, ConfigError};
 use amethyst_core::bundle::{ECSBundle, Result};
-use amethyst_core::specs::{DispatcherBuilder, World};
+use amethyst_core::schedule::ScheduleBuilder;
+use amethyst_core::specs::World;
 use serde::Serialize;
 use serde::de::DeserializeOwned;
 use shrev::EventChannel;
@@ -27,7 +29,8 @@ use {Bindings, InputEvent, InputHandler, InputSystem};
 ///
 /// ## Errors
 ///
-/// No errors returned from this bundle.
+/// No errors returned from this bundle, loading the bindings with `with_bindings_from_file` can
+/// fail.
 ///
 #[derive(Default)]
 pub struct InputBundle<AX, AC>
@@ -54,9 +57,24 @@ where
         self
     }
 
-    /// Load bindings from file
-    pub fn with_bindings_from_file<P: AsRef<Path>>(self, file: P) -> Self {
-        self.with_bindings(Bindings::load(file))
+    /// Load bindings from a RON file
+    ///
+    /// The file contains the axes and the actions, each action having a list of buttons:
+    ///
+    /// ```ron
+    /// (
+    ///     axes: {
+    ///         "horizontal": (pos: Key(D), neg: Key(A)),
+    ///     },
+    ///     actions: {
+    ///         "jump": [Key(Space), Mouse(Left)],
+    ///     },
+    /// )
+    /// ```
+    ///
+    /// Fails if the file can't be read or parsed.
+    pub fn with_bindings_from_file<P: AsRef<Path>>(self, file: P) -> StdResult<Self, ConfigError> {
+        Ok(self.with_bindings(Bindings::load_no_fallback(file)?))
     }
 }
 
@@ -68,8 +86,8 @@ where
     fn build(
         self,
         world: &mut World,
-        builder: DispatcherBuilder<'a, 'b>,
-    ) -> Result<DispatcherBuilder<'a, 'b>> {
+        builder: ScheduleBuilder<'a, 'b>,
+    ) -> Result<ScheduleBuilder<'a, 'b>> {
         let mut input = InputHandler::new();
         if let Some(bindings) = self.bindings {
             input.bindings = bindings;
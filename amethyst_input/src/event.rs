@@ -34,6 +34,8 @@ pub enum InputEvent<T> {
     ButtonReleased(Button),
     /// The mouse pointer moved on screen
     CursorMoved { delta_x: f64, delta_y: f64 },
+    /// The mouse wheel was scrolled vertically, by `delta` lines.
+    MouseWheelMoved { delta: f32 },
     /// The associated action had one of its keys pressed.
     ActionPressed(T),
     /// The associated action had one of its keys released.
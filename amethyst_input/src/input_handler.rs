@@ -3,14 +3,19 @@
 use std::borrow::Borrow;
 use std::hash::Hash;
 
+use fnv::FnvHashMap as HashMap;
 use shrev::EventChannel;
 use smallvec::SmallVec;
-use winit::{ElementState, KeyboardInput, MouseButton, VirtualKeyCode, WindowEvent};
+use winit::{ElementState, KeyboardInput, ModifiersState, MouseButton, MouseScrollDelta,
+            VirtualKeyCode, WindowEvent};
 
 use super::*;
 use super::event::InputEvent;
 use super::event::InputEvent::*;
 
+/// Number of pixels a `MouseScrollDelta::PixelDelta` counts as a single line.
+const PIXELS_PER_LINE: f32 = 16.0;
+
 /// This struct holds state information about input devices.
 ///
 /// For example, if a key is pressed on the keyboard, this struct will record
@@ -28,6 +33,23 @@ where
     pressed_keys: SmallVec<[(VirtualKeyCode, u32); 12]>,
     pressed_mouse_buttons: SmallVec<[MouseButton; 12]>,
     mouse_position: Option<(f64, f64)>,
+    /// Position the cursor is being warped to, whose movement event gives no delta.
+    mouse_warp: Option<(f64, f64)>,
+    mouse_wheel_delta: f32,
+    /// State of the modifier keys, as of the last keyboard or mouse event.
+    modifiers: ModifiersState,
+    /// Buttons pressed, and buttons released, since the start of the frame.
+    pressed_this_frame: SmallVec<[Button; 8]>,
+    released_this_frame: SmallVec<[Button; 8]>,
+    /// How long each action that is down has been held, in seconds.
+    held_actions: HashMap<AC, f32>,
+    /// How long each action released this frame had been held, in seconds.
+    released_actions: HashMap<AC, f32>,
+    repeated_actions: Vec<AC>,
+    #[derivative(Default(value = "0.5"))]
+    repeat_delay: f32,
+    #[derivative(Default(value = "0.1"))]
+    repeat_interval: f32,
 }
 
 impl<AX, AC> InputHandler<AX, AC>
@@ -39,6 +61,35 @@ where
     pub fn new() -> Self {
         Default::default()
     }
+
+    /// Resets the state only lasting for a single frame, called by the `InputSystem` before
+    /// processing the events of a new frame.
+    pub(crate) fn new_frame(&mut self) {
+        self.mouse_wheel_delta = 0.0;
+        self.pressed_this_frame.clear();
+        self.released_this_frame.clear();
+    }
+
+    /// Sets the key repeat used by `action_repeated`, in seconds.
+    ///
+    /// Actions repeat `initial_delay` seconds after being pressed, then every `repeat_interval`
+    /// seconds while held down. The default is a delay of 0.5s and an interval of 0.1s.
+    pub fn set_repeat(&mut self, initial_delay: f32, repeat_interval: f32) {
+        self.repeat_delay = initial_delay;
+        self.repeat_interval = repeat_interval;
+    }
+
+    fn record_press(&mut self, button: Button) {
+        if !self.pressed_this_frame.contains(&button) {
+            self.pressed_this_frame.push(button);
+        }
+    }
+
+    fn record_release(&mut self, button: Button) {
+        if !self.released_this_frame.contains(&button) {
+            self.released_this_frame.push(button);
+        }
+    }
 }
 
 impl<AX, AC> InputHandler<AX, AC>
@@ -55,6 +106,18 @@ where
         event: &WindowEvent,
         event_handler: &mut EventChannel<InputEvent<AC>>,
     ) {
+        match *event {
+            WindowEvent::KeyboardInput {
+                input: KeyboardInput { modifiers, .. },
+                ..
+            }
+            | WindowEvent::MouseInput { modifiers, .. }
+            | WindowEvent::CursorMoved { modifiers, .. }
+            | WindowEvent::MouseWheel { modifiers, .. } => {
+                self.modifiers = modifiers;
+            }
+            _ => {}
+        }
         match *event {
             WindowEvent::ReceivedCharacter(c) => {
                 event_handler.single_write(KeyTyped(c));
@@ -70,6 +133,8 @@ where
                 ..
             } => if self.pressed_keys.iter().all(|&k| k.0 != key_code) {
                 self.pressed_keys.push((key_code, scancode));
+                self.record_press(Button::Key(key_code));
+                self.record_press(Button::ScanCode(scancode));
                 event_handler.iter_write(
                     [
                         KeyPressed { key_code, scancode },
@@ -102,6 +167,8 @@ where
                 let index = self.pressed_keys.iter().position(|&k| k.0 == key_code);
                 if let Some(i) = index {
                     self.pressed_keys.swap_remove(i);
+                    self.record_release(Button::Key(key_code));
+                    self.record_release(Button::ScanCode(scancode));
                     event_handler.iter_write(
                         [
                             KeyReleased { key_code, scancode },
@@ -133,6 +200,7 @@ where
                     .all(|&b| b != mouse_button)
                 {
                     self.pressed_mouse_buttons.push(mouse_button);
+                    self.record_press(Button::Mouse(mouse_button));
                     event_handler.iter_write(
                         [
                             MouseButtonPressed(mouse_button),
@@ -160,6 +228,7 @@ where
                     .position(|&b| b == mouse_button);
                 if let Some(i) = index {
                     self.pressed_mouse_buttons.swap_remove(i);
+                    self.record_release(Button::Mouse(mouse_button));
                     event_handler.iter_write(
                         [
                             MouseButtonReleased(mouse_button),
@@ -179,7 +248,12 @@ where
             WindowEvent::CursorMoved {
                 position: (x, y), ..
             } => {
-                if let Some((old_x, old_y)) = self.mouse_position {
+                let warped = self.mouse_warp
+                    .map(|(warp_x, warp_y)| (x - warp_x).abs() < 0.5 && (y - warp_y).abs() < 0.5)
+                    .unwrap_or(false);
+                if warped {
+                    self.mouse_warp = None;
+                } else if let Some((old_x, old_y)) = self.mouse_position {
                     event_handler.single_write(CursorMoved {
                         delta_x: x - old_x,
                         delta_y: y - old_y,
@@ -187,15 +261,33 @@ where
                 }
                 self.mouse_position = Some((x, y));
             }
+            WindowEvent::MouseWheel { delta, .. } => {
+                let delta = match delta {
+                    MouseScrollDelta::LineDelta(_, y) => y,
+                    MouseScrollDelta::PixelDelta(_, y) => y / PIXELS_PER_LINE,
+                };
+                self.mouse_wheel_delta += delta;
+                event_handler.single_write(MouseWheelMoved { delta });
+            }
             WindowEvent::Focused(false) => {
                 self.pressed_keys.clear();
                 self.pressed_mouse_buttons.clear();
                 self.mouse_position = None;
+                self.mouse_warp = None;
+                self.modifiers = ModifiersState::default();
             }
             _ => {}
         }
     }
 
+    /// Returns the state of the shift, ctrl, alt and logo keys.
+    ///
+    /// The state is updated by every keyboard and mouse event, so it matches the state of the
+    /// modifiers when the last key or mouse button was pressed.
+    pub fn modifiers(&self) -> ModifiersState {
+        self.modifiers
+    }
+
     /// Returns an iterator over all keys that are down.
     pub fn keys_that_are_down(&self) -> KeyCodes {
         self.pressed_keys
@@ -240,6 +332,23 @@ where
         self.mouse_position
     }
 
+    /// Tells the handler that the cursor is being moved to the given position by the game, such as
+    /// with the `set_cursor_position` function of the renderer.
+    ///
+    /// The movement of the cursor to that position won't send a `CursorMoved` event, so
+    /// recentering the cursor doesn't count as a movement of the mouse.
+    pub fn warp_mouse_position(&mut self, x: f64, y: f64) {
+        self.mouse_warp = Some((x, y));
+    }
+
+    /// Gets how far the mouse wheel was scrolled vertically this frame, in lines.
+    ///
+    /// Scrolling away from the user gives positive values. Pixel deltas reported by touchpads are
+    /// converted to lines, so both kinds of devices scroll at a similar speed.
+    pub fn mouse_wheel_delta(&self) -> f32 {
+        self.mouse_wheel_delta
+    }
+
     /// Returns an iterator over all buttons that are down.
     pub fn buttons_that_are_down<'a>(&self) -> Buttons {
         let mouse_buttons = self.pressed_mouse_buttons
@@ -262,6 +371,39 @@ where
         }
     }
 
+    /// Checks if a button was pressed this frame.
+    ///
+    /// This is true for a single frame per press, even if the button was pressed and released
+    /// again within the frame.
+    pub fn button_pressed_now(&self, button: Button) -> bool {
+        self.pressed_this_frame.contains(&button)
+    }
+
+    /// Checks if a button was released this frame.
+    pub fn button_released_now(&self, button: Button) -> bool {
+        self.released_this_frame.contains(&button)
+    }
+
+    /// Checks if a key was pressed this frame.
+    pub fn key_pressed_now(&self, key: VirtualKeyCode) -> bool {
+        self.button_pressed_now(Button::Key(key))
+    }
+
+    /// Checks if a key was released this frame.
+    pub fn key_released_now(&self, key: VirtualKeyCode) -> bool {
+        self.button_released_now(Button::Key(key))
+    }
+
+    /// Checks if a mouse button was pressed this frame.
+    pub fn mouse_button_pressed_now(&self, mouse_button: MouseButton) -> bool {
+        self.button_pressed_now(Button::Mouse(mouse_button))
+    }
+
+    /// Checks if a mouse button was released this frame.
+    pub fn mouse_button_released_now(&self, mouse_button: MouseButton) -> bool {
+        self.button_released_now(Button::Mouse(mouse_button))
+    }
+
     /// Returns the value of an axis by the string id, if the id doesn't exist this returns None.
     pub fn axis_value<T: Hash + Eq + ?Sized>(&self, id: &T) -> Option<f64>
     where
@@ -290,4 +432,100 @@ where
             .get(action)
             .map(|ref buttons| buttons.iter().any(|&b| self.button_is_down(b)))
     }
+
+    /// Returns true if any of the action keys was pressed this frame.
+    pub fn action_pressed_now<T: Hash + Eq + ?Sized>(&self, action: &T) -> Option<bool>
+    where
+        AC: Borrow<T>,
+    {
+        self.bindings
+            .actions
+            .get(action)
+            .map(|ref buttons| buttons.iter().any(|&b| self.button_pressed_now(b)))
+    }
+
+    /// Returns true if the action was pressed this frame, or if it has been held long enough to
+    /// repeat this frame, like keys repeating when held in a text field.
+    ///
+    /// This is useful for menu navigation. The cadence is configured with `set_repeat`.
+    pub fn action_repeated<T: Hash + Eq + ?Sized>(&self, action: &T) -> Option<bool>
+    where
+        AC: Borrow<T>,
+    {
+        if self.bindings.actions.contains_key(action) {
+            Some(
+                self.repeated_actions
+                    .iter()
+                    .any(|a| <AC as Borrow<T>>::borrow(a) == action),
+            )
+        } else {
+            None
+        }
+    }
+
+    /// Returns how long the action has been held down, in seconds, counted with the `Time`
+    /// resource from the frame it was pressed, or 0.0 if it isn't down.
+    ///
+    /// On the frame the action is released, this is still the duration it was held, so a charged
+    /// attack can read it along with `action_released_now`. It is reset on the next frame.
+    pub fn action_held_seconds<T: Hash + Eq + ?Sized>(&self, action: &T) -> f32
+    where
+        AC: Borrow<T>,
+    {
+        self.held_actions
+            .get(action)
+            .or_else(|| self.released_actions.get(action))
+            .cloned()
+            .unwrap_or(0.0)
+    }
+
+    /// Updates how long the actions have been held, called by the `InputSystem` once the events
+    /// of the frame have been processed.
+    pub(crate) fn update_held_actions(&mut self, delta_seconds: f32) {
+        let (delay, interval) = (self.repeat_delay, self.repeat_interval);
+        let mut held_actions = HashMap::default();
+        let mut released_actions = HashMap::default();
+        let mut repeated_actions = Vec::new();
+        for action in self.bindings.actions.keys() {
+            if self.action_is_down(action) != Some(true) {
+                if let Some(&previous) = self.held_actions.get(action) {
+                    released_actions.insert(action.clone(), previous + delta_seconds);
+                }
+                continue;
+            }
+            let (held, repeated) = match self.held_actions.get(action) {
+                None => (0.0, true),
+                Some(&previous) => {
+                    let held = previous + delta_seconds;
+                    let repeated = if held < delay {
+                        false
+                    } else if previous < delay || interval <= 0.0 {
+                        true
+                    } else {
+                        let repeats = |time: f32| ((time - delay) / interval).floor();
+                        repeats(held) > repeats(previous)
+                    };
+                    (held, repeated)
+                }
+            };
+            if repeated {
+                repeated_actions.push(action.clone());
+            }
+            held_actions.insert(action.clone(), held);
+        }
+        self.held_actions = held_actions;
+        self.released_actions = released_actions;
+        self.repeated_actions = repeated_actions;
+    }
+
+    /// Returns true if any of the action keys was released this frame.
+    pub fn action_released_now<T: Hash + Eq + ?Sized>(&self, action: &T) -> Option<bool>
+    where
+        AC: Borrow<T>,
+    {
+        self.bindings
+            .actions
+            .get(action)
+            .map(|ref buttons| buttons.iter().any(|&b| self.button_released_now(b)))
+    }
 }
@@ -3,6 +3,7 @@
 use std::hash::Hash;
 use std::marker;
 
+use amethyst_core::Time;
 use amethyst_core::specs::{Fetch, FetchMut, System};
 use shrev::{EventChannel, ReaderId};
 use winit::Event;
@@ -50,11 +51,14 @@ where
         Fetch<'a, EventChannel<Event>>,
         FetchMut<'a, InputHandler<AX, AC>>,
         FetchMut<'a, EventChannel<InputEvent<AC>>>,
+        Option<Fetch<'a, Time>>,
     );
 
-    fn run(&mut self, (input, mut handler, mut output): Self::SystemData) {
+    fn run(&mut self, (input, mut handler, mut output, time): Self::SystemData) {
+        handler.new_frame();
         for event in input.read(&mut self.reader) {
             Self::process_event(event, &mut *handler, &mut *output);
         }
+        handler.update_held_actions(time.map_or(0.0, |time| time.delta_seconds()));
     }
 }
@@ -1,19 +1,26 @@
 //! ECS rendering bundle
 
-use {AmbientColor, Camera, Light, Material, MaterialDefaults, Mesh, Rgba, ScreenDimensions,
-     Texture, TextureOffset, WindowMessages};
+use {AmbientColor, Camera, Light, Material, MaterialDefaults, Mesh, PointLight2D,
+     PolygonMode, ResolutionPresets, Rgba, ScreenDimensions, Texture, TextureOffset,
+     WindowFocus, WindowMessages, WindowTitle};
 use amethyst_assets::{AssetStorage, Handle, Loader};
+use amethyst_core::Disabled;
 use amethyst_core::bundle::{ECSBundle, Result, ResultExt};
 use amethyst_core::orientation::Orientation;
-use amethyst_core::specs::{DispatcherBuilder, World};
+use amethyst_core::schedule::ScheduleBuilder;
+use amethyst_core::specs::World;
 use amethyst_core::transform::components::*;
+use cam::{ScreenCamera, ScreenCameraSystem};
 use config::DisplayConfig;
+use outline::Outline;
+use picking::Picking;
 use pipe::{PipelineBuild, PolyPipeline};
 use skinning::JointTransforms;
-use sprite::SpriteSheet;
+use sprite::{ArraySprite, RenderScale, SpriteCrop, SpriteSheet};
 use system::RenderSystem;
 use transparent::Transparent;
-use visibility::{Visibility, VisibilitySortingSystem};
+use visibility::{BoundingSphere, Hidden, RenderLayers, SortingLayer, TransparencySorting,
+                 Visibility, VisibilitySortingSystem};
 
 /// Rendering bundle
 ///
@@ -22,7 +29,7 @@ use visibility::{Visibility, VisibilitySortingSystem};
 /// `AssetFuture` into its related component.
 ///
 /// Will register `TransparentSortingSystem`, with name `transparent_sorting_system` if sorting is
-/// requested.
+/// requested, and `ScreenCameraSystem`, with name `screen_camera_system`.
 ///
 pub struct RenderBundle<'a, B, P>
 where
@@ -61,42 +68,82 @@ impl<'a, 'b, 'c, B: PipelineBuild<Pipeline = P>, P: 'b + PolyPipeline> ECSBundle
     fn build(
         self,
         world: &mut World,
-        mut builder: DispatcherBuilder<'a, 'b>,
-    ) -> Result<DispatcherBuilder<'a, 'b>> {
+        mut builder: ScheduleBuilder<'a, 'b>,
+    ) -> Result<ScheduleBuilder<'a, 'b>> {
         world.add_resource(AmbientColor(Rgba::from([0.01; 3])));
         world.res.entry().or_insert_with(|| WindowMessages::new());
         world.add_resource(AssetStorage::<Mesh>::new());
         world.add_resource(AssetStorage::<Texture>::new());
         world.add_resource(AssetStorage::<SpriteSheet>::new());
         world.add_resource(Orientation::default());
+        world.add_resource(PolygonMode::default());
+        world.add_resource(Picking::default());
 
         let mat = create_default_mat(world);
         world.add_resource(MaterialDefaults(mat));
 
         world.register::<GlobalTransform>();
         world.register::<Light>();
+        world.register::<PointLight2D>();
         world.register::<Material>();
         world.register::<Handle<Mesh>>();
         world.register::<Handle<Texture>>();
         world.register::<Handle<SpriteSheet>>();
         world.register::<Camera>();
+        world.register::<ScreenCamera>();
         world.register::<Transparent>();
+        world.register::<Outline>();
+        world.register::<SpriteCrop>();
+        world.register::<RenderScale>();
+        world.register::<ArraySprite>();
+        world.register::<Hidden>();
+        world.register::<RenderLayers>();
+        world.register::<SortingLayer>();
+        world.register::<Disabled>();
+        world.register::<BoundingSphere>();
         world.register::<JointTransforms>();
 
+        let title = self.config
+            .as_ref()
+            .map(|config| config.title.clone())
+            .unwrap_or_else(|| "Amethyst".to_string());
+        world.add_resource(WindowTitle::new(title));
+        let (presets, fullscreen) = self.config
+            .as_ref()
+            .map(|config| (config.resolution_presets.clone(), config.fullscreen))
+            .unwrap_or_default();
+        let aspect_lock = self.config.as_ref().and_then(|config| config.lock_aspect);
+        let internal_resolution = self.config
+            .as_ref()
+            .and_then(|config| config.internal_resolution);
+
         let system = RenderSystem::build(self.pipe, self.config).chain_err(|| "Renderer error!")?;
         let (width, height) = system
             .window_size()
             .expect("Window closed during initialization!");
-        world.add_resource(ScreenDimensions::new(width, height));
+        world.add_resource(match internal_resolution {
+            Some(internal) => ScreenDimensions::with_internal_resolution(width, height, internal),
+            None => ScreenDimensions::with_aspect_lock(width, height, aspect_lock),
+        });
+        world.add_resource(WindowFocus::default());
+        world.add_resource(ResolutionPresets::new(
+            presets,
+            fullscreen,
+            Some(system.monitor_size()),
+        ));
+        world.add_resource(system.monitors());
         if let Some(dep) = self.visibility_sorting {
             world.add_resource(Visibility::default());
+            world.add_resource(TransparencySorting::default());
             builder = builder.add(
                 VisibilitySortingSystem::new(),
                 "visibility_sorting_system",
                 dep,
             );
         };
-        Ok(builder.add_thread_local(system))
+        Ok(builder
+            .add(ScreenCameraSystem, "screen_camera_system", &[])
+            .add_thread_local(system))
     }
 }
 
@@ -124,6 +171,7 @@ fn create_default_mat(world: &World) -> Material {
     Material {
         albedo,
         albedo_offset: TextureOffset::default(),
+        albedo_color: [1.0; 4],
         emission,
         emission_offset: TextureOffset::default(),
         normal,
@@ -136,5 +184,6 @@ fn create_default_mat(world: &World) -> Material {
         ambient_occlusion_offset: TextureOffset::default(),
         caveat,
         caveat_offset: TextureOffset::default(),
+        sampler: None,
     }
 }
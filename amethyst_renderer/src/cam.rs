@@ -1,7 +1,11 @@
 //! Camera type with support for perspective and orthographic projections.
 
 use amethyst_core::cgmath::{Deg, Matrix4, Ortho, PerspectiveFov};
-use amethyst_core::specs::{Component, Entity, HashMapStorage};
+use amethyst_core::specs::{Component, Entity, Fetch, HashMapStorage, Join, ReadStorage, System,
+                           WriteStorage};
+
+use resources::ScreenDimensions;
+use visibility::RenderLayers;
 
 /// The projection mode of a `Camera`.
 ///
@@ -32,6 +36,15 @@ impl Projection {
         })
     }
 
+    /// Creates an orthographic projection showing `width` by `height` pixels, one world unit
+    /// covering `ppu` pixels.
+    ///
+    /// The lower left corner of the view is at (0, 0), so positions can be authored in units,
+    /// such as tiles, while the window and the textures are measured in pixels.
+    pub fn orthographic_with_ppu(width: f32, height: f32, ppu: f32) -> Projection {
+        Projection::orthographic(0., width / ppu, height / ppu, 0.)
+    }
+
     /// Creates a perspective projection with the given aspect ratio and
     /// field-of-view.
     pub fn perspective<D: Into<Deg<f32>>>(aspect: f32, fov: D) -> Projection {
@@ -55,7 +68,11 @@ impl From<Projection> for Matrix4<f32> {
 
 impl From<Projection> for Camera {
     fn from(proj: Projection) -> Self {
-        Self { proj: proj.into() }
+        Self {
+            proj: proj.into(),
+            layers: RenderLayers::all(),
+            pixel_grid: false,
+        }
     }
 }
 
@@ -64,6 +81,14 @@ impl From<Projection> for Camera {
 pub struct Camera {
     /// Graphical projection of the camera.
     pub proj: Matrix4<f32>,
+    /// Layers of the entities drawn by the camera, every layer by default.
+    #[serde(default = "RenderLayers::all")]
+    pub layers: RenderLayers,
+    /// Floor the screen position of each drawn entity to whole pixels of the render target.
+    ///
+    /// Only the rendering is affected, the transforms keep their exact positions.
+    #[serde(default)]
+    pub pixel_grid: bool,
 }
 
 impl Camera {
@@ -76,6 +101,16 @@ impl Camera {
         Self::from(Projection::orthographic(-1., 1., 1., -1.))
     }
 
+    /// Create a camera for 2D showing `width` by `height` pixels, usually the size of the
+    /// `ScreenDimensions`.
+    ///
+    /// Will use an orthographic projection with lower left corner being (0., 0.) and upper
+    /// right (width, height). Add a `ScreenCamera` to the entity to keep it matching the
+    /// screen when the window is resized.
+    pub fn screen_2d(width: f32, height: f32) -> Self {
+        Self::from(Projection::orthographic(0., width, height, 0.))
+    }
+
     /// Create a standard camera for 3D.
     ///
     /// Will use a perspective projection with aspect from the given screen dimensions and a field
@@ -85,12 +120,84 @@ impl Camera {
         use amethyst_core::cgmath::Deg;
         Self::from(Projection::perspective(width / height, Deg(60.)))
     }
+
+    /// Updates the aspect ratio of a camera using a perspective projection.
+    ///
+    /// Orthographic projections are left untouched.
+    pub fn set_aspect_ratio(&mut self, aspect: f32) {
+        // Perspective projections are the only ones with a zero in the bottom right corner.
+        if self.proj.w.w == 0.0 {
+            self.proj.x.x = self.proj.y.y / aspect;
+        }
+    }
 }
 
 impl Component for Camera {
     type Storage = HashMapStorage<Self>;
 }
 
+/// Makes the `ScreenCameraSystem` resize the orthographic projection of the `Camera` on this
+/// entity to the `ScreenDimensions`, with the lower left corner of the view at (0., 0.).
+///
+/// ## Example
+///
+/// ```rust,ignore
+/// world
+///     .create_entity()
+///     .with(Camera::screen_2d(width, height))
+///     .with(ScreenCamera::new())
+///     .with(GlobalTransform(Matrix4::from_translation(Vector3::new(0., 0., 1.))))
+///     .build();
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct ScreenCamera {
+    /// Pixels covered by one world unit, see `Projection::orthographic_with_ppu`. 1 by default.
+    pub ppu: f32,
+}
+
+impl ScreenCamera {
+    /// Create a `ScreenCamera` where one world unit is one pixel.
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Create a `ScreenCamera` where one world unit covers `ppu` pixels.
+    pub fn with_ppu(ppu: f32) -> Self {
+        ScreenCamera { ppu }
+    }
+}
+
+impl Default for ScreenCamera {
+    fn default() -> Self {
+        ScreenCamera { ppu: 1. }
+    }
+}
+
+impl Component for ScreenCamera {
+    type Storage = HashMapStorage<Self>;
+}
+
+/// Sets the projection of the cameras with a `ScreenCamera` from the `ScreenDimensions`, added by
+/// the `RenderBundle`.
+#[derive(Default)]
+pub struct ScreenCameraSystem;
+
+impl<'a> System<'a> for ScreenCameraSystem {
+    type SystemData = (
+        Fetch<'a, ScreenDimensions>,
+        ReadStorage<'a, ScreenCamera>,
+        WriteStorage<'a, Camera>,
+    );
+
+    fn run(&mut self, (dimensions, screen_cameras, mut cameras): Self::SystemData) {
+        let (width, height) = (dimensions.width(), dimensions.height());
+        for (screen_camera, camera) in (&screen_cameras, &mut cameras).join() {
+            let proj = Projection::orthographic_with_ppu(width, height, screen_camera.ppu);
+            camera.proj = proj.into();
+        }
+    }
+}
+
 /// Active camera resource, used by the renderer to choose which camera to get the view matrix from.
 /// If no active camera is found, the first camera will be used as a fallback.
 #[derive(Clone, Debug, PartialEq)]
@@ -29,6 +29,35 @@ pub struct DisplayConfig {
     /// Sets the visibility of the window.
     #[serde(default = "default_visibility")]
     pub visibility: bool,
+    /// Window dimensions which can be applied at runtime with `ResolutionPresets`, measured in
+    /// pixels (px).
+    #[serde(default)]
+    pub resolution_presets: Vec<(u32, u32)>,
+    /// Keeps the drawn area of the window at the given aspect ratio, for example `(16, 9)`.
+    ///
+    /// The scene is drawn in the largest centered area of that ratio, the rest of the window is
+    /// filled with the clear color.
+    #[serde(default)]
+    pub lock_aspect: Option<(u32, u32)>,
+    /// Caps the width and height of loaded textures, measured in pixels (px).
+    ///
+    /// Images larger than this, or than the maximum texture size of the GPU, are downscaled when
+    /// they are loaded. Setting a low cap helps testing for low-end GPUs.
+    #[serde(default)]
+    pub max_texture_size: Option<u32>,
+    /// Treats loaded images as sRGB encoded, and encodes the output drawn to the window to sRGB.
+    ///
+    /// Textures are then sampled and blended as linear colors, which keeps their colors accurate.
+    /// `TextureMetadata::with_srgb` overrides this for a single texture.
+    #[serde(default)]
+    pub srgb: bool,
+    /// Draws the pipeline at this fixed resolution, measured in pixels (px), then upscales it to
+    /// the window by the largest whole factor that fits, with nearest filtering.
+    ///
+    /// This keeps pixel art crisp at any window size. The upscaled image is centered in the
+    /// window and surrounded by black borders, and `lock_aspect` is ignored.
+    #[serde(default)]
+    pub internal_resolution: Option<(u32, u32)>,
 }
 
 impl Default for DisplayConfig {
@@ -42,6 +71,11 @@ impl Default for DisplayConfig {
             vsync: default_vsync(),
             multisampling: default_multisampling(),
             visibility: default_visibility(),
+            resolution_presets: Vec::new(),
+            lock_aspect: None,
+            max_texture_size: None,
+            srgb: false,
+            internal_resolution: None,
         }
     }
 }
@@ -16,6 +16,12 @@ pub type Result<T> = StdResult<T, Error>;
 pub enum Error {
     /// Failed to create a buffer.
     BufferCreation(gfx::buffer::CreationError),
+    /// A pipeline switch has two targets with this name but different layouts.
+    ConflictingTargets(String),
+    /// A render layer with the given index does not exist.
+    NoSuchLayer(u32),
+    /// A resolution preset with the given index does not exist.
+    NoSuchPreset(usize),
     /// A render target with the given name does not exist.
     NoSuchTarget(String),
     /// Failed to initialize a render pass.
@@ -32,6 +38,8 @@ pub enum Error {
     TargetCreation(gfx::CombinedError),
     /// Failed to create a texture resource.
     TextureCreation(gfx::texture::CreationError),
+    /// The resolution is bigger than the monitor, in fullscreen.
+    UnsupportedResolution(u32, u32),
     /// The window handle associated with the renderer has been destroyed.
     WindowDestroyed,
 }
@@ -40,6 +48,9 @@ impl StdError for Error {
     fn description(&self) -> &str {
         match *self {
             Error::BufferCreation(_) => "Failed to create buffer!",
+            Error::ConflictingTargets(_) => "Targets with this name have different layouts!",
+            Error::NoSuchLayer(_) => "Render layer with this index does not exist!",
+            Error::NoSuchPreset(_) => "Resolution preset with this index does not exist!",
             Error::NoSuchTarget(_) => "Target with this name does not exist!",
             Error::PassInit(_) => "Failed to initialize render pass!",
             Error::PipelineCreation(_) => "Failed to create PSO!",
@@ -48,6 +59,7 @@ impl StdError for Error {
             Error::ResViewCreation(_) => "Failed to create resource view!",
             Error::TargetCreation(_) => "Failed to create render target!",
             Error::TextureCreation(_) => "Failed to create texture!",
+            Error::UnsupportedResolution(..) => "Resolution is bigger than the monitor!",
             Error::WindowDestroyed => "Window has been destroyed!",
         }
     }
@@ -70,6 +82,11 @@ impl Display for Error {
     fn fmt(&self, fmt: &mut Formatter) -> FmtResult {
         match *self {
             Error::BufferCreation(ref e) => write!(fmt, "Buffer creation failed: {}", e),
+            Error::ConflictingTargets(ref e) => {
+                write!(fmt, "Targets with different layouts are named: {}", e)
+            }
+            Error::NoSuchLayer(ref e) => write!(fmt, "Nonexistent render layer: {}", e),
+            Error::NoSuchPreset(ref e) => write!(fmt, "Nonexistent resolution preset: {}", e),
             Error::NoSuchTarget(ref e) => write!(fmt, "Nonexistent target: {}", e),
             Error::PassInit(ref e) => write!(fmt, "Pass initialization failed: {}", e),
             Error::PipelineCreation(ref e) => write!(fmt, "PSO creation failed: {}", e),
@@ -78,6 +95,9 @@ impl Display for Error {
             Error::ResViewCreation(ref e) => write!(fmt, "Resource view creation failed: {}", e),
             Error::TargetCreation(ref e) => write!(fmt, "Target creation failed: {}", e),
             Error::TextureCreation(ref e) => write!(fmt, "Texture creation failed: {}", e),
+            Error::UnsupportedResolution(w, h) => {
+                write!(fmt, "Resolution bigger than the monitor: {}x{}", w, h)
+            }
             Error::WindowDestroyed => write!(fmt, "Window has been destroyed"),
         }
     }
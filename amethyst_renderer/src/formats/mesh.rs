@@ -19,6 +19,9 @@ pub enum MeshData {
     /// Position and texture coordinates
     PosTex(Vec<PosTex>),
 
+    /// Position, texture coordinates and color
+    PosTexColor(Vec<PosTexColor>),
+
     /// Position, normal and texture coordinates
     PosNormTex(Vec<PosNormTex>),
 
@@ -41,6 +44,12 @@ impl From<Vec<PosTex>> for MeshData {
     }
 }
 
+impl From<Vec<PosTexColor>> for MeshData {
+    fn from(data: Vec<PosTexColor>) -> Self {
+        MeshData::PosTexColor(data)
+    }
+}
+
 impl From<Vec<PosNormTex>> for MeshData {
     fn from(data: Vec<PosNormTex>) -> Self {
         MeshData::PosNormTex(data)
@@ -160,6 +169,10 @@ pub fn create_mesh_asset(data: MeshData, renderer: &mut Renderer) -> Result<Mesh
             let mb = MeshBuilder::new(vertices);
             renderer.create_mesh(mb)
         }
+        MeshData::PosTexColor(ref vertices) => {
+            let mb = MeshBuilder::new(vertices);
+            renderer.create_mesh(mb)
+        }
         MeshData::PosNormTex(ref vertices) => {
             let mb = MeshBuilder::new(vertices);
             renderer.create_mesh(mb)
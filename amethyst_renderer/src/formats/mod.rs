@@ -2,7 +2,9 @@
 //!
 
 pub use self::mesh::*;
+pub use self::sprite::*;
 pub use self::texture::*;
 
 mod mesh;
+mod sprite;
 mod texture;
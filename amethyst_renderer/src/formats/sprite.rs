@@ -0,0 +1,116 @@
+use std::str::from_utf8;
+
+use amethyst_assets::{Result, ResultExt, SimpleFormat};
+use fnv::FnvHashMap;
+use ron::de::from_str;
+
+use sprite::{Sprite, SpriteSheet};
+
+/// Allows loading of sprite sheet definitions from RON files.
+///
+/// The sprites are given in pixels, with the origin at the top left of the texture:
+///
+/// ```ron
+/// (
+///     texture_width: 64,
+///     texture_height: 32,
+///     sprites: [
+///         (name: "bat_fly_1", x: 0, y: 0, w: 32, h: 32),
+///         (name: "bat_fly_2", x: 32, y: 0, w: 32, h: 32),
+///     ],
+/// )
+/// ```
+///
+/// The name of a sprite is optional, named sprites can be found with `SpriteSheet::index_of`.
+/// Sprites without a name, or with an empty one, are unnamed.
+/// The options are the index of the sprite sheet texture in the `MaterialTextureSet`.
+#[derive(Clone)]
+pub struct SpriteSheetFormat;
+
+#[derive(Deserialize)]
+struct SpriteSheetDefinition {
+    texture_width: u32,
+    texture_height: u32,
+    sprites: Vec<SpriteDefinition>,
+}
+
+#[derive(Deserialize)]
+struct SpriteDefinition {
+    /// Empty for unnamed sprites.
+    #[serde(default)]
+    name: String,
+    x: u32,
+    y: u32,
+    w: u32,
+    h: u32,
+}
+
+impl SpriteSheetFormat {
+    /// Load a sprite sheet definition from memory buffer
+    pub fn from_data(&self, data: Vec<u8>, index: usize) -> Result<SpriteSheet> {
+        let s = from_utf8(&data)?;
+        let definition: SpriteSheetDefinition =
+            from_str(s).chain_err(|| "Failed to decode sprite sheet file")?;
+        let (width, height) = (definition.texture_width as f32, definition.texture_height as f32);
+
+        let mut names = FnvHashMap::default();
+        let mut sprites = Vec::with_capacity(definition.sprites.len());
+        for (i, sprite) in definition.sprites.into_iter().enumerate() {
+            if !sprite.name.is_empty() {
+                if names.insert(sprite.name.clone(), i).is_some() {
+                    return Err(format!("Sprite name {:?} is used twice", sprite.name).into());
+                }
+            }
+            sprites.push(Sprite {
+                left: sprite.x as f32 / width,
+                right: (sprite.x + sprite.w) as f32 / width,
+                top: sprite.y as f32 / height,
+                bottom: (sprite.y + sprite.h) as f32 / height,
+            });
+        }
+
+        Ok(SpriteSheet {
+            index,
+            texture: None,
+            sprites,
+            names,
+        })
+    }
+}
+
+impl SimpleFormat<SpriteSheet> for SpriteSheetFormat {
+    const NAME: &'static str = "SPRITE_SHEET";
+
+    type Options = usize;
+
+    fn import(&self, bytes: Vec<u8>, index: usize) -> Result<SpriteSheet> {
+        self.from_data(bytes, index)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::SpriteSheetFormat;
+
+    #[test]
+    fn named_sprites_are_indexed() {
+        let ron = r#"(
+            texture_width: 64,
+            texture_height: 32,
+            sprites: [
+                (name: "bat_fly_1", x: 0, y: 0, w: 32, h: 32),
+                (x: 32, y: 0, w: 32, h: 16),
+                (name: "bat_fly_2", x: 32, y: 16, w: 32, h: 16),
+            ],
+        )"#;
+        let sheet = SpriteSheetFormat.from_data(ron.as_bytes().to_vec(), 2).unwrap();
+        assert_eq!(sheet.index, 2);
+        assert_eq!(sheet.sprites.len(), 3);
+        assert_eq!(sheet.index_of("bat_fly_2"), Some(2));
+        assert_eq!(sheet.index_of("bat_fly_3"), None);
+        assert_eq!(sheet.index_of(""), None);
+        assert_eq!(sheet.sprites[2].left, 0.5);
+        assert_eq!(sheet.sprites[2].top, 0.5);
+        assert_eq!(sheet.sprites[2].bottom, 1.0);
+    }
+}
@@ -5,7 +5,7 @@ use std::io::Cursor;
 use Renderer;
 use amethyst_assets::{Result, ResultExt, SimpleFormat};
 use gfx::format::{ChannelType, SurfaceType};
-use gfx::texture::SamplerInfo;
+use gfx::texture::{FilterMethod, SamplerInfo, WrapMode};
 use gfx::traits::Pod;
 use imagefmt;
 use imagefmt::{ColFmt, Image};
@@ -20,12 +20,19 @@ pub struct TextureMetadata {
     pub mip_levels: Option<u8>,
     /// Texture size
     pub size: Option<(u16, u16)>,
+    /// Number of layers of a texture array
+    pub layers: Option<u16>,
     /// Dynamic texture
     pub dynamic: bool,
+    /// Generate mipmaps from the texture data
+    pub generate_mipmaps: bool,
     /// Surface type
     pub format: Option<SurfaceType>,
     /// Channel type
     pub channel: Option<ChannelType>,
+    /// Whether the data is sRGB encoded, `DisplayConfig::srgb` deciding for images if neither
+    /// this nor the channel type is set
+    pub srgb: Option<bool>,
 }
 
 impl Default for TextureMetadata {
@@ -34,9 +41,12 @@ impl Default for TextureMetadata {
             sampler: None,
             mip_levels: None,
             size: None,
+            layers: None,
             dynamic: false,
+            generate_mipmaps: false,
             format: None,
             channel: None,
+            srgb: None,
         }
     }
 }
@@ -48,6 +58,17 @@ impl TextureMetadata {
         self
     }
 
+    /// Wrap mode of the sampler, keeping its other settings.
+    ///
+    /// Use `WrapMode::Tile` to repeat the texture, for example with `TextureOffset::tiled`.
+    pub fn with_wrap_mode(mut self, wrap: WrapMode) -> Self {
+        let mut sampler = self.sampler
+            .unwrap_or_else(|| SamplerInfo::new(FilterMethod::Scale, wrap));
+        sampler.wrap_mode = (wrap, wrap, wrap);
+        self.sampler = Some(sampler);
+        self
+    }
+
     /// Mipmapping
     pub fn with_mip_levels(mut self, mip_levels: u8) -> Self {
         self.mip_levels = Some(mip_levels);
@@ -60,6 +81,12 @@ impl TextureMetadata {
         self
     }
 
+    /// Number of layers of a texture array, the data holding the layers one after the other
+    pub fn with_layers(mut self, layers: u16) -> Self {
+        self.layers = Some(layers);
+        self
+    }
+
     /// Surface type
     pub fn with_format(mut self, format: SurfaceType) -> Self {
         self.format = Some(format);
@@ -72,11 +99,23 @@ impl TextureMetadata {
         self
     }
 
+    /// Whether the data is sRGB encoded, so it's converted to linear colors when sampled
+    pub fn with_srgb(mut self, srgb: bool) -> Self {
+        self.srgb = Some(srgb);
+        self
+    }
+
     /// Texture is dynamic
     pub fn dynamic(mut self, d: bool) -> Self {
         self.dynamic = d;
         self
     }
+
+    /// Generate mipmaps from the texture data
+    pub fn generate_mipmaps(mut self, generate: bool) -> Self {
+        self.generate_mipmaps = generate;
+        self
+    }
 }
 
 /// Texture data for loading
@@ -263,6 +302,10 @@ where
     D: AsRef<[T]>,
     T: Pod + Copy,
 {
+    // Applied first, so that an explicit sampler overrides the trilinear filtering.
+    if metadata.generate_mipmaps {
+        tb = tb.generate_mipmaps(true);
+    }
     match metadata.sampler {
         Some(sampler) => tb = tb.with_sampler(sampler),
         _ => (),
@@ -275,13 +318,23 @@ where
         Some((w, h)) => tb = tb.with_size(w, h),
         _ => (),
     }
+    match metadata.layers {
+        Some(layers) => tb = tb.with_layers(layers),
+        _ => (),
+    }
     if metadata.dynamic {
         tb = tb.dynamic(true);
     }
+
     match metadata.format {
         Some(format) => tb = tb.with_format(format),
         _ => (),
     }
+    // Applied first, so that an explicit channel type overrides it.
+    match metadata.srgb {
+        Some(srgb) => tb = tb.with_srgb(srgb),
+        _ => (),
+    }
     match metadata.channel {
         Some(channel) => tb = tb.with_channel_type(channel),
         _ => (),
@@ -292,7 +345,7 @@ where
 
 fn create_texture_asset_from_image(
     image: ImageData,
-    options: TextureMetadata,
+    mut options: TextureMetadata,
     renderer: &mut Renderer,
 ) -> Result<Texture> {
     fn convert_color_format(fmt: ColFmt) -> Option<SurfaceType> {
@@ -304,10 +357,25 @@ fn create_texture_asset_from_image(
         }
     }
 
-    let image = image.raw;
+    let mut image = image.raw;
     let fmt = convert_color_format(image.fmt)
         .chain_err(|| format!("Unsupported color format {:?}", image.fmt))?;
 
+    let max_size = renderer.max_texture_size().max(1) as usize;
+    if image.w > max_size || image.h > max_size {
+        let largest = image.w.max(image.h);
+        let w = (image.w * max_size / largest).max(1);
+        let h = (image.h * max_size / largest).max(1);
+        warn!(
+            "Texture of size ({}, {}) is larger than the maximum texture size {}, downscaling \
+             it to ({}, {})",
+            image.w, image.h, max_size, w, h
+        );
+        image.buf = downscale(&image.buf, (image.w, image.h), (w, h));
+        image.w = w;
+        image.h = h;
+    }
+
     if image.w > u16::max_value() as usize || image.h > u16::max_value() as usize {
         bail!(
             "Unsupported texture size (expected: ({}, {}), got: ({}, {})",
@@ -318,6 +386,9 @@ fn create_texture_asset_from_image(
         );
     }
 
+    if options.srgb.is_none() && options.channel.is_none() {
+        options.srgb = Some(renderer.srgb());
+    }
     let tb = apply_options(
         TextureBuilder::new(image.buf)
             .with_format(fmt)
@@ -330,9 +401,37 @@ fn create_texture_asset_from_image(
         .chain_err(|| "Failed to create texture from texture data")
 }
 
+/// Downscales 4 channel 8-bit pixels, averaging the source pixels covered by each destination
+/// pixel.
+fn downscale(
+    pixels: &[u8],
+    (width, height): (usize, usize),
+    (new_width, new_height): (usize, usize),
+) -> Vec<u8> {
+    let mut scaled = Vec::with_capacity(new_width * new_height * 4);
+    for y in 0..new_height {
+        let (top, bottom) = (y * height / new_height, (y + 1) * height / new_height);
+        for x in 0..new_width {
+            let (left, right) = (x * width / new_width, (x + 1) * width / new_width);
+            let mut sum = [0u32; 4];
+            for src_y in top..bottom.max(top + 1) {
+                for src_x in left..right.max(left + 1) {
+                    let i = (src_y * width + src_x) * 4;
+                    for (sum, &channel) in sum.iter_mut().zip(&pixels[i..i + 4]) {
+                        *sum += channel as u32;
+                    }
+                }
+            }
+            let count = ((bottom.max(top + 1) - top) * (right.max(left + 1) - left)) as u32;
+            scaled.extend(sum.iter().map(|sum| (sum / count) as u8));
+        }
+    }
+    scaled
+}
+
 #[cfg(test)]
 mod tests {
-    use super::TextureData;
+    use super::{downscale, TextureData};
 
     #[test]
     fn texture_data_from_f32_3() {
@@ -343,4 +442,13 @@ mod tests {
             _ => panic!("Expected [f32; 3] to turn into TextureData::Rgba"),
         }
     }
+
+    #[test]
+    fn downscale_averages_pixels() {
+        let pixels = [
+            0, 0, 0, 255, 255, 255, 255, 255, 10, 20, 30, 40, 10, 20, 30, 40,
+        ];
+        let scaled = downscale(&pixels, (4, 1), (2, 1));
+        assert_eq!(scaled, vec![127, 127, 127, 255, 10, 20, 30, 40]);
+    }
 }
@@ -26,6 +26,7 @@ extern crate imagefmt;
 #[macro_use]
 extern crate log;
 extern crate rayon;
+extern crate ron;
 extern crate serde;
 #[macro_use]
 extern crate serde_derive;
@@ -61,35 +62,41 @@ extern crate gfx_device_vulkan;
 extern crate gfx_window_vulkan;
 
 pub use bundle::RenderBundle;
-pub use cam::{ActiveCamera, Camera, Projection};
+pub use cam::{ActiveCamera, Camera, Projection, ScreenCamera, ScreenCameraSystem};
 pub use color::Rgba;
 pub use config::DisplayConfig;
 pub use formats::{build_mesh_with_combo, create_mesh_asset, create_texture_asset, BmpFormat,
                   ComboMeshCreator, ImageData, ImageError, JpgFormat, MeshCreator, MeshData,
-                  ObjFormat, PngFormat, TextureData, TextureMetadata};
+                  ObjFormat, PngFormat, SpriteSheetFormat, TextureData, TextureMetadata};
 pub use input::{ElementState, Event, KeyboardInput, MouseButton, VirtualKeyCode, WindowEvent};
-pub use light::{DirectionalLight, Light, PointLight, SpotLight, SunLight};
-pub use mesh::{vertex_data, Mesh, MeshBuilder, MeshHandle, VertexBuffer};
-pub use mtl::{Material, MaterialDefaults, TextureOffset};
-pub use pass::{DrawFlat, DrawFlatSeparate, DrawPbm, DrawPbmSeparate, DrawShaded,
-               DrawShadedSeparate};
-pub use pipe::{ColorBuffer, Data, DepthBuffer, DepthMode, Effect, EffectBuilder, Init, Meta,
-               NewEffect, Pipeline, PipelineBuild, PipelineBuilder, PipelineData, PolyPipeline,
-               PolyStage, PolyStages, Stage, StageBuilder, Target, TargetBuilder, Targets};
+pub use light::{DirectionalLight, Light, PointLight, PointLight2D, SpotLight, SunLight};
+pub use mesh::{vertex_data, DynamicVertexBuffer, Mesh, MeshBuilder, MeshHandle, VertexBuffer};
+pub use mtl::{Material, MaterialDefaults, MaterialSampler, TextureOffset};
+pub use outline::Outline;
+pub use pass::{DrawFlat, DrawFlatSeparate, DrawLit2D, DrawOutline, DrawPbm, DrawPbmSeparate,
+               DrawPickingIds, DrawShaded, DrawShadedSeparate, DrawSpriteArray};
+pub use picking::Picking;
+pub use pipe::{ActivePipeline, ColorBuffer, Data, DepthBuffer, DepthMode, Effect, EffectBuilder,
+               Init, Meta, NewEffect, Pipeline, PipelineBuild, PipelineBuilder, PipelineData,
+               PipelineSwitch, PipelineSwitchBuilder, PolyPipeline, PolyStage, PolyStages, Stage,
+               StageBuilder, Target, TargetBuilder, Targets};
 pub use renderer::Renderer;
-pub use resources::{AmbientColor, ScreenDimensions, WindowMessages};
+pub use resources::{AmbientColor, MonitorInfo, Monitors, PolygonMode, ResolutionPresets,
+                    ScreenDimensions, WindowFocus, WindowMessages, WindowTitle};
 pub use skinning::{AnimatedComboMeshCreator, AnimatedVertexBufferCombination, JointIds,
                    JointTransforms, JointWeights};
-pub use sprite::{Sprite, SpriteSheet, SpriteSheetHandle};
+pub use sprite::{ArraySprite, CropDirection, RenderScale, Sprite, SpriteArray, SpriteArrayBuilder,
+                 SpriteCrop, SpriteSheet, SpriteSheetBuilder, SpriteSheetHandle};
 pub use system::RenderSystem;
 pub use tex::{Texture, TextureBuilder, TextureHandle};
-pub use transparent::{Blend, BlendChannel, BlendValue, ColorMask, Equation, Factor, Transparent,
-                      ALPHA, REPLACE};
+pub use transparent::{Blend, BlendChannel, BlendMode, BlendValue, ColorMask, Equation, Factor,
+                      Transparent, ALPHA, REPLACE};
 pub use types::{Encoder, Factory, PipelineState, Resources};
 pub use vertex::{Attribute, AttributeFormat, Attributes, Color, Normal, PosColor, PosNormTangTex,
-                 PosNormTex, PosTex, Position, Query, Separate, Tangent, TexCoord,
-                 VertexBufferCombination, VertexFormat, With};
-pub use visibility::{Visibility, VisibilitySortingSystem};
+                 PosNormTex, PosTex, PosTexColor, PosTexLayer, Position, Query, Separate, Tangent,
+                 TexCoord, TexLayer, VertexBufferCombination, VertexFormat, With};
+pub use visibility::{BoundingSphere, Hidden, RenderLayers, SortingLayer, SortingLayers,
+                     TransparencySorting, Visibility, VisibilitySortingSystem};
 
 pub mod error;
 pub mod mouse;
@@ -107,7 +114,9 @@ mod input;
 mod light;
 mod mesh;
 mod mtl;
+mod outline;
 mod pass;
+mod picking;
 mod renderer;
 mod resources;
 mod skinning;
@@ -116,5 +125,6 @@ mod system;
 mod tex;
 mod transparent;
 mod types;
+mod upscale;
 mod vertex;
 mod visibility;
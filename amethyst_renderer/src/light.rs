@@ -177,3 +177,42 @@ impl From<SunLight> for Light {
 impl Component for Light {
     type Storage = DenseVecStorage<Self>;
 }
+
+/// A point light for 2D scenes, drawn by `DrawLit2D`.
+///
+/// The light is placed at the position of the entity's `GlobalTransform`, and lights the sprites
+/// within `radius` of it. The light fades out with the square of the distance left to `radius`,
+/// `(1 - distance / radius)^2`, so it reaches its edge smoothly.
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub struct PointLight2D {
+    /// Color of the light in RGBA8 format.
+    pub color: Rgba,
+    /// Maximum radius of the light's affected area, in world units.
+    pub radius: f32,
+    /// Brightness of the light at its center.
+    pub intensity: f32,
+    /// Height of the light above the sprites, in world units, used to shade their normal maps.
+    pub height: f32,
+}
+
+impl PointLight2D {
+    /// Create a new light, with a height of a quarter of its radius.
+    pub fn new(color: Rgba, radius: f32, intensity: f32) -> Self {
+        PointLight2D {
+            color,
+            radius,
+            intensity,
+            height: radius / 4.0,
+        }
+    }
+}
+
+impl Default for PointLight2D {
+    fn default() -> Self {
+        PointLight2D::new(Rgba::white(), 100.0, 1.0)
+    }
+}
+
+impl Component for PointLight2D {
+    type Storage = DenseVecStorage<Self>;
+}
@@ -7,9 +7,10 @@ use amethyst_assets::Handle;
 
 use amethyst_core::cgmath::{Deg, Matrix4, Point3, Transform, Vector3};
 use gfx::Primitive;
+use gfx::handle::Buffer;
 
 use error::Result;
-use types::{Factory, RawBuffer, Slice};
+use types::{Encoder, Factory, RawBuffer, Resources, Slice};
 use vertex::{Attributes, VertexFormat};
 
 /// Raw buffer with its attributes
@@ -298,6 +299,109 @@ where
     }
 }
 
+/// A vertex buffer rewritten every frame, such as the vertices of batched sprites.
+///
+/// The buffer is kept between frames and only reallocated when the vertices don't fit in it
+/// anymore, doubling its capacity, so drawing a varying amount of vertices doesn't create a new
+/// buffer every frame.
+#[derive(Clone, Debug)]
+pub struct DynamicVertexBuffer<V> {
+    buffer: Option<Buffer<Resources, V>>,
+    capacity: usize,
+    len: usize,
+}
+
+impl<V> DynamicVertexBuffer<V>
+where
+    V: VertexFormat,
+{
+    /// Create an empty buffer, allocated on the first write.
+    pub fn new() -> Self {
+        DynamicVertexBuffer {
+            buffer: None,
+            capacity: 0,
+            len: 0,
+        }
+    }
+
+    /// Number of vertices the buffer can hold without growing.
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// Number of vertices written by the last `write`.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Check if the last `write` had no vertices.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Grow the buffer so it holds at least `capacity` vertices, doubling its capacity until it is
+    /// large enough.
+    pub fn reserve(&mut self, factory: &mut Factory, capacity: usize) -> Result<()> {
+        use gfx::Factory;
+        use gfx::buffer::Role;
+        use gfx::memory::{Bind, Usage};
+
+        if self.buffer.is_some() && capacity <= self.capacity {
+            return Ok(());
+        }
+        let capacity = capacity.max(1).next_power_of_two().max(self.capacity);
+        let buffer =
+            factory.create_buffer(capacity, Role::Vertex, Usage::Dynamic, Bind::empty())?;
+        self.buffer = Some(buffer);
+        self.capacity = capacity;
+        Ok(())
+    }
+
+    /// Write the vertices at the start of the buffer, growing it if they don't fit.
+    pub fn write(
+        &mut self,
+        factory: &mut Factory,
+        encoder: &mut Encoder,
+        vertices: &[V],
+    ) -> Result<()> {
+        self.reserve(factory, vertices.len())?;
+        if let Some(ref buffer) = self.buffer {
+            encoder
+                .update_buffer(buffer, vertices, 0)
+                .expect("Vertices fit in the reserved buffer");
+        }
+        self.len = vertices.len();
+        Ok(())
+    }
+
+    /// The raw buffer, to be pushed to the vertex buffers of an `Effect`.
+    pub fn raw(&self) -> Option<&RawBuffer> {
+        self.buffer.as_ref().map(|buffer| buffer.raw())
+    }
+
+    /// Slice drawing the vertices of the last `write`.
+    pub fn slice(&self) -> Slice {
+        use gfx::IndexBuffer;
+
+        Slice {
+            start: 0,
+            end: self.len as u32,
+            base_vertex: 0,
+            instances: None,
+            buffer: IndexBuffer::Auto,
+        }
+    }
+}
+
+impl<V> Default for DynamicVertexBuffer<V>
+where
+    V: VertexFormat,
+{
+    fn default() -> Self {
+        DynamicVertexBuffer::new()
+    }
+}
+
 /// Check that attributes are sorted
 fn check_attributes_are_sorted(attrs: Attributes) -> bool {
     let mut last = 0;
@@ -37,3 +37,16 @@ pub fn set_mouse_cursor(msg: &mut WindowMessages, cursor: MouseCursor) {
         win.set_cursor(cursor);
     });
 }
+
+/// Move the cursor to the given position in the window, in pixels from the top left corner.
+///
+/// The window reports the warp as a cursor movement. Call `InputHandler::warp_mouse_position`
+/// with the same position, so it isn't read as a movement of the mouse, for example to recenter
+/// the cursor every frame for mouse look.
+pub fn set_cursor_position(msg: &mut WindowMessages, x: i32, y: i32) {
+    msg.send_command(move |win| {
+        if let Err(err) = win.set_cursor_position(x, y) {
+            error!("Unable to set the cursor position! Error: {:?}", err);
+        }
+    });
+}
@@ -1,8 +1,9 @@
 //! Physically-based material.
 
 use amethyst_core::specs::{Component, DenseVecStorage};
+use gfx::texture::SamplerInfo;
 
-use tex::TextureHandle;
+use tex::{FilterMethod, TextureHandle, WrapMode};
 
 /// Material reference this part of the texture
 #[derive(Debug, Clone, PartialEq)]
@@ -13,6 +14,20 @@ pub struct TextureOffset {
     pub v: (f32, f32),
 }
 
+impl TextureOffset {
+    /// Create an offset repeating the texture `u_repeat` times horizontally and `v_repeat` times
+    /// vertically across the mesh.
+    ///
+    /// The texture needs to be loaded with a tiling wrap mode, see
+    /// `TextureMetadata::with_wrap_mode`, or the material needs a tiling `MaterialSampler`.
+    pub fn tiled(u_repeat: f32, v_repeat: f32) -> Self {
+        TextureOffset {
+            u: (0., u_repeat),
+            v: (0., v_repeat),
+        }
+    }
+}
+
 impl Default for TextureOffset {
     fn default() -> Self {
         TextureOffset {
@@ -22,6 +37,40 @@ impl Default for TextureOffset {
     }
 }
 
+/// Sampler settings of a `Material`, used for all of its textures instead of the sampler they
+/// were loaded with.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct MaterialSampler {
+    /// Filtering of the textures, both when minified and magnified. `FilterMethod::Scale`
+    /// samples the nearest texel, for pixel art, while `FilterMethod::Bilinear` blends them.
+    pub filter: FilterMethod,
+    /// Wrapping of the horizontal texture coordinate outside of 0 to 1.
+    pub wrap_s: WrapMode,
+    /// Wrapping of the vertical texture coordinate outside of 0 to 1.
+    pub wrap_t: WrapMode,
+}
+
+impl MaterialSampler {
+    /// Create sampler settings wrapping both texture coordinates the same way.
+    ///
+    /// A tiled pixel art background uses `MaterialSampler::new(FilterMethod::Scale,
+    /// WrapMode::Tile)` with a `TextureOffset::tiled` albedo offset.
+    pub fn new(filter: FilterMethod, wrap: WrapMode) -> Self {
+        MaterialSampler {
+            filter,
+            wrap_s: wrap,
+            wrap_t: wrap,
+        }
+    }
+
+    /// Returns the gfx sampler info for these settings.
+    pub fn info(&self) -> SamplerInfo {
+        let mut info = SamplerInfo::new(self.filter, self.wrap_s);
+        info.wrap_mode = (self.wrap_s, self.wrap_t, self.wrap_s);
+        info
+    }
+}
+
 /// Material struct.
 #[derive(Clone, PartialEq)]
 pub struct Material {
@@ -29,6 +78,8 @@ pub struct Material {
     pub albedo: TextureHandle,
     /// Diffuse texture offset
     pub albedo_offset: TextureOffset,
+    /// Color multiplied with the diffuse map, white by default.
+    pub albedo_color: [f32; 4],
     /// Emission map.
     pub emission: TextureHandle,
     /// Emission texture offset
@@ -53,6 +104,9 @@ pub struct Material {
     pub caveat: TextureHandle,
     /// Caveat texture offset
     pub caveat_offset: TextureOffset,
+    /// Sampler settings for the textures of this material, if they shouldn't use the sampler
+    /// they were loaded with.
+    pub sampler: Option<MaterialSampler>,
 }
 
 impl Component for Material {
@@ -0,0 +1,29 @@
+//! Outline component
+
+use amethyst_core::specs::{Component, DenseVecStorage};
+
+use color::Rgba;
+
+/// Draws an outline around the opaque region of the entity's albedo texture, using the
+/// `DrawOutline` pass.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Outline {
+    /// Color of the outline.
+    pub color: Rgba,
+    /// Thickness of the outline, in texels of the albedo texture.
+    pub thickness: f32,
+}
+
+impl Outline {
+    /// Create a new outline
+    pub fn new<C: Into<Rgba>>(color: C, thickness: f32) -> Self {
+        Outline {
+            color: color.into(),
+            thickness,
+        }
+    }
+}
+
+impl Component for Outline {
+    type Storage = DenseVecStorage<Self>;
+}
@@ -3,7 +3,8 @@
 use std::marker::PhantomData;
 
 use amethyst_assets::AssetStorage;
-use amethyst_core::specs::{Fetch, Join, ReadStorage};
+use amethyst_core::Disabled;
+use amethyst_core::specs::{Entities, Fetch, Join, ReadStorage};
 use amethyst_core::transform::GlobalTransform;
 use gfx::pso::buffer::ElemStride;
 use gfx_core::state::{Blend, ColorMask};
@@ -13,26 +14,35 @@ use cam::{ActiveCamera, Camera};
 use error::Result;
 use mesh::{Mesh, MeshHandle};
 use mtl::{Material, MaterialDefaults};
-use pass::util::{draw_mesh, get_camera, setup_textures, VertexArgs};
+use pass::util::{draw_mesh, excluded_entities, get_camera, render_transform, set_crop,
+                 setup_crop, setup_textures, VertexArgs};
 use pipe::{DepthMode, Effect, NewEffect};
 use pipe::pass::{Pass, PassData};
+use resources::PolygonMode;
+use sprite::{RenderScale, SpriteCrop};
 use tex::Texture;
 use types::{Encoder, Factory};
-use vertex::{Position, Query, TexCoord};
-use visibility::Visibility;
+use vertex::{Position, Query};
+use visibility::{Hidden, RenderLayers, Visibility};
 
 /// Draw mesh without lighting
+///
+/// If the vertex format has a `Color` attribute, like `PosColor` and `PosTexColor`, the vertex
+/// color is multiplied into the output. Formats without a `TexCoord` attribute sample the albedo
+/// texture at its origin, so they should be drawn with a plain texture, like the default one.
 /// `V` is `VertexFormat`
 #[derive(Derivative, Clone, Debug, PartialEq)]
-#[derivative(Default(bound = "V: Query<(Position, TexCoord)>, Self: Pass"))]
+#[derivative(Default(bound = "V: Query<(Position,)>, Self: Pass"))]
 pub struct DrawFlat<V> {
     _pd: PhantomData<V>,
     transparency: Option<(ColorMask, Blend, Option<DepthMode>)>,
+    #[derivative(Default(value = "true"))]
+    depth_test: bool,
 }
 
 impl<V> DrawFlat<V>
 where
-    V: Query<(Position, TexCoord)>,
+    V: Query<(Position,)>,
     Self: Pass,
 {
     /// Create instance of `DrawFlat` pass
@@ -40,21 +50,31 @@ where
         Default::default()
     }
 
-    /// Enable transparency
-    pub fn with_transparency(
+    /// Enable transparency, `blend` can be a `Blend` or a `BlendMode`
+    pub fn with_transparency<B: Into<Blend>>(
         mut self,
         mask: ColorMask,
-        blend: Blend,
+        blend: B,
         depth: Option<DepthMode>,
     ) -> Self {
-        self.transparency = Some((mask, blend, depth));
+        self.transparency = Some((mask, blend.into(), depth));
+        self
+    }
+
+    /// Enable or disable depth testing, enabled by default.
+    ///
+    /// When disabled, meshes are drawn over each other in drawing order, regardless of their
+    /// depth. This is useful for 2D games, where transparent sprites could otherwise occlude
+    /// each other.
+    pub fn with_depth_test(mut self, depth_test: bool) -> Self {
+        self.depth_test = depth_test;
         self
     }
 }
 
 impl<'a, V> PassData<'a> for DrawFlat<V>
 where
-    V: Query<(Position, TexCoord)>,
+    V: Query<(Position,)>,
 {
     type Data = (
         Option<Fetch<'a, ActiveCamera>>,
@@ -63,35 +83,53 @@ where
         Fetch<'a, AssetStorage<Texture>>,
         Fetch<'a, MaterialDefaults>,
         Option<Fetch<'a, Visibility>>,
+        Option<Fetch<'a, PolygonMode>>,
+        Entities<'a>,
+        ReadStorage<'a, Hidden>,
+        ReadStorage<'a, RenderLayers>,
+        ReadStorage<'a, Disabled>,
         ReadStorage<'a, MeshHandle>,
         ReadStorage<'a, Material>,
         ReadStorage<'a, GlobalTransform>,
+        ReadStorage<'a, SpriteCrop>,
+        ReadStorage<'a, RenderScale>,
     );
 }
 
 impl<V> Pass for DrawFlat<V>
 where
-    V: Query<(Position, TexCoord)>,
+    V: Query<(Position,)>,
 {
     fn compile(&mut self, effect: NewEffect) -> Result<Effect> {
         use std::mem;
-        let mut builder = effect.simple(VERT_SRC, FRAG_SRC);
+        let mut builder = if has_color::<V>() {
+            effect.simple(VERT_COLOR_SRC, FRAG_COLOR_SRC)
+        } else {
+            effect.simple(VERT_SRC, FRAG_SRC)
+        };
         builder
             .with_raw_constant_buffer("VertexArgs", mem::size_of::<VertexArgs>(), 1)
-            .with_raw_vertex_buffer(V::QUERIED_ATTRIBUTES, V::size() as ElemStride, 0);
+            .with_raw_vertex_buffer(V::ATTRIBUTES, V::size() as ElemStride, 0);
         setup_textures(&mut builder, &TEXTURES);
-        match self.transparency {
-            Some((mask, blend, depth)) => builder.with_blended_output("color", mask, blend, depth),
-            None => builder.with_output("color", Some(DepthMode::LessEqualWrite)),
+        setup_crop(&mut builder);
+        match (self.transparency, self.depth_test) {
+            (Some((mask, blend, depth)), true) => {
+                builder.with_blended_output("color", mask, blend, depth)
+            }
+            (Some((mask, blend, _)), false) => {
+                builder.with_blended_output("color", mask, blend, None)
+            }
+            (None, true) => builder.with_output("color", Some(DepthMode::LessEqualWrite)),
+            (None, false) => builder.with_output("color", None),
         };
-        builder.build()
+        builder.with_wireframe_variant().build()
     }
 
     fn apply<'a, 'b: 'a>(
         &'a mut self,
         encoder: &mut Encoder,
         effect: &mut Effect,
-        _factory: Factory,
+        mut factory: Factory,
         (
             active,
             camera,
@@ -99,18 +137,31 @@ where
             tex_storage,
             material_defaults,
             visibility,
+            polygon_mode,
+            entities,
+            hidden,
+            layers,
+            disabled,
             mesh,
             material,
             global,
+            crop,
+            render_scale,
         ): <Self as PassData<'a>>::Data,
     ) {
         let camera = get_camera(active, &camera, &global);
+        let excluded = excluded_entities(camera, &entities, &layers, &disabled);
+        effect.set_wireframe(polygon_mode.map_or(false, |mode| *mode == PolygonMode::Line));
 
         match visibility {
-            None => for (mesh, material, global) in (&mesh, &material, &global).join() {
+            None => for (entity, mesh, material, global, _, _) in
+                (&*entities, &mesh, &material, &global, !&hidden, !&excluded).join()
+            {
+                set_crop(effect, encoder, crop.get(entity));
                 draw_mesh(
                     encoder,
                     effect,
+                    &mut factory,
                     false,
                     mesh_storage.get(mesh),
                     None,
@@ -118,18 +169,26 @@ where
                     Some(material),
                     &material_defaults,
                     camera,
-                    Some(global),
-                    &[V::QUERIED_ATTRIBUTES],
+                    Some(&render_transform(global, render_scale.get(entity))),
+                    &[V::ATTRIBUTES],
                     &TEXTURES,
                 );
             },
             Some(ref visibility) => {
-                for (mesh, material, global, _) in
-                    (&mesh, &material, &global, &visibility.visible_unordered).join()
+                for (entity, mesh, material, global, _, _) in (
+                    &*entities,
+                    &mesh,
+                    &material,
+                    &global,
+                    &visibility.visible_unordered,
+                    !&excluded,
+                ).join()
                 {
+                    set_crop(effect, encoder, crop.get(entity));
                     draw_mesh(
                         encoder,
                         effect,
+                        &mut factory,
                         false,
                         mesh_storage.get(mesh),
                         None,
@@ -137,17 +196,22 @@ where
                         Some(material),
                         &material_defaults,
                         camera,
-                        Some(global),
-                        &[V::QUERIED_ATTRIBUTES],
+                        Some(&render_transform(global, render_scale.get(entity))),
+                        &[V::ATTRIBUTES],
                         &TEXTURES,
                     );
                 }
 
                 for entity in &visibility.visible_ordered {
+                    if excluded.contains(entity.id()) {
+                        continue;
+                    }
                     if let Some(mesh) = mesh.get(*entity) {
+                        set_crop(effect, encoder, crop.get(*entity));
                         draw_mesh(
                             encoder,
                             effect,
+                            &mut factory,
                             false,
                             mesh_storage.get(mesh),
                             None,
@@ -155,8 +219,11 @@ where
                             material.get(*entity),
                             &material_defaults,
                             camera,
-                            global.get(*entity),
-                            &[V::QUERIED_ATTRIBUTES],
+                            global
+                                .get(*entity)
+                                .map(|global| render_transform(global, render_scale.get(*entity)))
+                                .as_ref(),
+                            &[V::ATTRIBUTES],
                             &TEXTURES,
                         );
                     }
@@ -5,8 +5,16 @@ mod interleaved;
 mod separate;
 
 use pass::util::TextureType;
+use vertex::{Attribute, Color, VertexFormat};
 
 static VERT_SRC: &[u8] = include_bytes!("../shaders/vertex/basic.glsl");
 static FRAG_SRC: &[u8] = include_bytes!("../shaders/fragment/flat.glsl");
+static VERT_COLOR_SRC: &[u8] = include_bytes!("../shaders/vertex/color.glsl");
+static FRAG_COLOR_SRC: &[u8] = include_bytes!("../shaders/fragment/flat_color.glsl");
 
 static TEXTURES: [TextureType; 1] = [TextureType::Albedo];
+
+/// Returns true if the vertex format has a color attribute.
+fn has_color<V: VertexFormat>() -> bool {
+    V::ATTRIBUTES.iter().any(|&(name, _)| name == Color::NAME)
+}
@@ -1,6 +1,7 @@
 //! Simple flat forward drawing pass.
 
 use amethyst_assets::AssetStorage;
+use amethyst_core::Disabled;
 use amethyst_core::specs::{Entities, Fetch, Join, ReadStorage};
 use amethyst_core::transform::GlobalTransform;
 use gfx::pso::buffer::ElemStride;
@@ -12,14 +13,17 @@ use error::Result;
 use mesh::{Mesh, MeshHandle};
 use mtl::{Material, MaterialDefaults};
 use pass::skinning::{create_skinning_effect, setup_skinning_buffers};
-use pass::util::{draw_mesh, get_camera, setup_textures, VertexArgs};
+use pass::util::{draw_mesh, excluded_entities, get_camera, render_transform, set_crop,
+                 setup_crop, setup_textures, VertexArgs};
 use pipe::{DepthMode, Effect, NewEffect};
 use pipe::pass::{Pass, PassData};
+use resources::PolygonMode;
 use skinning::JointTransforms;
+use sprite::{RenderScale, SpriteCrop};
 use tex::Texture;
 use types::{Encoder, Factory};
 use vertex::{Attributes, Position, Separate, TexCoord, VertexFormat};
-use visibility::Visibility;
+use visibility::{Hidden, RenderLayers, Visibility};
 
 static ATTRIBUTES: [Attributes<'static>; 2] = [
     Separate::<Position>::ATTRIBUTES,
@@ -32,6 +36,8 @@ static ATTRIBUTES: [Attributes<'static>; 2] = [
 pub struct DrawFlatSeparate {
     skinning: bool,
     transparency: Option<(ColorMask, Blend, Option<DepthMode>)>,
+    #[derivative(Default(value = "true"))]
+    depth_test: bool,
 }
 
 impl DrawFlatSeparate
@@ -49,14 +55,24 @@ where
         self
     }
 
-    /// Enable transparency
-    pub fn with_transparency(
+    /// Enable transparency, `blend` can be a `Blend` or a `BlendMode`
+    pub fn with_transparency<B: Into<Blend>>(
         mut self,
         mask: ColorMask,
-        blend: Blend,
+        blend: B,
         depth: Option<DepthMode>,
     ) -> Self {
-        self.transparency = Some((mask, blend, depth));
+        self.transparency = Some((mask, blend.into(), depth));
+        self
+    }
+
+    /// Enable or disable depth testing, enabled by default.
+    ///
+    /// When disabled, meshes are drawn over each other in drawing order, regardless of their
+    /// depth. This is useful for 2D games, where transparent sprites could otherwise occlude
+    /// each other.
+    pub fn with_depth_test(mut self, depth_test: bool) -> Self {
+        self.depth_test = depth_test;
         self
     }
 }
@@ -70,9 +86,15 @@ impl<'a> PassData<'a> for DrawFlatSeparate {
         Fetch<'a, AssetStorage<Texture>>,
         Fetch<'a, MaterialDefaults>,
         Option<Fetch<'a, Visibility>>,
+        Option<Fetch<'a, PolygonMode>>,
+        ReadStorage<'a, Hidden>,
+        ReadStorage<'a, RenderLayers>,
+        ReadStorage<'a, Disabled>,
         ReadStorage<'a, MeshHandle>,
         ReadStorage<'a, Material>,
         ReadStorage<'a, GlobalTransform>,
+        ReadStorage<'a, SpriteCrop>,
+        ReadStorage<'a, RenderScale>,
         ReadStorage<'a, JointTransforms>,
     );
 }
@@ -101,18 +123,25 @@ impl Pass for DrawFlatSeparate {
         }
         builder.with_raw_constant_buffer("VertexArgs", mem::size_of::<VertexArgs>(), 1);
         setup_textures(&mut builder, &TEXTURES);
-        match self.transparency {
-            Some((mask, blend, depth)) => builder.with_blended_output("color", mask, blend, depth),
-            None => builder.with_output("color", Some(DepthMode::LessEqualWrite)),
+        setup_crop(&mut builder);
+        match (self.transparency, self.depth_test) {
+            (Some((mask, blend, depth)), true) => {
+                builder.with_blended_output("color", mask, blend, depth)
+            }
+            (Some((mask, blend, _)), false) => {
+                builder.with_blended_output("color", mask, blend, None)
+            }
+            (None, true) => builder.with_output("color", Some(DepthMode::LessEqualWrite)),
+            (None, false) => builder.with_output("color", None),
         };
-        builder.build()
+        builder.with_wireframe_variant().build()
     }
 
     fn apply<'a, 'b: 'a>(
         &'a mut self,
         encoder: &mut Encoder,
         effect: &mut Effect,
-        _factory: Factory,
+        mut factory: Factory,
         (
             entities,
             active,
@@ -121,21 +150,31 @@ impl Pass for DrawFlatSeparate {
             tex_storage,
             material_defaults,
             visibility,
+            polygon_mode,
+            hidden,
+            layers,
+            disabled,
             mesh,
             material,
             global,
+            crop,
+            render_scale,
             joints,
         ): <Self as PassData<'a>>::Data,
     ) {
         let camera = get_camera(active, &camera, &global);
+        let excluded = excluded_entities(camera, &entities, &layers, &disabled);
+        effect.set_wireframe(polygon_mode.map_or(false, |mode| *mode == PolygonMode::Line));
 
         match visibility {
-            None => for (entity, mesh, material, global) in
-                (&*entities, &mesh, &material, &global).join()
+            None => for (entity, mesh, material, global, _, _) in
+                (&*entities, &mesh, &material, &global, !&hidden, !&excluded).join()
             {
+                set_crop(effect, encoder, crop.get(entity));
                 draw_mesh(
                     encoder,
                     effect,
+                    &mut factory,
                     self.skinning,
                     mesh_storage.get(mesh),
                     joints.get(entity),
@@ -143,23 +182,26 @@ impl Pass for DrawFlatSeparate {
                     Some(material),
                     &material_defaults,
                     camera,
-                    Some(global),
+                    Some(&render_transform(global, render_scale.get(entity))),
                     &ATTRIBUTES,
                     &TEXTURES,
                 );
             },
             Some(ref visibility) => {
-                for (entity, mesh, material, global, _) in (
+                for (entity, mesh, material, global, _, _) in (
                     &*entities,
                     &mesh,
                     &material,
                     &global,
                     &visibility.visible_unordered,
+                    !&excluded,
                 ).join()
                 {
+                    set_crop(effect, encoder, crop.get(entity));
                     draw_mesh(
                         encoder,
                         effect,
+                        &mut factory,
                         self.skinning,
                         mesh_storage.get(mesh),
                         joints.get(entity),
@@ -167,17 +209,22 @@ impl Pass for DrawFlatSeparate {
                         Some(material),
                         &material_defaults,
                         camera,
-                        Some(global),
+                        Some(&render_transform(global, render_scale.get(entity))),
                         &ATTRIBUTES,
                         &TEXTURES,
                     );
                 }
 
                 for entity in &visibility.visible_ordered {
+                    if excluded.contains(entity.id()) {
+                        continue;
+                    }
                     if let Some(mesh) = mesh.get(*entity) {
+                        set_crop(effect, encoder, crop.get(*entity));
                         draw_mesh(
                             encoder,
                             effect,
+                            &mut factory,
                             self.skinning,
                             mesh_storage.get(mesh),
                             joints.get(*entity),
@@ -185,7 +232,10 @@ impl Pass for DrawFlatSeparate {
                             material.get(*entity),
                             &material_defaults,
                             camera,
-                            global.get(*entity),
+                            global
+                                .get(*entity)
+                                .map(|global| render_transform(global, render_scale.get(*entity)))
+                                .as_ref(),
                             &ATTRIBUTES,
                             &TEXTURES,
                         );
@@ -195,3 +245,21 @@ impl Pass for DrawFlatSeparate {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::DrawFlatSeparate;
+    use pipe::{Pipeline, PipelineBuild, Stage};
+
+    fn assert_pipeline<P: PipelineBuild>(_: P) {}
+
+    #[test]
+    fn builds_into_a_pipeline() {
+        let pass = DrawFlatSeparate::new()
+            .with_vertex_skinning()
+            .with_depth_test(false);
+        assert!(pass.skinning);
+        assert!(!pass.depth_test);
+        assert_pipeline(Pipeline::build().with_stage(Stage::with_backbuffer().with_pass(pass)));
+    }
+}
@@ -0,0 +1,247 @@
+//! Forward drawing pass for 2D scenes lit by `PointLight2D`s.
+
+use std::marker::PhantomData;
+use std::mem;
+
+use amethyst_assets::AssetStorage;
+use amethyst_core::Disabled;
+use amethyst_core::specs::{Entities, Fetch, Join, ReadStorage};
+use amethyst_core::transform::GlobalTransform;
+use gfx::pso::buffer::ElemStride;
+use gfx::traits::Pod;
+use gfx_core::state::{Blend, ColorMask};
+
+use cam::{ActiveCamera, Camera};
+use error::Result;
+use light::PointLight2D;
+use mesh::{Mesh, MeshHandle};
+use mtl::{Material, MaterialDefaults};
+use pass::util::{draw_mesh, excluded_entities, get_camera, setup_textures, TextureType,
+                 VertexArgs};
+use pipe::{DepthMode, Effect, NewEffect};
+use pipe::pass::{Pass, PassData};
+use resources::AmbientColor;
+use tex::Texture;
+use types::{Encoder, Factory};
+use vertex::{Position, Query, TexCoord};
+use visibility::{Hidden, RenderLayers, Visibility};
+
+static VERT_SRC: &[u8] = include_bytes!("shaders/vertex/basic.glsl");
+static FRAG_SRC: &[u8] = include_bytes!("shaders/fragment/lit2d.glsl");
+
+static TEXTURES: [TextureType; 2] = [TextureType::Albedo, TextureType::Normal];
+
+/// Maximum number of lights drawn by the pass, the other lights are ignored.
+const MAX_LIGHTS: usize = 128;
+
+#[repr(C)]
+#[derive(Clone, Copy, Debug)]
+struct FragmentArgs {
+    point_light_count: i32,
+    _pad: [i32; 3],
+}
+
+#[repr(C)]
+#[derive(Clone, Copy, Debug)]
+struct PointLight2DPod {
+    position: [f32; 4],
+    color: [f32; 4],
+    intensity: f32,
+    radius: f32,
+    _pad: [f32; 2],
+}
+
+unsafe impl Pod for PointLight2DPod {}
+
+/// Draw sprites lit by the `PointLight2D`s and the `AmbientColor`.
+///
+/// The normal map of the `Material` shades the sprites, those without one use the flat normal
+/// of the `MaterialDefaults`. Sprites are expected to lie in the xy plane, facing the camera.
+/// `V` is `VertexFormat`
+#[derive(Derivative, Clone, Debug, PartialEq)]
+#[derivative(Default(bound = "V: Query<(Position, TexCoord)>"))]
+pub struct DrawLit2D<V> {
+    _pd: PhantomData<V>,
+    transparency: Option<(ColorMask, Blend, Option<DepthMode>)>,
+}
+
+impl<V> DrawLit2D<V>
+where
+    V: Query<(Position, TexCoord)>,
+{
+    /// Create instance of `DrawLit2D` pass
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Enable transparency, `blend` can be a `Blend` or a `BlendMode`
+    pub fn with_transparency<B: Into<Blend>>(
+        mut self,
+        mask: ColorMask,
+        blend: B,
+        depth: Option<DepthMode>,
+    ) -> Self {
+        self.transparency = Some((mask, blend.into(), depth));
+        self
+    }
+}
+
+impl<'a, V> PassData<'a> for DrawLit2D<V>
+where
+    V: Query<(Position, TexCoord)>,
+{
+    type Data = (
+        Option<Fetch<'a, ActiveCamera>>,
+        ReadStorage<'a, Camera>,
+        Fetch<'a, AmbientColor>,
+        Fetch<'a, AssetStorage<Mesh>>,
+        Fetch<'a, AssetStorage<Texture>>,
+        Fetch<'a, MaterialDefaults>,
+        Option<Fetch<'a, Visibility>>,
+        Entities<'a>,
+        ReadStorage<'a, Hidden>,
+        ReadStorage<'a, RenderLayers>,
+        ReadStorage<'a, Disabled>,
+        ReadStorage<'a, MeshHandle>,
+        ReadStorage<'a, Material>,
+        ReadStorage<'a, GlobalTransform>,
+        ReadStorage<'a, PointLight2D>,
+    );
+}
+
+impl<V> Pass for DrawLit2D<V>
+where
+    V: Query<(Position, TexCoord)>,
+{
+    fn compile(&mut self, effect: NewEffect) -> Result<Effect> {
+        let mut builder = effect.simple(VERT_SRC, FRAG_SRC);
+        builder
+            .with_raw_constant_buffer("VertexArgs", mem::size_of::<VertexArgs>(), 1)
+            .with_raw_constant_buffer("FragmentArgs", mem::size_of::<FragmentArgs>(), 1)
+            .with_raw_constant_buffer(
+                "PointLights",
+                mem::size_of::<PointLight2DPod>(),
+                MAX_LIGHTS,
+            )
+            .with_raw_global("ambient_color")
+            .with_raw_vertex_buffer(V::QUERIED_ATTRIBUTES, V::size() as ElemStride, 0);
+        setup_textures(&mut builder, &TEXTURES);
+        match self.transparency {
+            Some((mask, blend, depth)) => builder.with_blended_output("color", mask, blend, depth),
+            None => builder.with_output("color", Some(DepthMode::LessEqualWrite)),
+        };
+        builder.build()
+    }
+
+    fn apply<'a, 'b: 'a>(
+        &'a mut self,
+        encoder: &mut Encoder,
+        effect: &mut Effect,
+        mut factory: Factory,
+        (
+            active,
+            camera,
+            ambient,
+            mesh_storage,
+            tex_storage,
+            material_defaults,
+            visibility,
+            entities,
+            hidden,
+            layers,
+            disabled,
+            mesh,
+            material,
+            global,
+            lights,
+        ): <Self as PassData<'a>>::Data,
+    ) {
+        let camera = get_camera(active, &camera, &global);
+        let excluded = excluded_entities(camera, &entities, &layers, &disabled);
+
+        let point_lights: Vec<PointLight2DPod> = (&lights, &global)
+            .join()
+            .take(MAX_LIGHTS)
+            .map(|(light, global)| PointLight2DPod {
+                position: [global.0[3][0], global.0[3][1], light.height, 1.0],
+                color: light.color.into(),
+                intensity: light.intensity,
+                radius: light.radius,
+                _pad: [0.0; 2],
+            })
+            .collect();
+        let fragment_args = FragmentArgs {
+            point_light_count: point_lights.len() as i32,
+            _pad: [0; 3],
+        };
+        effect.update_constant_buffer("FragmentArgs", &fragment_args, encoder);
+        effect.update_buffer("PointLights", &point_lights[..], encoder);
+        effect.update_global("ambient_color", Into::<[f32; 3]>::into(*ambient.as_ref()));
+
+        match visibility {
+            None => for (mesh, material, global, _, _) in
+                (&mesh, &material, &global, !&hidden, !&excluded).join()
+            {
+                draw_mesh(
+                    encoder,
+                    effect,
+                    &mut factory,
+                    false,
+                    mesh_storage.get(mesh),
+                    None,
+                    &tex_storage,
+                    Some(material),
+                    &material_defaults,
+                    camera,
+                    Some(global),
+                    &[V::QUERIED_ATTRIBUTES],
+                    &TEXTURES,
+                );
+            },
+            Some(ref visibility) => {
+                for (mesh, material, global, _, _) in
+                    (&mesh, &material, &global, &visibility.visible_unordered, !&excluded).join()
+                {
+                    draw_mesh(
+                        encoder,
+                        effect,
+                        &mut factory,
+                        false,
+                        mesh_storage.get(mesh),
+                        None,
+                        &tex_storage,
+                        Some(material),
+                        &material_defaults,
+                        camera,
+                        Some(global),
+                        &[V::QUERIED_ATTRIBUTES],
+                        &TEXTURES,
+                    );
+                }
+
+                for entity in &visibility.visible_ordered {
+                    if excluded.contains(entity.id()) {
+                        continue;
+                    }
+                    if let Some(mesh) = mesh.get(*entity) {
+                        draw_mesh(
+                            encoder,
+                            effect,
+                            &mut factory,
+                            false,
+                            mesh_storage.get(mesh),
+                            None,
+                            &tex_storage,
+                            material.get(*entity),
+                            &material_defaults,
+                            camera,
+                            global.get(*entity),
+                            &[V::QUERIED_ATTRIBUTES],
+                            &TEXTURES,
+                        );
+                    }
+                }
+            }
+        }
+    }
+}
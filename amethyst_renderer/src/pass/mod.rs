@@ -1,13 +1,21 @@
 //! Different kinds of render passes.
 //
 pub use self::flat::*;
+pub use self::lit2d::*;
+pub use self::outline::*;
 pub use self::pbm::*;
+pub use self::picking::*;
 pub use self::shaded::*;
+pub use self::sprite_array::*;
 pub use self::skinning::set_skinning_buffers;
 
 mod flat;
+mod lit2d;
+mod outline;
 mod pbm;
+mod picking;
 mod shaded;
+mod sprite_array;
 mod skinning;
 mod util;
 mod shaded_util;
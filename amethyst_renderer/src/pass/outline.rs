@@ -0,0 +1,152 @@
+//! Outline drawing pass.
+
+use std::marker::PhantomData;
+
+use amethyst_assets::AssetStorage;
+use amethyst_core::Disabled;
+use amethyst_core::specs::{Entities, Fetch, Join, ReadStorage};
+use amethyst_core::transform::GlobalTransform;
+use gfx::pso::buffer::ElemStride;
+
+use cam::{ActiveCamera, Camera};
+use error::Result;
+use mesh::{Mesh, MeshHandle};
+use mtl::{Material, MaterialDefaults};
+use outline::Outline;
+use pass::util::{draw_mesh, excluded_entities, get_camera, setup_textures, TextureType,
+                 VertexArgs};
+use pipe::{DepthMode, Effect, NewEffect};
+use pipe::pass::{Pass, PassData};
+use tex::Texture;
+use transparent::{ColorMask, ALPHA};
+use types::{Encoder, Factory};
+use vertex::{Position, Query, TexCoord};
+use visibility::{Hidden, RenderLayers};
+
+static VERT_SRC: &[u8] = include_bytes!("shaders/vertex/basic.glsl");
+static FRAG_SRC: &[u8] = include_bytes!("shaders/fragment/outline.glsl");
+
+static TEXTURES: [TextureType; 1] = [TextureType::Albedo];
+
+#[repr(C)]
+#[derive(Clone, Copy, Debug)]
+struct OutlineArgs {
+    color: [f32; 4],
+    thickness: f32,
+    _pad: [f32; 3],
+}
+
+/// Draw an outline around the opaque region of the albedo texture of entities with an `Outline`.
+///
+/// The outline is drawn inside the mesh, so the texture needs transparent texels around its
+/// opaque region. Add this pass before the pass drawing the outlined meshes.
+/// `V` is `VertexFormat`
+#[derive(Derivative, Clone, Debug, PartialEq)]
+#[derivative(Default(bound = "V: Query<(Position, TexCoord)>"))]
+pub struct DrawOutline<V> {
+    _pd: PhantomData<V>,
+}
+
+impl<V> DrawOutline<V>
+where
+    V: Query<(Position, TexCoord)>,
+{
+    /// Create instance of `DrawOutline` pass
+    pub fn new() -> Self {
+        Default::default()
+    }
+}
+
+impl<'a, V> PassData<'a> for DrawOutline<V>
+where
+    V: Query<(Position, TexCoord)>,
+{
+    type Data = (
+        Option<Fetch<'a, ActiveCamera>>,
+        ReadStorage<'a, Camera>,
+        Fetch<'a, AssetStorage<Mesh>>,
+        Fetch<'a, AssetStorage<Texture>>,
+        Fetch<'a, MaterialDefaults>,
+        ReadStorage<'a, MeshHandle>,
+        ReadStorage<'a, Material>,
+        ReadStorage<'a, GlobalTransform>,
+        ReadStorage<'a, Outline>,
+        Entities<'a>,
+        ReadStorage<'a, Hidden>,
+        ReadStorage<'a, RenderLayers>,
+        ReadStorage<'a, Disabled>,
+    );
+}
+
+impl<V> Pass for DrawOutline<V>
+where
+    V: Query<(Position, TexCoord)>,
+{
+    fn compile(&mut self, effect: NewEffect) -> Result<Effect> {
+        use std::mem;
+        let mut builder = effect.simple(VERT_SRC, FRAG_SRC);
+        builder
+            .with_raw_constant_buffer("VertexArgs", mem::size_of::<VertexArgs>(), 1)
+            .with_raw_constant_buffer("OutlineArgs", mem::size_of::<OutlineArgs>(), 1)
+            .with_raw_vertex_buffer(V::QUERIED_ATTRIBUTES, V::size() as ElemStride, 0);
+        setup_textures(&mut builder, &TEXTURES);
+        builder.with_blended_output(
+            "color",
+            ColorMask::all(),
+            ALPHA,
+            Some(DepthMode::LessEqualTest),
+        );
+        builder.build()
+    }
+
+    fn apply<'a, 'b: 'a>(
+        &'a mut self,
+        encoder: &mut Encoder,
+        effect: &mut Effect,
+        mut factory: Factory,
+        (
+            active,
+            camera,
+            mesh_storage,
+            tex_storage,
+            material_defaults,
+            mesh,
+            material,
+            global,
+            outline,
+            entities,
+            hidden,
+            layers,
+            disabled,
+        ): <Self as PassData<'a>>::Data,
+    ) {
+        let camera = get_camera(active, &camera, &global);
+        let excluded = excluded_entities(camera, &entities, &layers, &disabled);
+
+        for (mesh, material, global, outline, _, _) in
+            (&mesh, &material, &global, &outline, !&hidden, !&excluded).join()
+        {
+            let outline_args = OutlineArgs {
+                color: outline.color.into(),
+                thickness: outline.thickness,
+                _pad: [0.0; 3],
+            };
+            effect.update_constant_buffer("OutlineArgs", &outline_args, encoder);
+            draw_mesh(
+                encoder,
+                effect,
+                &mut factory,
+                false,
+                mesh_storage.get(mesh),
+                None,
+                &tex_storage,
+                Some(material),
+                &material_defaults,
+                camera,
+                Some(global),
+                &[V::QUERIED_ATTRIBUTES],
+                &TEXTURES,
+            );
+        }
+    }
+}
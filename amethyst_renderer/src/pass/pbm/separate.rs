@@ -1,6 +1,7 @@
 //! Forward physically-based drawing pass.
 
 use amethyst_assets::AssetStorage;
+use amethyst_core::Disabled;
 use amethyst_core::specs::{Entities, Fetch, Join, ReadStorage};
 use amethyst_core::transform::GlobalTransform;
 use gfx::pso::buffer::ElemStride;
@@ -14,15 +15,15 @@ use mesh::{Mesh, MeshHandle};
 use mtl::{Material, MaterialDefaults};
 use pass::shaded_util::{set_light_args, setup_light_buffers};
 use pass::skinning::{create_skinning_effect, setup_skinning_buffers};
-use pass::util::{draw_mesh, get_camera, setup_textures, setup_vertex_args};
+use pass::util::{draw_mesh, excluded_entities, get_camera, setup_textures, setup_vertex_args};
 use pipe::{DepthMode, Effect, NewEffect};
 use pipe::pass::{Pass, PassData};
-use resources::AmbientColor;
+use resources::{AmbientColor, PolygonMode};
 use skinning::JointTransforms;
 use tex::Texture;
 use types::{Encoder, Factory};
 use vertex::{Attributes, Normal, Position, Separate, Tangent, TexCoord, VertexFormat};
-use visibility::Visibility;
+use visibility::{Hidden, RenderLayers, Visibility};
 
 static ATTRIBUTES: [Attributes<'static>; 4] = [
     Separate::<Position>::ATTRIBUTES,
@@ -50,14 +51,14 @@ impl DrawPbmSeparate {
         self
     }
 
-    /// Enable transparency
-    pub fn with_transparency(
+    /// Enable transparency, `blend` can be a `Blend` or a `BlendMode`
+    pub fn with_transparency<B: Into<Blend>>(
         mut self,
         mask: ColorMask,
-        blend: Blend,
+        blend: B,
         depth: Option<DepthMode>,
     ) -> Self {
-        self.transparency = Some((mask, blend, depth));
+        self.transparency = Some((mask, blend.into(), depth));
         self
     }
 }
@@ -72,6 +73,10 @@ impl<'a> PassData<'a> for DrawPbmSeparate {
         Fetch<'a, AssetStorage<Texture>>,
         Fetch<'a, MaterialDefaults>,
         Option<Fetch<'a, Visibility>>,
+        Option<Fetch<'a, PolygonMode>>,
+        ReadStorage<'a, Hidden>,
+        ReadStorage<'a, RenderLayers>,
+        ReadStorage<'a, Disabled>,
         ReadStorage<'a, MeshHandle>,
         ReadStorage<'a, Material>,
         ReadStorage<'a, GlobalTransform>,
@@ -118,14 +123,14 @@ impl Pass for DrawPbmSeparate {
             Some((mask, blend, depth)) => builder.with_blended_output("color", mask, blend, depth),
             None => builder.with_output("color", Some(DepthMode::LessEqualWrite)),
         };
-        builder.build()
+        builder.with_wireframe_variant().build()
     }
 
     fn apply<'a, 'b: 'a>(
         &'a mut self,
         encoder: &mut Encoder,
         effect: &mut Effect,
-        _factory: Factory,
+        mut factory: Factory,
         (
             entities,
             active,
@@ -135,6 +140,10 @@ impl Pass for DrawPbmSeparate {
             tex_storage,
             material_defaults,
             visibility,
+            polygon_mode,
+            hidden,
+            layers,
+            disabled,
             mesh,
             material,
             global,
@@ -143,16 +152,19 @@ impl Pass for DrawPbmSeparate {
         ): <Self as PassData<'a>>::Data,
     ) {
         let camera = get_camera(active, &camera, &global);
+        let excluded = excluded_entities(camera, &entities, &layers, &disabled);
+        effect.set_wireframe(polygon_mode.map_or(false, |mode| *mode == PolygonMode::Line));
 
         set_light_args(effect, encoder, &light, &ambient, camera);
 
         match visibility {
-            None => for (entity, mesh, material, global) in
-                (&*entities, &mesh, &material, &global).join()
+            None => for (entity, mesh, material, global, _, _) in
+                (&*entities, &mesh, &material, &global, !&hidden, !&excluded).join()
             {
                 draw_mesh(
                     encoder,
                     effect,
+                    &mut factory,
                     self.skinning,
                     mesh_storage.get(mesh),
                     joints.get(entity),
@@ -166,17 +178,19 @@ impl Pass for DrawPbmSeparate {
                 );
             },
             Some(ref visibility) => {
-                for (entity, mesh, material, global, _) in (
+                for (entity, mesh, material, global, _, _) in (
                     &*entities,
                     &mesh,
                     &material,
                     &global,
                     &visibility.visible_unordered,
+                    !&excluded,
                 ).join()
                 {
                     draw_mesh(
                         encoder,
                         effect,
+                        &mut factory,
                         self.skinning,
                         mesh_storage.get(mesh),
                         joints.get(entity),
@@ -191,10 +205,14 @@ impl Pass for DrawPbmSeparate {
                 }
 
                 for entity in &visibility.visible_ordered {
+                    if excluded.contains(entity.id()) {
+                        continue;
+                    }
                     if let Some(mesh) = mesh.get(*entity) {
                         draw_mesh(
                             encoder,
                             effect,
+                            &mut factory,
                             self.skinning,
                             mesh_storage.get(mesh),
                             joints.get(*entity),
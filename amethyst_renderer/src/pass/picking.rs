@@ -0,0 +1,246 @@
+//! Entity id drawing pass, used for picking.
+
+use std::marker::PhantomData;
+
+use amethyst_assets::AssetStorage;
+use amethyst_core::Disabled;
+use amethyst_core::specs::{Entities, Fetch, FetchMut, Join, ReadStorage};
+use amethyst_core::transform::GlobalTransform;
+use gfx::handle::Buffer;
+use gfx::pso::buffer::ElemStride;
+
+use cam::{ActiveCamera, Camera};
+use error::Result;
+use mesh::{Mesh, MeshHandle};
+use mtl::{Material, MaterialDefaults};
+use pass::util::{draw_mesh, excluded_entities, get_camera, setup_textures, TextureType,
+                 VertexArgs};
+use picking::Picking;
+use pipe::{DepthMode, Effect, NewEffect};
+use pipe::pass::{Pass, PassData};
+use tex::Texture;
+use types::{ColorFormat, Encoder, Factory, Resources};
+use vertex::{Position, Query, TexCoord};
+use visibility::{Hidden, RenderLayers};
+
+static VERT_SRC: &[u8] = include_bytes!("shaders/vertex/basic.glsl");
+static FRAG_SRC: &[u8] = include_bytes!("shaders/fragment/picking.glsl");
+
+static TEXTURES: [TextureType; 1] = [TextureType::Albedo];
+
+#[repr(C)]
+#[derive(Clone, Copy, Debug)]
+struct PickingArgs {
+    id: [f32; 4],
+}
+
+/// Draw the id of each entity to the target of the stage, so the `Picking` resource can find
+/// the entity drawn at a pixel.
+///
+/// The stage needs its own target with a depth buffer, as the window can't be read back, and
+/// should clear it to transparent black, meaning no entity. Texels of the albedo texture with an
+/// alpha not above 0.5 are not drawn, so sprites are picked by their actual shape.
+/// `V` is `VertexFormat`
+#[derive(Derivative, Clone, Debug, PartialEq)]
+#[derivative(Default(bound = "V: Query<(Position, TexCoord)>"))]
+pub struct DrawPickingIds<V> {
+    download: Option<Buffer<Resources, [u8; 4]>>,
+    pending: Option<(u32, u32)>,
+    _pd: PhantomData<V>,
+}
+
+impl<V> DrawPickingIds<V>
+where
+    V: Query<(Position, TexCoord)>,
+{
+    /// Create instance of `DrawPickingIds` pass
+    pub fn new() -> Self {
+        Default::default()
+    }
+}
+
+impl<'a, V> PassData<'a> for DrawPickingIds<V>
+where
+    V: Query<(Position, TexCoord)>,
+{
+    type Data = (
+        Option<Fetch<'a, ActiveCamera>>,
+        ReadStorage<'a, Camera>,
+        Fetch<'a, AssetStorage<Mesh>>,
+        Fetch<'a, AssetStorage<Texture>>,
+        Fetch<'a, MaterialDefaults>,
+        FetchMut<'a, Picking>,
+        ReadStorage<'a, MeshHandle>,
+        ReadStorage<'a, Material>,
+        ReadStorage<'a, GlobalTransform>,
+        Entities<'a>,
+        ReadStorage<'a, Hidden>,
+        ReadStorage<'a, RenderLayers>,
+        ReadStorage<'a, Disabled>,
+    );
+}
+
+impl<V> Pass for DrawPickingIds<V>
+where
+    V: Query<(Position, TexCoord)>,
+{
+    fn compile(&mut self, effect: NewEffect) -> Result<Effect> {
+        use std::mem;
+        let mut builder = effect.simple(VERT_SRC, FRAG_SRC);
+        builder
+            .with_raw_constant_buffer("VertexArgs", mem::size_of::<VertexArgs>(), 1)
+            .with_raw_constant_buffer("PickingArgs", mem::size_of::<PickingArgs>(), 1)
+            .with_raw_vertex_buffer(V::QUERIED_ATTRIBUTES, V::size() as ElemStride, 0);
+        setup_textures(&mut builder, &TEXTURES);
+        builder.with_output("color", Some(DepthMode::LessEqualWrite));
+        builder.build()
+    }
+
+    fn apply<'a, 'b: 'a>(
+        &'a mut self,
+        encoder: &mut Encoder,
+        effect: &mut Effect,
+        mut factory: Factory,
+        (
+            active,
+            camera,
+            mesh_storage,
+            tex_storage,
+            material_defaults,
+            mut picking,
+            mesh,
+            material,
+            global,
+            entities,
+            hidden,
+            layers,
+            disabled,
+        ): <Self as PassData<'a>>::Data,
+    ) {
+        // The pixel copied last frame has been read back by now.
+        if let Some(pixel) = self.pending.take() {
+            let entity = self.download
+                .as_ref()
+                .and_then(|buffer| read_id(&mut factory, buffer))
+                .map(|id| entities.entity(id))
+                .and_then(|entity| {
+                    if entities.is_alive(entity) {
+                        Some(entity)
+                    } else {
+                        None
+                    }
+                });
+            picking.picked = Some((pixel, entity));
+        }
+
+        let camera = get_camera(active, &camera, &global);
+        let excluded = excluded_entities(camera, &entities, &layers, &disabled);
+
+        for (entity, mesh, material, global, _, _) in (
+            &*entities,
+            &mesh,
+            &material,
+            &global,
+            !&hidden,
+            !&excluded,
+        ).join()
+        {
+            let picking_args = PickingArgs {
+                id: encode_id(entity.id()),
+            };
+            effect.update_constant_buffer("PickingArgs", &picking_args, encoder);
+            draw_mesh(
+                encoder,
+                effect,
+                &mut factory,
+                false,
+                mesh_storage.get(mesh),
+                None,
+                &tex_storage,
+                Some(material),
+                &material_defaults,
+                camera,
+                Some(global),
+                &[V::QUERIED_ATTRIBUTES],
+                &TEXTURES,
+            );
+        }
+
+        if let Some(pixel) = picking.request.take() {
+            if self.copy_pixel(encoder, effect, &mut factory, pixel) {
+                self.pending = Some(pixel);
+            }
+        }
+    }
+}
+
+impl<V> DrawPickingIds<V> {
+    /// Copy the pixel `(x, y)` of the target, measured from the top left corner, to the download
+    /// buffer.
+    fn copy_pixel(
+        &mut self,
+        encoder: &mut Encoder,
+        effect: &Effect,
+        factory: &mut Factory,
+        (x, y): (u32, u32),
+    ) -> bool {
+        use gfx::Factory;
+        use gfx::format::Formatted;
+        use gfx::memory::Typed;
+        use gfx::texture::ImageInfoCommon;
+
+        let (width, height) = effect.target_size();
+        let texture = match effect.target_texture() {
+            Some(texture) if x < width && y < height => texture,
+            _ => return false,
+        };
+        if self.download.is_none() {
+            match factory.create_download_buffer(1) {
+                Ok(buffer) => self.download = Some(buffer),
+                Err(err) => {
+                    error!("Failed to create the picking buffer: {:?}", err);
+                    return false;
+                }
+            }
+        }
+        let info = ImageInfoCommon {
+            xoffset: x as u16,
+            // Textures start at the bottom.
+            yoffset: (height - 1 - y) as u16,
+            zoffset: 0,
+            width: 1,
+            height: 1,
+            depth: 1,
+            format: ColorFormat::get_format(),
+            mipmap: 0,
+        };
+        let buffer = self.download.as_ref().unwrap().raw();
+        encoder
+            .copy_texture_to_buffer_raw(texture, None, info, buffer, 0)
+            .is_ok()
+    }
+}
+
+/// Encodes an entity id into a color, 0 meaning no entity.
+fn encode_id(id: u32) -> [f32; 4] {
+    let id = id + 1;
+    [
+        (id & 0xFF) as f32 / 255.,
+        ((id >> 8) & 0xFF) as f32 / 255.,
+        ((id >> 16) & 0xFF) as f32 / 255.,
+        1.,
+    ]
+}
+
+fn read_id(factory: &mut Factory, buffer: &Buffer<Resources, [u8; 4]>) -> Option<u32> {
+    use gfx::Factory;
+
+    let reader = factory.read_mapping(buffer).ok()?;
+    let pixel = reader[0];
+    let id = pixel[0] as u32 | (pixel[1] as u32) << 8 | (pixel[2] as u32) << 16;
+    if id == 0 {
+        None
+    } else {
+        Some(id - 1)
+    }
+}
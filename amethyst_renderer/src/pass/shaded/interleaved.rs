@@ -3,7 +3,8 @@
 use std::marker::PhantomData;
 
 use amethyst_assets::AssetStorage;
-use amethyst_core::specs::{Fetch, Join, ReadStorage};
+use amethyst_core::Disabled;
+use amethyst_core::specs::{Entities, Fetch, Join, ReadStorage};
 use amethyst_core::transform::GlobalTransform;
 use gfx::pso::buffer::ElemStride;
 use gfx_core::state::{Blend, ColorMask};
@@ -15,14 +16,14 @@ use light::Light;
 use mesh::{Mesh, MeshHandle};
 use mtl::{Material, MaterialDefaults};
 use pass::shaded_util::{set_light_args, setup_light_buffers};
-use pass::util::{draw_mesh, get_camera, setup_textures, setup_vertex_args};
+use pass::util::{draw_mesh, excluded_entities, get_camera, setup_textures, setup_vertex_args};
 use pipe::{DepthMode, Effect, NewEffect};
 use pipe::pass::{Pass, PassData};
-use resources::AmbientColor;
+use resources::{AmbientColor, PolygonMode};
 use tex::Texture;
 use types::{Encoder, Factory};
 use vertex::{Normal, Position, Query, TexCoord};
-use visibility::Visibility;
+use visibility::{Hidden, RenderLayers, Visibility};
 
 /// Draw mesh with simple lighting technique
 /// `V` is `VertexFormat`
@@ -42,14 +43,14 @@ where
         Default::default()
     }
 
-    /// Enable transparency
-    pub fn with_transparency(
+    /// Enable transparency, `blend` can be a `Blend` or a `BlendMode`
+    pub fn with_transparency<B: Into<Blend>>(
         mut self,
         mask: ColorMask,
-        blend: Blend,
+        blend: B,
         depth: Option<DepthMode>,
     ) -> Self {
-        self.transparency = Some((mask, blend, depth));
+        self.transparency = Some((mask, blend.into(), depth));
         self
     }
 }
@@ -66,6 +67,11 @@ where
         Fetch<'a, AssetStorage<Texture>>,
         Fetch<'a, MaterialDefaults>,
         Option<Fetch<'a, Visibility>>,
+        Option<Fetch<'a, PolygonMode>>,
+        Entities<'a>,
+        ReadStorage<'a, Hidden>,
+        ReadStorage<'a, RenderLayers>,
+        ReadStorage<'a, Disabled>,
         ReadStorage<'a, MeshHandle>,
         ReadStorage<'a, Material>,
         ReadStorage<'a, GlobalTransform>,
@@ -87,14 +93,14 @@ where
             Some((mask, blend, depth)) => builder.with_blended_output("color", mask, blend, depth),
             None => builder.with_output("color", Some(DepthMode::LessEqualWrite)),
         };
-        builder.build()
+        builder.with_wireframe_variant().build()
     }
 
     fn apply<'a, 'b: 'a>(
         &'a mut self,
         encoder: &mut Encoder,
         effect: &mut Effect,
-        _factory: Factory,
+        mut factory: Factory,
         (
             active,
             camera,
@@ -103,6 +109,11 @@ where
             tex_storage,
             material_defaults,
             visibility,
+            polygon_mode,
+            entities,
+            hidden,
+            layers,
+            disabled,
             mesh,
             material,
             global,
@@ -110,14 +121,19 @@ where
         ): <Self as PassData<'a>>::Data,
     ) {
         let camera = get_camera(active, &camera, &global);
+        let excluded = excluded_entities(camera, &entities, &layers, &disabled);
+        effect.set_wireframe(polygon_mode.map_or(false, |mode| *mode == PolygonMode::Line));
 
         set_light_args(effect, encoder, &light, &ambient, camera);
 
         match visibility {
-            None => for (mesh, material, global) in (&mesh, &material, &global).join() {
+            None => for (mesh, material, global, _, _) in
+                (&mesh, &material, &global, !&hidden, !&excluded).join()
+            {
                 draw_mesh(
                     encoder,
                     effect,
+                    &mut factory,
                     false,
                     mesh_storage.get(mesh),
                     None,
@@ -131,12 +147,13 @@ where
                 );
             },
             Some(ref visibility) => {
-                for (mesh, material, global, _) in
-                    (&mesh, &material, &global, &visibility.visible_unordered).join()
+                for (mesh, material, global, _, _) in
+                    (&mesh, &material, &global, &visibility.visible_unordered, !&excluded).join()
                 {
                     draw_mesh(
                         encoder,
                         effect,
+                        &mut factory,
                         false,
                         mesh_storage.get(mesh),
                         None,
@@ -151,10 +168,14 @@ where
                 }
 
                 for entity in &visibility.visible_ordered {
+                    if excluded.contains(entity.id()) {
+                        continue;
+                    }
                     if let Some(mesh) = mesh.get(*entity) {
                         draw_mesh(
                             encoder,
                             effect,
+                            &mut factory,
                             false,
                             mesh_storage.get(mesh),
                             None,
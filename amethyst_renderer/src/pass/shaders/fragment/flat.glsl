@@ -9,6 +9,15 @@ layout (std140) uniform AlbedoOffset {
     vec2 v_offset;
 } albedo_offset;
 
+layout (std140) uniform AlbedoColor {
+    vec4 albedo_color;
+};
+
+layout (std140) uniform CropArgs {
+    vec2 crop_min;
+    vec2 crop_max;
+};
+
 in VertexData {
     vec4 position;
     vec3 normal;
@@ -27,5 +36,9 @@ vec2 tex_coords(vec2 coord, vec2 u, vec2 v) {
 }
 
 void main() {
-    color = texture(albedo, tex_coords(vertex.tex_coord, albedo_offset.u_offset, albedo_offset.v_offset));
+    if (any(lessThan(vertex.tex_coord, crop_min)) || any(greaterThan(vertex.tex_coord, crop_max))) {
+        discard;
+    }
+    color = texture(albedo, tex_coords(vertex.tex_coord, albedo_offset.u_offset, albedo_offset.v_offset)) * albedo_color;
+    color.rgb = srgb_encode(color.rgb);
 }
@@ -0,0 +1,43 @@
+// TODO: Needs documentation.
+
+#version 150 core
+
+uniform sampler2D albedo;
+
+layout (std140) uniform AlbedoOffset {
+    vec2 u_offset;
+    vec2 v_offset;
+} albedo_offset;
+
+layout (std140) uniform AlbedoColor {
+    vec4 albedo_color;
+};
+
+layout (std140) uniform CropArgs {
+    vec2 crop_min;
+    vec2 crop_max;
+};
+
+in VertexData {
+    vec4 position;
+    vec4 color;
+    vec2 tex_coord;
+} vertex;
+
+out vec4 color;
+
+float tex_coord(float coord, vec2 offset) {
+    return offset.x + coord * (offset.y - offset.x);
+}
+
+vec2 tex_coords(vec2 coord, vec2 u, vec2 v) {
+    return vec2(tex_coord(coord.x, u), tex_coord(coord.y, v));
+}
+
+void main() {
+    if (any(lessThan(vertex.tex_coord, crop_min)) || any(greaterThan(vertex.tex_coord, crop_max))) {
+        discard;
+    }
+    color = texture(albedo, tex_coords(vertex.tex_coord, albedo_offset.u_offset, albedo_offset.v_offset)) * albedo_color * vertex.color;
+    color.rgb = srgb_encode(color.rgb);
+}
@@ -0,0 +1,71 @@
+// TODO: Needs documentation.
+
+#version 150 core
+
+layout (std140) uniform FragmentArgs {
+    int point_light_count;
+};
+
+struct PointLight {
+    vec4 position;
+    vec4 color;
+    float intensity;
+    float radius;
+    vec2 _pad;
+};
+
+layout (std140) uniform PointLights {
+    PointLight plight[128];
+};
+
+uniform vec3 ambient_color;
+
+uniform sampler2D albedo;
+uniform sampler2D normal;
+
+layout (std140) uniform AlbedoOffset {
+    vec2 u_offset;
+    vec2 v_offset;
+} albedo_offset;
+
+layout (std140) uniform AlbedoColor {
+    vec4 albedo_color;
+};
+
+layout (std140) uniform NormalOffset {
+    vec2 u_offset;
+    vec2 v_offset;
+} normal_offset;
+
+in VertexData {
+    vec4 position;
+    vec3 normal;
+    vec3 tangent;
+    vec2 tex_coord;
+} vertex;
+
+out vec4 out_color;
+
+float tex_coord(float coord, vec2 offset) {
+    return offset.x + coord * (offset.y - offset.x);
+}
+
+vec2 tex_coords(vec2 coord, vec2 u, vec2 v) {
+    return vec2(tex_coord(coord.x, u), tex_coord(coord.y, v));
+}
+
+void main() {
+    vec4 color = texture(albedo, tex_coords(vertex.tex_coord, albedo_offset.u_offset, albedo_offset.v_offset)) * albedo_color;
+    // Normal maps point towards the camera, along the z axis.
+    vec3 normal = normalize(texture(normal, tex_coords(vertex.tex_coord, normal_offset.u_offset, normal_offset.v_offset)).rgb * 2.0 - 1.0);
+    vec3 lighting = ambient_color;
+    for (int i = 0; i < point_light_count; i++) {
+        vec2 delta = plight[i].position.xy - vertex.position.xy;
+        float falloff = clamp(1.0 - length(delta) / plight[i].radius, 0.0, 1.0);
+        vec3 light_dir = normalize(vec3(delta, plight[i].position.z));
+        float diff = max(dot(light_dir, normal), 0.0);
+        lighting += plight[i].color.rgb * plight[i].intensity * diff * falloff * falloff;
+    }
+    out_color = vec4(lighting, 1.0) * color;
+    out_color.rgb = srgb_encode(out_color.rgb);
+}
@@ -0,0 +1,60 @@
+// TODO: Needs documentation.
+
+#version 150 core
+
+uniform sampler2D albedo;
+
+layout (std140) uniform AlbedoOffset {
+    vec2 u_offset;
+    vec2 v_offset;
+} albedo_offset;
+
+layout (std140) uniform AlbedoColor {
+    vec4 albedo_color;
+};
+
+layout (std140) uniform OutlineArgs {
+    uniform vec4 outline_color;
+    uniform float thickness;
+};
+
+in VertexData {
+    vec4 position;
+    vec3 normal;
+    vec3 tangent;
+    vec2 tex_coord;
+} vertex;
+
+out vec4 color;
+
+float tex_coord(float coord, vec2 offset) {
+    return offset.x + coord * (offset.y - offset.x);
+}
+
+vec2 tex_coords(vec2 coord, vec2 u, vec2 v) {
+    return vec2(tex_coord(coord.x, u), tex_coord(coord.y, v));
+}
+
+void main() {
+    vec2 uv = tex_coords(vertex.tex_coord, albedo_offset.u_offset, albedo_offset.v_offset);
+    if (texture(albedo, uv).a > 0.5) {
+        discard;
+    }
+
+    vec2 texel = thickness / vec2(textureSize(albedo, 0));
+    float neighbour = 0.0;
+    neighbour = max(neighbour, texture(albedo, uv + vec2(texel.x, 0.0)).a);
+    neighbour = max(neighbour, texture(albedo, uv - vec2(texel.x, 0.0)).a);
+    neighbour = max(neighbour, texture(albedo, uv + vec2(0.0, texel.y)).a);
+    neighbour = max(neighbour, texture(albedo, uv - vec2(0.0, texel.y)).a);
+    neighbour = max(neighbour, texture(albedo, uv + texel).a);
+    neighbour = max(neighbour, texture(albedo, uv - texel).a);
+    neighbour = max(neighbour, texture(albedo, uv + vec2(texel.x, -texel.y)).a);
+    neighbour = max(neighbour, texture(albedo, uv + vec2(-texel.x, texel.y)).a);
+    if (neighbour <= 0.5) {
+        discard;
+    }
+
+    color = vec4(outline_color.rgb, outline_color.a * albedo_color.a);
+    color.rgb = srgb_encode(color.rgb);
+}
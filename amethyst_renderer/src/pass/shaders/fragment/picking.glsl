@@ -0,0 +1,43 @@
+// Writes the id of the entity, for the texels opaque enough to be picked.
+
+#version 150 core
+
+uniform sampler2D albedo;
+
+layout (std140) uniform AlbedoOffset {
+    vec2 u_offset;
+    vec2 v_offset;
+} albedo_offset;
+
+layout (std140) uniform AlbedoColor {
+    vec4 albedo_color;
+};
+
+layout (std140) uniform PickingArgs {
+    uniform vec4 id;
+};
+
+in VertexData {
+    vec4 position;
+    vec3 normal;
+    vec3 tangent;
+    vec2 tex_coord;
+} vertex;
+
+out vec4 color;
+
+float tex_coord(float coord, vec2 offset) {
+    return offset.x + coord * (offset.y - offset.x);
+}
+
+vec2 tex_coords(vec2 coord, vec2 u, vec2 v) {
+    return vec2(tex_coord(coord.x, u), tex_coord(coord.y, v));
+}
+
+void main() {
+    vec2 uv = tex_coords(vertex.tex_coord, albedo_offset.u_offset, albedo_offset.v_offset);
+    if (texture(albedo, uv).a * albedo_color.a <= 0.5) {
+        discard;
+    }
+    color = id;
+}
@@ -0,0 +1,17 @@
+// Fragment shader averaging the samples of a multisampled color buffer.
+
+#version 150 core
+
+uniform sampler2DMS source;
+uniform int samples;
+
+out vec4 color;
+
+void main() {
+    ivec2 pixel = ivec2(gl_FragCoord.xy);
+    vec4 sum = vec4(0.0);
+    for (int i = 0; i < samples; i++) {
+        sum += texelFetch(source, pixel, i);
+    }
+    color = sum / float(samples);
+}
@@ -40,6 +40,10 @@ layout (std140) uniform AlbedoOffset {
     vec2 v_offset;
 } albedo_offset;
 
+layout (std140) uniform AlbedoColor {
+    vec4 albedo_color;
+};
+
 layout (std140) uniform EmissionOffset {
     vec2 u_offset;
     vec2 v_offset;
@@ -63,7 +67,7 @@ vec2 tex_coords(vec2 coord, vec2 u, vec2 v) {
 }
 
 void main() {
-    vec4 color = texture(albedo, tex_coords(vertex.tex_coord, albedo_offset.u_offset, albedo_offset.v_offset));
+    vec4 color = texture(albedo, tex_coords(vertex.tex_coord, albedo_offset.u_offset, albedo_offset.v_offset)) * albedo_color;
     vec4 ecolor = texture(emission, tex_coords(vertex.tex_coord, emission_offset.u_offset, emission_offset.v_offset));
     vec4 lighting = vec4(0.0);
     vec4 normal = vec4(normalize(vertex.normal), 0.0);
@@ -86,4 +90,5 @@ void main() {
     }
     lighting += vec4(ambient_color, 0.0);
     out_color = lighting * color + ecolor;
+    out_color.rgb = srgb_encode(out_color.rgb);
 }
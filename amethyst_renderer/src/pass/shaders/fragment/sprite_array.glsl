@@ -0,0 +1,16 @@
+// Fragment shader of the sprites batched by `DrawSpriteArray`, sampling a layer of the array.
+
+#version 150 core
+
+uniform sampler2DArray albedo;
+
+in VertexData {
+    vec3 tex_coord;
+} vertex;
+
+out vec4 color;
+
+void main() {
+    color = texture(albedo, vertex.tex_coord);
+    color.rgb = srgb_encode(color.rgb);
+}
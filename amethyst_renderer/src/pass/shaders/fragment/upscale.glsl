@@ -0,0 +1,15 @@
+// Fragment shader copying the internal resolution to the window.
+
+#version 150 core
+
+uniform sampler2D source;
+
+in VertexData {
+    vec2 tex_coord;
+} vertex;
+
+out vec4 color;
+
+void main() {
+    color = texture(source, vertex.tex_coord);
+}
@@ -0,0 +1,10 @@
+// Inserted after the `#version` line of every pixel shader, `SRGB_OUTPUT` being defined before
+// it when the shader draws to a target encoding its output to sRGB.
+
+vec3 srgb_encode(vec3 rgb) {
+#ifdef SRGB_OUTPUT
+    return mix(rgb * 12.92, 1.055 * pow(rgb, vec3(1.0 / 2.4)) - 0.055, step(0.0031308, rgb));
+#else
+    return rgb;
+#endif
+}
@@ -0,0 +1,26 @@
+// TODO: Needs documentation.
+
+#version 150 core
+
+layout (std140) uniform VertexArgs {
+    uniform mat4 proj;
+    uniform mat4 view;
+    uniform mat4 model;
+};
+
+in vec3 position;
+in vec4 color;
+in vec2 tex_coord;
+
+out VertexData {
+    vec4 position;
+    vec4 color;
+    vec2 tex_coord;
+} vertex;
+
+void main() {
+    vertex.position = model * vec4(position, 1.0);
+    vertex.color = color;
+    vertex.tex_coord = tex_coord;
+    gl_Position = proj * view * vertex.position;
+}
@@ -0,0 +1,9 @@
+// Vertex shader of the quad covering a multisampled target to resolve.
+
+#version 150 core
+
+in vec3 position;
+
+void main() {
+    gl_Position = vec4(position.xy, 0.0, 1.0);
+}
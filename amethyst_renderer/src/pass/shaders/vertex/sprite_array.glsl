@@ -0,0 +1,22 @@
+// Vertex shader of the sprites batched by `DrawSpriteArray`, already in world space.
+
+#version 150 core
+
+layout (std140) uniform VertexArgs {
+    uniform mat4 proj;
+    uniform mat4 view;
+    uniform mat4 model;
+};
+
+in vec3 position;
+in vec2 tex_coord;
+in float tex_layer;
+
+out VertexData {
+    vec3 tex_coord;
+} vertex;
+
+void main() {
+    vertex.tex_coord = vec3(tex_coord, tex_layer);
+    gl_Position = proj * view * model * vec4(position, 1.0);
+}
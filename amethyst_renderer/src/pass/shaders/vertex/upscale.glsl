@@ -0,0 +1,20 @@
+// Vertex shader of the quad showing the internal resolution in the window.
+
+#version 150 core
+
+layout (std140) uniform UpscaleArgs {
+    uniform vec2 scale;
+    uniform vec2 offset;
+};
+
+in vec3 position;
+in vec2 tex_coord;
+
+out VertexData {
+    vec2 tex_coord;
+} vertex;
+
+void main() {
+    vertex.tex_coord = tex_coord;
+    gl_Position = vec4(position.xy * scale + offset, 0.0, 1.0);
+}
@@ -0,0 +1,206 @@
+//! Batched drawing of the sprites of a texture array.
+
+use std::cmp::Ordering;
+
+use amethyst_assets::AssetStorage;
+use amethyst_core::Disabled;
+use amethyst_core::cgmath::Vector4;
+use amethyst_core::specs::{Entities, Fetch, Join, ReadStorage};
+use amethyst_core::transform::GlobalTransform;
+use gfx::pso::buffer::ElemStride;
+use gfx_core::state::{Blend, ColorMask};
+
+use cam::{ActiveCamera, Camera};
+use error::Result;
+use mesh::DynamicVertexBuffer;
+use pass::util::{add_texture, excluded_entities, get_camera, render_transform, set_vertex_args,
+                 VertexArgs};
+use pipe::{DepthMode, Effect, NewEffect};
+use pipe::pass::{Pass, PassData};
+use sprite::{ArraySprite, RenderScale, Sprite, SpriteArray};
+use tex::Texture;
+use types::{Encoder, Factory};
+use vertex::{PosTexLayer, VertexFormat};
+use visibility::{sort_key, Hidden, RenderLayers, SortingLayer, SortingLayers};
+
+static VERT_SRC: &[u8] = include_bytes!("shaders/vertex/sprite_array.glsl");
+static FRAG_SRC: &[u8] = include_bytes!("shaders/fragment/sprite_array.glsl");
+
+/// Draw the `ArraySprite`s of all the entities in a single draw call, sampling the texture array
+/// of the `SpriteArray` resource.
+///
+/// As every sheet is a layer of the same texture, sprites of different sheets don't break the
+/// batch. Sprites are drawn in the order of their `SortingLayer`, then from back to front within
+/// a layer with transparency enabled.
+#[derive(Derivative, Clone, Debug)]
+#[derivative(Default)]
+pub struct DrawSpriteArray {
+    vertices: Vec<PosTexLayer>,
+    #[derivative(Default(value = "DynamicVertexBuffer::new()"))]
+    buffer: DynamicVertexBuffer<PosTexLayer>,
+    transparency: Option<(ColorMask, Blend, Option<DepthMode>)>,
+}
+
+impl DrawSpriteArray {
+    /// Create instance of `DrawSpriteArray` pass
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Enable transparency, `blend` can be a `Blend` or a `BlendMode`
+    pub fn with_transparency<B: Into<Blend>>(
+        mut self,
+        mask: ColorMask,
+        blend: B,
+        depth: Option<DepthMode>,
+    ) -> Self {
+        self.transparency = Some((mask, blend.into(), depth));
+        self
+    }
+}
+
+impl<'a> PassData<'a> for DrawSpriteArray {
+    type Data = (
+        Option<Fetch<'a, ActiveCamera>>,
+        ReadStorage<'a, Camera>,
+        Fetch<'a, AssetStorage<Texture>>,
+        Option<Fetch<'a, SpriteArray>>,
+        Entities<'a>,
+        ReadStorage<'a, Hidden>,
+        ReadStorage<'a, RenderLayers>,
+        ReadStorage<'a, Disabled>,
+        ReadStorage<'a, ArraySprite>,
+        ReadStorage<'a, GlobalTransform>,
+        ReadStorage<'a, RenderScale>,
+        Option<Fetch<'a, SortingLayers>>,
+        ReadStorage<'a, SortingLayer>,
+    );
+}
+
+impl Pass for DrawSpriteArray {
+    fn compile(&mut self, effect: NewEffect) -> Result<Effect> {
+        use std::mem;
+        let mut builder = effect.simple(VERT_SRC, FRAG_SRC);
+        builder
+            .with_raw_constant_buffer("VertexArgs", mem::size_of::<VertexArgs>(), 1)
+            .with_raw_vertex_buffer(
+                PosTexLayer::ATTRIBUTES,
+                PosTexLayer::size() as ElemStride,
+                0,
+            )
+            .with_texture("albedo");
+        match self.transparency {
+            Some((mask, blend, depth)) => builder.with_blended_output("color", mask, blend, depth),
+            None => builder.with_output("color", Some(DepthMode::LessEqualWrite)),
+        };
+        builder.build()
+    }
+
+    fn apply<'a, 'b: 'a>(
+        &'a mut self,
+        encoder: &mut Encoder,
+        effect: &mut Effect,
+        mut factory: Factory,
+        (
+            active,
+            camera,
+            tex_storage,
+            array,
+            entities,
+            hidden,
+            layers,
+            disabled,
+            sprites,
+            global,
+            render_scale,
+            sorting_layers,
+            sorting_layer,
+        ): <Self as PassData<'a>>::Data,
+    ) {
+        let array = match array {
+            Some(array) => array,
+            None => return,
+        };
+        let texture = match array.texture.as_ref().and_then(|tex| tex_storage.get(tex)) {
+            Some(texture) => texture,
+            None => return,
+        };
+        let camera = get_camera(active, &camera, &global);
+        let excluded = excluded_entities(camera, &entities, &layers, &disabled);
+
+        let sorting_layers = sorting_layers.as_ref().map(|layers| &**layers);
+        let mut drawn = (&*entities, &sprites, &global, !&hidden, !&excluded)
+            .join()
+            .filter_map(|(entity, sprite, global, _, _)| {
+                let global = render_transform(global, render_scale.get(entity));
+                let key = sort_key(sorting_layers, sorting_layer.get(entity));
+                array
+                    .sprite(sprite.layer, sprite.sprite)
+                    .map(|uv| (key, sprite.layer, uv, global))
+            })
+            .collect::<Vec<_>>();
+        let back_to_front = self.transparency.is_some();
+        drawn.sort_by(|a, b| {
+            a.0.cmp(&b.0).then_with(|| if back_to_front {
+                (a.3).0.w.z
+                    .partial_cmp(&(b.3).0.w.z)
+                    .unwrap_or(Ordering::Equal)
+            } else {
+                Ordering::Equal
+            })
+        });
+
+        self.vertices.clear();
+        for (_, layer, uv, global) in drawn {
+            push_quad(&mut self.vertices, layer, uv, array.size, &global);
+        }
+        if self.vertices.is_empty() {
+            return;
+        }
+        if let Err(err) = self.buffer.write(&mut factory, encoder, &self.vertices) {
+            error!("Failed to write the sprite array vertices: {}", err);
+            return;
+        }
+
+        if let Some(buffer) = self.buffer.raw() {
+            effect.data.vertex_bufs.push(buffer.clone());
+        }
+        set_vertex_args(effect, encoder, camera, &GlobalTransform::default());
+        add_texture(effect, texture);
+        effect.draw(&self.buffer.slice(), encoder);
+        effect.clear();
+    }
+}
+
+/// Push the two triangles of a sprite centered on the entity, in world space.
+fn push_quad(
+    vertices: &mut Vec<PosTexLayer>,
+    layer: usize,
+    uv: &Sprite,
+    (width, height): (u32, u32),
+    global: &GlobalTransform,
+) {
+    let half_width = (uv.right - uv.left).abs() * width as f32 / 2.;
+    let half_height = (uv.bottom - uv.top).abs() * height as f32 / 2.;
+    // The bottom of the quad samples the top of the sprite, as with a `Material` offset.
+    let corner = |x: f32, y: f32, u: f32, v: f32| {
+        let position = global.0 * Vector4::new(x, y, 0., 1.);
+        PosTexLayer {
+            position: [position.x, position.y, position.z],
+            tex_coord: [u, v],
+            tex_layer: [layer as f32],
+        }
+    };
+    let bottom_left = corner(-half_width, -half_height, uv.left, uv.top);
+    let bottom_right = corner(half_width, -half_height, uv.right, uv.top);
+    let top_left = corner(-half_width, half_height, uv.left, uv.bottom);
+    let top_right = corner(half_width, half_height, uv.right, uv.bottom);
+    vertices.extend_from_slice(&[
+        bottom_left,
+        bottom_right,
+        top_left,
+        top_right,
+        top_left,
+        bottom_right,
+    ]);
+}
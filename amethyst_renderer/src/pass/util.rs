@@ -1,9 +1,10 @@
 use std::mem;
 
 use amethyst_assets::AssetStorage;
-use amethyst_core::GlobalTransform;
-use amethyst_core::cgmath::{Matrix4, One, SquareMatrix};
-use amethyst_core::specs::{Fetch, Join, ReadStorage};
+use amethyst_core::{Disabled, GlobalTransform};
+use amethyst_core::cgmath::{Matrix4, One, SquareMatrix, Vector3, Vector4};
+use amethyst_core::specs::{Entities, Fetch, Join, ReadStorage};
+use hibitset::BitSet;
 
 use cam::{ActiveCamera, Camera};
 use mesh::Mesh;
@@ -11,9 +12,11 @@ use mtl::{Material, MaterialDefaults, TextureOffset};
 use pass::set_skinning_buffers;
 use pipe::{Effect, EffectBuilder};
 use skinning::JointTransforms;
+use sprite::{RenderScale, SpriteCrop};
 use tex::Texture;
-use types::Encoder;
+use types::{Encoder, Factory};
 use vertex::Attributes;
+use visibility::RenderLayers;
 
 pub(crate) enum TextureType {
     Albedo,
@@ -87,12 +90,16 @@ pub(crate) fn setup_textures(builder: &mut EffectBuilder, types: &[TextureType])
 pub(crate) fn add_textures(
     effect: &mut Effect,
     encoder: &mut Encoder,
+    factory: &mut Factory,
     storage: &AssetStorage<Texture>,
     material: &Material,
     default: &Material,
     types: &[TextureType],
 ) {
     use self::TextureType::*;
+    let sampler = material
+        .sampler
+        .map(|sampler| effect.sampler(factory, sampler.info()));
     for ty in types {
         let texture = match *ty {
             Albedo => storage
@@ -117,7 +124,12 @@ pub(crate) fn add_textures(
                 .get(&material.caveat)
                 .or_else(|| storage.get(&default.caveat)),
         };
-        add_texture(effect, texture.unwrap());
+        let texture = texture.unwrap();
+        effect.data.textures.push(texture.view().clone());
+        effect
+            .data
+            .samplers
+            .push(sampler.clone().unwrap_or_else(|| texture.sampler().clone()));
     }
     set_texture_offsets(effect, encoder, material, types);
 }
@@ -126,11 +138,9 @@ pub(crate) fn setup_texture_offsets(builder: &mut EffectBuilder, types: &[Textur
     use self::TextureType::*;
     for ty in types {
         match *ty {
-            Albedo => builder.with_raw_constant_buffer(
-                "AlbedoOffset",
-                mem::size_of::<TextureOffsetPod>(),
-                1,
-            ),
+            Albedo => builder
+                .with_raw_constant_buffer("AlbedoOffset", mem::size_of::<TextureOffsetPod>(), 1)
+                .with_raw_constant_buffer("AlbedoColor", mem::size_of::<[f32; 4]>(), 1),
             Emission => builder.with_raw_constant_buffer(
                 "EmissionOffset",
                 mem::size_of::<TextureOffsetPod>(),
@@ -174,11 +184,14 @@ pub(crate) fn set_texture_offsets(
     use self::TextureType::*;
     for ty in types {
         match *ty {
-            Albedo => effect.update_constant_buffer(
-                "AlbedoOffset",
-                &TextureOffsetPod::from_offset(&material.albedo_offset),
-                encoder,
-            ),
+            Albedo => {
+                effect.update_constant_buffer(
+                    "AlbedoOffset",
+                    &TextureOffsetPod::from_offset(&material.albedo_offset),
+                    encoder,
+                );
+                effect.update_constant_buffer("AlbedoColor", &material.albedo_color, encoder);
+            }
             Emission => effect.update_constant_buffer(
                 "EmissionOffset",
                 &TextureOffsetPod::from_offset(&material.emission_offset),
@@ -223,24 +236,61 @@ pub(crate) fn set_vertex_args(
     camera: Option<(&Camera, &GlobalTransform)>,
     global: &GlobalTransform,
 ) {
+    let (scale_x, scale_y) = effect.viewport_scale();
+    let viewport = Matrix4::from_nonuniform_scale(scale_x, scale_y, 1.0);
+    let target_size = effect.target_size();
     let vertex_args = camera
         .as_ref()
-        .map(|&(ref cam, ref transform)| VertexArgs {
-            proj: cam.proj.into(),
-            view: transform.0.invert().unwrap().into(),
-            model: *global.as_ref(),
+        .map(|&(ref cam, ref transform)| {
+            let view = transform.0.invert().unwrap();
+            let mut proj = viewport * cam.proj;
+            if cam.pixel_grid {
+                proj = pixel_grid_offset(proj * view * global.0, target_size) * proj;
+            }
+            VertexArgs {
+                proj: proj.into(),
+                view: view.into(),
+                model: *global.as_ref(),
+            }
         })
         .unwrap_or_else(|| VertexArgs {
-            proj: Matrix4::one().into(),
+            proj: viewport.into(),
             view: Matrix4::one().into(),
             model: *global.as_ref(),
         });
     effect.update_constant_buffer("VertexArgs", &vertex_args, encoder);
 }
 
+/// Translation in normalized device coordinates moving the origin of `to_clip` down and left to
+/// the closest pixel of a target of size `target_size`.
+fn pixel_grid_offset(to_clip: Matrix4<f32>, (width, height): (u32, u32)) -> Matrix4<f32> {
+    let origin = to_clip * Vector4::new(0.0, 0.0, 0.0, 1.0);
+    if origin.w == 0.0 {
+        return Matrix4::one();
+    }
+    let (width, height) = (width as f32, height as f32);
+    let x = (origin.x / origin.w + 1.0) * 0.5 * width;
+    let y = (origin.y / origin.w + 1.0) * 0.5 * height;
+    Matrix4::from_translation(Vector3::new(
+        (x.floor() - x) * 2.0 / width,
+        (y.floor() - y) * 2.0 / height,
+        0.0,
+    ))
+}
+
+pub(crate) fn setup_crop(builder: &mut EffectBuilder) {
+    builder.with_raw_constant_buffer("CropArgs", mem::size_of::<[f32; 4]>(), 1);
+}
+
+pub(crate) fn set_crop(effect: &mut Effect, encoder: &mut Encoder, crop: Option<&SpriteCrop>) {
+    let bounds = crop.map_or([0., 0., 1., 1.], SpriteCrop::bounds);
+    effect.update_constant_buffer("CropArgs", &bounds, encoder);
+}
+
 pub(crate) fn draw_mesh(
     encoder: &mut Encoder,
     effect: &mut Effect,
+    factory: &mut Factory,
     skinning: bool,
     mesh: Option<&Mesh>,
     joint: Option<&JointTransforms>,
@@ -278,6 +328,7 @@ pub(crate) fn draw_mesh(
     add_textures(
         effect,
         encoder,
+        factory,
         &tex_storage,
         material.unwrap(),
         &material_defaults.0,
@@ -288,6 +339,14 @@ pub(crate) fn draw_mesh(
     effect.clear();
 }
 
+/// The transform an entity is drawn with, scaled by its `RenderScale`.
+pub(crate) fn render_transform(
+    global: &GlobalTransform,
+    scale: Option<&RenderScale>,
+) -> GlobalTransform {
+    scale.map_or(*global, |scale| scale.apply(global))
+}
+
 pub(crate) fn get_camera<'a>(
     active: Option<Fetch<'a, ActiveCamera>>,
     camera: &'a ReadStorage<Camera>,
@@ -301,3 +360,29 @@ pub(crate) fn get_camera<'a>(
         })
         .or_else(|| (camera, global).join().next())
 }
+
+/// Entities which are not drawn by the camera, because they are `Disabled` or their
+/// `RenderLayers` don't intersect its own.
+pub(crate) fn excluded_entities(
+    camera: Option<(&Camera, &GlobalTransform)>,
+    entities: &Entities,
+    layers: &ReadStorage<RenderLayers>,
+    disabled: &ReadStorage<Disabled>,
+) -> BitSet {
+    let mut excluded = BitSet::new();
+    for (entity, _) in (&**entities, disabled).join() {
+        excluded.add(entity.id());
+    }
+    let camera_layers = camera.map_or(RenderLayers::all(), |(camera, _)| camera.layers);
+    for (entity, layers) in (&**entities, layers).join() {
+        if !camera_layers.intersects(*layers) {
+            excluded.add(entity.id());
+        }
+    }
+    if !camera_layers.intersects(RenderLayers::default()) {
+        for (entity, _) in (&**entities, !layers).join() {
+            excluded.add(entity.id());
+        }
+    }
+    excluded
+}
@@ -0,0 +1,33 @@
+//! Picking resource
+
+use amethyst_core::specs::Entity;
+
+/// Finds the entity drawn at a pixel, using the `DrawPickingIds` pass.
+///
+/// Reading the pixel back from the GPU takes a frame, so the entity at a pixel is known the frame
+/// after it was requested.
+#[derive(Clone, Debug, Default)]
+pub struct Picking {
+    pub(crate) request: Option<(u32, u32)>,
+    pub(crate) picked: Option<((u32, u32), Option<Entity>)>,
+}
+
+impl Picking {
+    /// Request the entity drawn at the pixel `(x, y)` of the picking target, measured from its top
+    /// left corner, and return the entity found at this pixel by the previous request.
+    ///
+    /// Calling this every frame with the cursor position gives the entity under the cursor, a
+    /// frame late. Returns `None` if no entity is drawn there, or if the pixel wasn't read yet.
+    pub fn pick_at(&mut self, x: u32, y: u32) -> Option<Entity> {
+        self.request = Some((x, y));
+        match self.picked {
+            Some((pixel, entity)) if pixel == (x, y) => entity,
+            _ => None,
+        }
+    }
+
+    /// Get the pixel read by the last request, and the entity drawn there.
+    pub fn last_pick(&self) -> Option<((u32, u32), Option<Entity>)> {
+        self.picked
+    }
+}
@@ -12,18 +12,22 @@ use gfx::preset::depth::{LESS_EQUAL_TEST, LESS_EQUAL_WRITE};
 use gfx::pso::buffer::{ElemStride, InstanceRate};
 use gfx::shade::{ProgramError, ToUniform};
 use gfx::shade::core::UniformValue;
-use gfx::state::{Blend, ColorMask, Comparison, Depth, MultiSample, Rasterizer, Stencil};
+use gfx::texture::SamplerInfo;
+use gfx::state::{Blend, ColorMask, Comparison, CullFace, Depth, MultiSample, RasterMethod,
+                 Rasterizer, Stencil};
 use gfx::traits::Pod;
 
 pub use self::pso::{Data, Init, Meta};
 
 use error::{Error, Result};
 use pipe::Target;
-use types::{Encoder, Factory, PipelineState, Resources, Slice};
+use types::{Encoder, Factory, PipelineState, RawTexture, Resources, Sampler, Slice};
 use vertex::Attributes;
 
 mod pso;
 
+static SRGB_HEADER: &[u8] = include_bytes!("../../pass/shaders/srgb.glsl");
+
 #[derive(Clone, Copy, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
 pub enum DepthMode {
     LessEqualTest,
@@ -38,30 +42,54 @@ pub(crate) enum ProgramSource<'a> {
 }
 
 impl<'a> ProgramSource<'a> {
-    pub fn compile(&self, fac: &mut Factory) -> Result<ShaderSet<Resources>> {
+    /// Compiles the shaders, defining `SRGB_OUTPUT` in the pixel shader if `srgb` is set.
+    pub fn compile(&self, fac: &mut Factory, srgb: bool) -> Result<ShaderSet<Resources>> {
         use gfx::Factory;
         use gfx::traits::FactoryExt;
 
         match *self {
-            ProgramSource::Simple(ref vs, ref ps) => fac.create_shader_set(vs, ps)
-                .map_err(|e| Error::ProgramCreation(e)),
+            ProgramSource::Simple(ref vs, ref ps) => {
+                fac.create_shader_set(vs, &pixel_source(ps, srgb))
+                    .map_err(|e| Error::ProgramCreation(e))
+            }
             ProgramSource::Geometry(ref vs, ref gs, ref ps) => {
                 let v = fac.create_shader_vertex(vs)
                     .map_err(|e| ProgramError::Vertex(e))?;
                 let g = fac.create_shader_geometry(gs)
                     .expect("Geometry shader creation failed");
-                let p = fac.create_shader_pixel(ps)
+                let p = fac.create_shader_pixel(&pixel_source(ps, srgb))
                     .map_err(|e| ProgramError::Pixel(e))?;
                 Ok(ShaderSet::Geometry(v, g, p))
             }
             ProgramSource::Tessellated(ref vs, ref hs, ref ds, ref ps) => {
-                fac.create_shader_set_tessellation(vs, hs, ds, ps)
+                fac.create_shader_set_tessellation(vs, hs, ds, &pixel_source(ps, srgb))
                     .map_err(|e| Error::ProgramCreation(e))
             }
         }
     }
 }
 
+/// Inserts the header defining `srgb_encode` after the `#version` line of the pixel shader,
+/// preceded by `#define SRGB_OUTPUT` if `srgb` is set so it encodes colors to sRGB.
+fn pixel_source(source: &[u8], srgb: bool) -> Vec<u8> {
+    let define: &[u8] = if srgb { b"#define SRGB_OUTPUT\n" } else { b"" };
+    let version = source.windows(8).position(|window| window == b"#version");
+    let insert_at = version
+        .and_then(|start| {
+            source[start..]
+                .iter()
+                .position(|&byte| byte == b'\n')
+                .map(|end| start + end + 1)
+        })
+        .unwrap_or(0);
+    let mut patched = Vec::with_capacity(source.len() + define.len() + SRGB_HEADER.len());
+    patched.extend_from_slice(&source[..insert_at]);
+    patched.extend_from_slice(define);
+    patched.extend_from_slice(SRGB_HEADER);
+    patched.extend_from_slice(&source[insert_at..]);
+    patched
+}
+
 #[derive(Derivative)]
 #[derivative(Clone, Debug, Eq, PartialEq)]
 pub struct Effect {
@@ -69,9 +97,64 @@ pub struct Effect {
     pub data: Data,
     const_bufs: HashMap<String, usize>,
     globals: HashMap<String, usize>,
+    wireframe_pso: Option<PipelineState<Meta>>,
+    wireframe: bool,
+    viewport: (u32, u32, u32, u32),
+    target_size: (u32, u32),
+    target_texture: Option<RawTexture>,
+    #[derivative(PartialEq = "ignore")]
+    samplers: Vec<(SamplerInfo, Sampler)>,
 }
 
 impl Effect {
+    /// Returns the factors scaling normalized device coordinates to the viewport of the target.
+    ///
+    /// Passes should scale their projection with it, so the scene fits the viewport when the
+    /// aspect ratio of the window is locked.
+    pub fn viewport_scale(&self) -> (f32, f32) {
+        let (_, _, w, h) = self.viewport;
+        (
+            w as f32 / self.target_size.0 as f32,
+            h as f32 / self.target_size.1 as f32,
+        )
+    }
+
+    /// Returns the offset in pixels of the viewport from the top left corner of the target.
+    pub fn viewport_offset(&self) -> (f32, f32) {
+        let (x, y, _, h) = self.viewport;
+        (x as f32, self.target_size.1.saturating_sub(y + h) as f32)
+    }
+
+    /// Returns the size in pixels of the target.
+    pub fn target_size(&self) -> (u32, u32) {
+        self.target_size
+    }
+
+    /// Returns the texture of the first color buffer of the target, if it can be copied from.
+    pub fn target_texture(&self) -> Option<&RawTexture> {
+        self.target_texture.as_ref()
+    }
+
+    /// Returns a sampler created with the given info, creating it on first use.
+    pub(crate) fn sampler(&mut self, factory: &mut Factory, info: SamplerInfo) -> Sampler {
+        use gfx::Factory;
+
+        if let Some(&(_, ref sampler)) = self.samplers.iter().find(|s| s.0 == info) {
+            return sampler.clone();
+        }
+        let sampler = factory.create_sampler(info);
+        self.samplers.push((info, sampler.clone()));
+        sampler
+    }
+
+    /// Restricts drawing to the viewport of the given target.
+    pub(crate) fn set_viewport(&mut self, target: &Target) {
+        self.data.scissor = target.scissor();
+        self.viewport = target.viewport();
+        self.target_size = target.size();
+        self.target_texture = target.color_buf(0).and_then(|cb| cb.texture.clone());
+    }
+
     pub fn update_global<N: AsRef<str>, T: ToUniform>(&mut self, name: N, data: T) {
         match self.globals.get(name.as_ref()) {
             Some(i) => self.data.globals[*i] = data.convert(),
@@ -131,8 +214,18 @@ impl Effect {
         self.data.vertex_bufs.clear();
     }
 
+    /// Draw with the wireframe pipeline state, if it was built with
+    /// `EffectBuilder::with_wireframe_variant`.
+    pub fn set_wireframe(&mut self, wireframe: bool) {
+        self.wireframe = wireframe;
+    }
+
     pub fn draw(&mut self, slice: &Slice, enc: &mut Encoder) {
-        enc.draw(&slice, &self.pso, &self.data);
+        let pso = match self.wireframe_pso {
+            Some(ref pso) if self.wireframe => pso,
+            _ => &self.pso,
+        };
+        enc.draw(&slice, pso, &self.data);
     }
 }
 
@@ -175,6 +268,7 @@ pub struct EffectBuilder<'a> {
     prog: ProgramSource<'a>,
     rast: Rasterizer,
     const_bufs: Vec<BufferInfo>,
+    wireframe_variant: bool,
 }
 
 impl<'a> EffectBuilder<'a> {
@@ -191,11 +285,15 @@ impl<'a> EffectBuilder<'a> {
         EffectBuilder {
             factory: fac,
             out: out,
-            init: Init::default(),
+            init: Init {
+                scissor: out.scissor().is_some(),
+                ..Init::default()
+            },
             prim: Primitive::TriangleList,
             rast,
             prog: src,
             const_bufs: Vec::new(),
+            wireframe_variant: false,
         }
     }
 
@@ -231,6 +329,14 @@ impl<'a> EffectBuilder<'a> {
         self
     }
 
+    /// Also build a pipeline state drawing triangles as lines.
+    ///
+    /// Use `Effect::set_wireframe` to switch between both at runtime.
+    pub fn with_wireframe_variant(&mut self) -> &mut Self {
+        self.wireframe_variant = true;
+        self
+    }
+
     /// Sets the output target of the PSO.
     ///
     /// If the target contains a depth buffer, its mode will be set by `depth`.
@@ -317,9 +423,20 @@ impl<'a> EffectBuilder<'a> {
         debug!("Building effect");
         debug!("Compiling shaders");
         let ref mut fac = self.factory;
-        let prog = self.prog.compile(fac)?;
+        let prog = self.prog.compile(fac, self.out.srgb())?;
         debug!("Creating pipeline state");
         let pso = fac.create_pipeline_state(&prog, self.prim, self.rast, self.init.clone())?;
+        let wireframe_pso = if self.wireframe_variant {
+            debug!("Creating wireframe pipeline state");
+            let rast = Rasterizer {
+                method: RasterMethod::Line(1),
+                cull_face: CullFace::Nothing,
+                ..self.rast
+            };
+            Some(fac.create_pipeline_state(&prog, self.prim, rast, self.init.clone())?)
+        } else {
+            None
+        };
 
         let mut data = Data::default();
 
@@ -368,11 +485,19 @@ impl<'a> EffectBuilder<'a> {
             .map(|db| (db.as_output.clone(), (0, 0)));
 
         debug!("Finished building effect");
-        Ok(Effect {
+        let mut effect = Effect {
             pso,
             data,
             const_bufs,
             globals,
-        })
+            wireframe_pso,
+            wireframe: false,
+            viewport: (0, 0, 1, 1),
+            target_size: (1, 1),
+            target_texture: None,
+            samplers: Vec::new(),
+        };
+        effect.set_viewport(self.out);
+        Ok(effect)
     }
 }
@@ -4,6 +4,7 @@ use gfx::pso::buffer::{RawConstantBuffer, RawGlobal, RawVertexBuffer};
 use gfx::pso::resource::{RawShaderResource, Sampler};
 use gfx::pso::target;
 use gfx::shade::core::{BaseType, ContainerType, OutputVar, ProgramInfo};
+use gfx_core::target::Rect;
 
 use types::{ColorFormat, DepthFormat, Resources};
 
@@ -38,6 +39,7 @@ pub struct Init<'d> {
     pub samplers: Vec<<Sampler as DataLink<'d>>::Init>,
     pub textures: Vec<<RawShaderResource as DataLink<'d>>::Init>,
     pub vertex_bufs: Vec<<RawVertexBuffer as DataLink<'d>>::Init>,
+    pub scissor: bool,
 }
 
 impl<'d> PipelineInit for Init<'d> {
@@ -45,6 +47,7 @@ impl<'d> PipelineInit for Init<'d> {
 
     fn link_to<'r>(&self, desc: &mut Descriptor, info: &'r ProgramInfo) -> InitResult<'r, Meta> {
         let mut meta = Meta::default();
+        desc.scissor = self.scissor;
 
         for cbuf in &self.const_bufs {
             let mut meta_cbuf = <RawConstantBuffer as DataLink<'d>>::new();
@@ -126,7 +129,7 @@ impl<'d> PipelineInit for Init<'d> {
         if let Some(depth) = self.out_depth {
             let mut meta_depth = <DepthStencilTarget as DataLink<'d>>::new();
             if let Some(d) = meta_depth.link_depth_stencil(&depth) {
-                desc.scissor = meta_depth.link_scissor();
+                desc.scissor = self.scissor || meta_depth.link_scissor();
                 desc.depth_stencil = Some(d);
             }
             meta.out_depth = Some(meta_depth);
@@ -184,6 +187,7 @@ pub struct Data {
     pub samplers: Vec<<Sampler as DataBind<Resources>>::Data>,
     pub textures: Vec<<RawShaderResource as DataBind<Resources>>::Data>,
     pub vertex_bufs: Vec<<RawVertexBuffer as DataBind<Resources>>::Data>,
+    pub scissor: Option<Rect>,
 }
 
 impl PipelineData<Resources> for Data {
@@ -229,5 +233,9 @@ impl PipelineData<Resources> for Data {
         for (meta_vbuf, vbuf) in vertex_bufs {
             meta_vbuf.bind_to(out, &vbuf, mgr, acc);
         }
+
+        if let Some(scissor) = self.scissor {
+            out.scissor = scissor;
+        }
     }
 }
@@ -20,11 +20,15 @@ pub use self::effect::{Data, DepthMode, Effect, EffectBuilder, Init, Meta, NewEf
 pub use self::pipe::{Pipeline, PipelineBuild, PipelineBuilder, PipelineData, PolyPipeline,
                      PolyStages};
 pub use self::stage::{PolyStage, Stage, StageBuilder};
+pub use self::switch::{ActivePipeline, PipelineSwitch, PipelineSwitchBuilder, SwitchData};
 pub use self::target::{ColorBuffer, DepthBuffer, Target, TargetBuilder, Targets};
+pub(crate) use self::target::locked_area;
 
 pub mod pass;
 
 mod effect;
 mod stage;
 mod pipe;
+mod resolve;
+mod switch;
 mod target;
@@ -85,5 +85,7 @@ impl<P> CompiledPass<P> {
 
         // Distribute new depth buffer
         self.effect.data.out_depth = target.depth_buf().map(|db| (db.as_output.clone(), (0, 0)));
+
+        self.effect.set_viewport(target);
     }
 }
@@ -0,0 +1,94 @@
+//! Resolve of multisampled targets, see `TargetBuilder::with_multisampling`.
+
+use gfx::pso::buffer::ElemStride;
+
+use error::Result;
+use mesh::Mesh;
+use pipe::{Effect, NewEffect, Target};
+use types::{Encoder, Factory, RawShaderResourceView, RenderTargetView, Sampler};
+use vertex::{PosTex, VertexFormat};
+
+static VERT_SRC: &[u8] = include_bytes!("../pass/shaders/vertex/resolve.glsl");
+static FRAG_SRC: &[u8] = include_bytes!("../pass/shaders/fragment/resolve.glsl");
+
+/// Averages the samples of the multisampled color buffers of a target into its single-sample
+/// color buffers, which passes read.
+#[derive(Clone, Debug)]
+pub(crate) struct Resolve {
+    effect: Effect,
+    mesh: Mesh,
+    sampler: Sampler,
+    sources: Vec<RawShaderResourceView>,
+    outputs: Vec<RenderTargetView>,
+}
+
+impl Resolve {
+    /// Create the resolve of `target`, if it is multisampled.
+    pub fn new(fac: &mut Factory, target: &Target) -> Result<Option<Self>> {
+        use gfx::Factory;
+        use gfx::texture::{FilterMethod, SamplerInfo, WrapMode};
+
+        let multisampled = match target.multisampled() {
+            Some(multisampled) => multisampled,
+            None => return Ok(None),
+        };
+        let vertices = [(-1., -1.), (1., -1.), (1., 1.), (-1., -1.), (1., 1.), (-1., 1.)]
+            .iter()
+            .map(|&(x, y)| PosTex {
+                position: [x, y, 0.],
+                tex_coord: [0., 0.],
+            })
+            .collect::<Vec<_>>();
+        let mesh = Mesh::build(vertices).build(fac)?;
+        let sampler = fac.create_sampler(SamplerInfo::new(FilterMethod::Scale, WrapMode::Clamp));
+        let mut effect = NewEffect::new(fac, &multisampled.resolved, 0)
+            .simple(VERT_SRC, FRAG_SRC)
+            .with_raw_global("samples")
+            .with_raw_vertex_buffer(PosTex::ATTRIBUTES, PosTex::size() as ElemStride, 0)
+            .with_texture("source")
+            .with_output("color", None)
+            .build()?;
+        effect.update_global("samples", multisampled.samples as i32);
+
+        let mut resolve = Resolve {
+            effect,
+            mesh,
+            sampler,
+            sources: Vec::new(),
+            outputs: Vec::new(),
+        };
+        resolve.new_target(target);
+        Ok(Some(resolve))
+    }
+
+    /// Follow the buffers of the target, after it was rebuilt.
+    pub fn new_target(&mut self, target: &Target) {
+        if let Some(multisampled) = target.multisampled() {
+            self.sources = multisampled.sources.clone();
+            self.outputs = multisampled
+                .resolved
+                .color_bufs()
+                .iter()
+                .map(|buf| buf.as_output.clone())
+                .collect();
+            self.effect.set_viewport(&multisampled.resolved);
+        }
+    }
+
+    /// Resolve each color buffer of the target.
+    pub fn apply(&mut self, encoder: &mut Encoder) {
+        let vbuf = match self.mesh.buffer(PosTex::ATTRIBUTES) {
+            Some(vbuf) => vbuf.clone(),
+            None => return,
+        };
+        for (source, output) in self.sources.iter().zip(&self.outputs) {
+            self.effect.data.out_colors.clear();
+            self.effect.data.out_colors.push(output.clone());
+            self.effect.data.vertex_bufs.push(vbuf.clone());
+            self.effect.data.textures.push(source.clone());
+            self.effect.data.samplers.push(self.sampler.clone());
+            self.effect.draw(self.mesh.slice(), encoder);
+            self.effect.clear();
+        }
+    }
+}
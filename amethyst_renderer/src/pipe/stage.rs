@@ -7,6 +7,7 @@ use error::{Error, Result};
 use fnv::FnvHashMap as HashMap;
 use pipe::{Target, Targets};
 use pipe::pass::{CompiledPass, Pass, PassData};
+use pipe::resolve::Resolve;
 
 use types::{Encoder, Factory};
 
@@ -19,6 +20,7 @@ pub struct Stage<L> {
     passes: L,
     target_name: String,
     target: Target,
+    resolve: Option<Resolve>,
 }
 
 impl Stage<List<()>> {
@@ -166,6 +168,10 @@ where
             .map(|d| self.target.clear_depth_stencil(encoder, d));
 
         self.passes.apply(encoder, factory, data);
+
+        if let Some(ref mut resolve) = self.resolve {
+            resolve.apply(encoder);
+        }
     }
 
     fn new_targets(&mut self, new_targets: &HashMap<String, Target>) {
@@ -173,6 +179,9 @@ where
             Some(target) => {
                 self.target = target.clone();
                 self.passes.new_target(target);
+                if let Some(ref mut resolve) = self.resolve {
+                    resolve.new_target(target);
+                }
             }
             None => {
                 eprintln!("Target name {:?} not found!", self.target_name);
@@ -218,6 +227,21 @@ impl<Q> StageBuilder<Q> {
         self
     }
 
+    /// Clears only the color buffers of the stage's target, keeping its depth buffer.
+    pub fn clear_color_only<R: Into<[f32; 4]>>(mut self, color_val: R) -> Self {
+        self.clear_color = Some(color_val.into());
+        self.clear_depth = None;
+        self
+    }
+
+    /// Clears only the depth buffer of the stage's target, keeping the color drawn by the
+    /// previous stages, such as a UI stage drawn over the scene.
+    pub fn clear_depth_only(mut self, depth_val: f32) -> Self {
+        self.clear_color = None;
+        self.clear_depth = Some(depth_val);
+        self
+    }
+
     /// Sets whether the `PolyStage` is turned on by default.
     pub fn enabled(mut self, val: bool) -> Self {
         self.enabled = val;
@@ -241,6 +265,15 @@ impl<Q> StageBuilder<Q> {
             .cloned()
             .ok_or(Error::NoSuchTarget(self.target_name.clone()))?;
 
+        // Passes drawing to a multisampled target need multisampling, which the stage resolves
+        // once they are done so the following stages read a single sample per pixel.
+        let resolve = Resolve::new(fac, &out)?;
+        let multisampling = if out.samples() > 1 {
+            multisampling.max(out.samples() as u16)
+        } else {
+            multisampling
+        };
+
         let passes = self.passes
             .into_list()
             .fmap(CompilePass::new(fac, &out, multisampling))
@@ -253,6 +286,7 @@ impl<Q> StageBuilder<Q> {
             passes,
             target: out,
             target_name: self.target_name,
+            resolve,
         })
     }
 }
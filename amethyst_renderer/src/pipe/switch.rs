@@ -0,0 +1,159 @@
+//! Runtime switching between pipelines.
+
+use amethyst_core::shred::{ResourceId, Resources};
+use amethyst_core::specs::{Fetch, SystemData};
+use fnv::FnvHashMap as HashMap;
+
+use error::{Error, Result};
+use pipe::{PipelineBuild, PipelineData, PolyPipeline, Target};
+use types::{Encoder, Factory};
+
+/// Resource selecting, by name, which pipeline of a `PipelineSwitch` is drawn.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct ActivePipeline(pub String);
+
+impl ActivePipeline {
+    /// Creates a new `ActivePipeline` selecting the pipeline with the given name.
+    pub fn new<N: Into<String>>(name: N) -> Self {
+        ActivePipeline(name.into())
+    }
+}
+
+/// A pipeline drawing one of two pipelines every frame.
+///
+/// The first pipeline is drawn when the `ActivePipeline` resource matches its name, the second one
+/// otherwise. Switches can be nested in the second pipeline to choose between more than two
+/// pipelines.
+///
+/// Only the data of the drawn pipeline is fetched from the `World`.
+///
+/// The targets of both pipelines are rebuilt together when the window is resized, so targets of
+/// the same name must have the same layout in both, see `PipelineSwitchBuilder`.
+#[derive(Clone, Debug)]
+pub struct PipelineSwitch<A, B> {
+    name: String,
+    first: A,
+    second: B,
+    targets: HashMap<String, Target>,
+}
+
+impl PipelineSwitch<(), ()> {
+    /// Builds a new switch, drawing `first` when the `ActivePipeline` is `name` and `second`
+    /// otherwise.
+    pub fn build<N, A, B>(name: N, first: A, second: B) -> PipelineSwitchBuilder<A, B>
+    where
+        N: Into<String>,
+        A: PipelineBuild,
+        B: PipelineBuild,
+    {
+        PipelineSwitchBuilder {
+            name: name.into(),
+            first,
+            second,
+        }
+    }
+}
+
+/// Data of a `PipelineSwitch`, fetched lazily once the drawn pipeline is known.
+pub struct SwitchData<'a> {
+    res: &'a Resources,
+    id: usize,
+}
+
+impl<'a> SystemData<'a> for SwitchData<'a> {
+    fn fetch(res: &'a Resources, id: usize) -> Self {
+        SwitchData { res, id }
+    }
+
+    // The render system runs thread local, so it doesn't need to declare its accesses.
+    fn reads(_: usize) -> Vec<ResourceId> {
+        Vec::new()
+    }
+
+    fn writes(_: usize) -> Vec<ResourceId> {
+        Vec::new()
+    }
+}
+
+impl<'a, A, B> PipelineData<'a> for PipelineSwitch<A, B> {
+    type Data = SwitchData<'a>;
+}
+
+impl<A, B> PolyPipeline for PipelineSwitch<A, B>
+where
+    A: PolyPipeline,
+    B: PolyPipeline,
+{
+    fn apply<'a, 'b: 'a>(
+        &'a mut self,
+        encoder: &mut Encoder,
+        factory: Factory,
+        data: SwitchData<'b>,
+    ) {
+        let first = Option::<Fetch<ActivePipeline>>::fetch(data.res, data.id)
+            .map_or(false, |active| active.0 == self.name);
+        if first {
+            let data = <A as PipelineData<'b>>::Data::fetch(data.res, data.id);
+            self.first.apply(encoder, factory, data);
+        } else {
+            let data = <B as PipelineData<'b>>::Data::fetch(data.res, data.id);
+            self.second.apply(encoder, factory, data);
+        }
+    }
+
+    fn new_targets(&mut self, new_targets: HashMap<String, Target>) {
+        self.first.new_targets(new_targets.clone());
+        self.second.new_targets(new_targets.clone());
+        self.targets = new_targets;
+    }
+
+    fn targets(&self) -> &HashMap<String, Target> {
+        &self.targets
+    }
+}
+
+/// Constructs a new `PipelineSwitch`.
+///
+/// Building fails with `Error::ConflictingTargets` if both pipelines have a target with the same
+/// name but a different number of color buffers, depth buffer, multisampling or sRGB encoding.
+#[derive(Clone, Debug)]
+pub struct PipelineSwitchBuilder<A, B> {
+    name: String,
+    first: A,
+    second: B,
+}
+
+impl<A, B> PipelineBuild for PipelineSwitchBuilder<A, B>
+where
+    A: PipelineBuild,
+    B: PipelineBuild,
+{
+    type Pipeline = PipelineSwitch<A::Pipeline, B::Pipeline>;
+
+    fn build(self, fac: &mut Factory, out: &Target, multisampling: u16) -> Result<Self::Pipeline> {
+        let first = self.first.build(fac, out, multisampling)?;
+        let second = self.second.build(fac, out, multisampling)?;
+        let mut targets = first.targets().clone();
+        for (name, target) in second.targets() {
+            if let Some(existing) = targets.get(name) {
+                if !same_layout(existing, target) {
+                    return Err(Error::ConflictingTargets(name.clone()));
+                }
+            }
+            targets.insert(name.clone(), target.clone());
+        }
+        Ok(PipelineSwitch {
+            name: self.name,
+            first,
+            second,
+            targets,
+        })
+    }
+}
+
+/// Checks if the targets would be rebuilt the same way on resize.
+fn same_layout(a: &Target, b: &Target) -> bool {
+    a.color_bufs().len() == b.color_bufs().len()
+        && a.depth_buf().is_some() == b.depth_buf().is_some()
+        && a.samples() == b.samples() && a.srgb() == b.srgb()
+}
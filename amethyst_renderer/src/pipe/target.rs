@@ -1,9 +1,11 @@
 //! Render target used for storing 2D pixel representations of 3D scenes.
 
 use fnv::FnvHashMap as HashMap;
+use gfx_core::target::Rect;
 
 use error::Result;
-use types::{DepthStencilView, Encoder, Factory, RenderTargetView, ShaderResourceView, Window};
+use types::{DepthStencilView, Encoder, Factory, RawShaderResourceView, RawTexture,
+            RenderTargetView, ShaderResourceView, Window};
 
 /// Target color buffer.
 #[derive(Clone, Debug, PartialEq)]
@@ -12,6 +14,8 @@ pub struct ColorBuffer {
     pub as_input: Option<ShaderResourceView<[f32; 4]>>,
     /// Target view.
     pub as_output: RenderTargetView,
+    /// Texture of the buffer, which can be copied from. The window has none.
+    pub texture: Option<RawTexture>,
 }
 
 /// Target depth-stencil buffer.
@@ -23,6 +27,18 @@ pub struct DepthBuffer {
     pub as_output: DepthStencilView,
 }
 
+/// The multisampled buffers of a target, which its passes draw to, before they are resolved to
+/// the single-sample buffers read by other passes.
+#[derive(Clone, Debug, PartialEq)]
+pub(crate) struct Multisampled {
+    /// Shader resource views of the multisampled color buffers.
+    pub sources: Vec<RawShaderResourceView>,
+    /// Number of samples per pixel.
+    pub samples: u8,
+    /// The single-sample color buffers the samples are resolved to.
+    pub resolved: Box<Target>,
+}
+
 /// A hash map containing named render targets.
 pub type Targets = HashMap<String, Target>;
 
@@ -35,6 +51,9 @@ pub struct Target {
     color_bufs: Vec<ColorBuffer>,
     depth_buf: Option<DepthBuffer>,
     size: (u32, u32),
+    aspect_lock: Option<(u32, u32)>,
+    srgb: bool,
+    multisampled: Option<Multisampled>,
 }
 
 impl Target {
@@ -44,9 +63,42 @@ impl Target {
             color_bufs: vec![cb],
             depth_buf: Some(db),
             size: size,
+            aspect_lock: None,
+            srgb: false,
+            multisampled: None,
         }
     }
 
+    /// Restricts drawing to the largest centered area of the given aspect ratio.
+    pub(crate) fn set_aspect_lock(&mut self, aspect_lock: Option<(u32, u32)>) {
+        self.aspect_lock = aspect_lock;
+    }
+
+    /// Sets whether the passes drawing to the target encode their output to sRGB.
+    pub(crate) fn set_srgb(&mut self, srgb: bool) {
+        self.srgb = srgb;
+    }
+
+    /// Returns true if the passes drawing to the target encode their linear output to sRGB.
+    pub fn srgb(&self) -> bool {
+        self.srgb
+    }
+
+    /// Returns the number of samples per pixel of the render target, 1 if it isn't
+    /// multisampled.
+    ///
+    /// The color buffers of a multisampled target are drawn to with multisampling, then resolved
+    /// once their stage is done, so their `as_input` views and textures hold a single sample per
+    /// pixel like other targets.
+    pub fn samples(&self) -> u8 {
+        self.multisampled.as_ref().map(|ms| ms.samples).unwrap_or(1)
+    }
+
+    /// Returns the multisampled buffers of the render target, if it has some.
+    pub(crate) fn multisampled(&self) -> Option<&Multisampled> {
+        self.multisampled.as_ref()
+    }
+
     /// Creates a new TargetBuilder with the given name.
     pub fn named<N: Into<String>>(name: N) -> TargetBuilder {
         TargetBuilder::new(name)
@@ -74,6 +126,31 @@ impl Target {
         self.size
     }
 
+    /// Returns the area of the render target which is drawn to, measured in pixels from the
+    /// bottom left corner, as `(x, y, width, height)`.
+    ///
+    /// This is the whole target, unless its aspect ratio is locked with
+    /// `DisplayConfig::lock_aspect`.
+    pub fn viewport(&self) -> (u32, u32, u32, u32) {
+        match self.aspect_lock {
+            Some(aspect) => locked_area(self.size, aspect),
+            None => (0, 0, self.size.0, self.size.1),
+        }
+    }
+
+    /// Returns the rectangle drawing should be restricted to, if the aspect ratio is locked.
+    pub(crate) fn scissor(&self) -> Option<Rect> {
+        self.aspect_lock.map(|_| {
+            let (x, y, w, h) = self.viewport();
+            Rect {
+                x: x as u16,
+                y: y as u16,
+                w: w as u16,
+                h: h as u16,
+            }
+        })
+    }
+
     /// Returns the color buffer with index `i`.
     pub fn color_buf(&self, i: usize) -> Option<&ColorBuffer> {
         self.color_bufs.get(i)
@@ -103,6 +180,9 @@ impl Target {
     /// Creates the OpenGL backend.
     #[cfg(feature = "opengl")]
     pub fn resize_main_target(&mut self, window: &Window) {
+        if let Some(size) = window.get_inner_size() {
+            self.size = size;
+        }
         if let Some(depth_buf) = self.depth_buf.as_mut() {
             for ref mut color_buf in &mut self.color_bufs {
                 use gfx_window_glutin as win;
@@ -112,6 +192,22 @@ impl Target {
     }
 }
 
+/// Computes the largest centered area of `aspect` ratio fitting in `size`, as
+/// `(x, y, width, height)`.
+pub(crate) fn locked_area(size: (u32, u32), aspect: (u32, u32)) -> (u32, u32, u32, u32) {
+    let (w, h) = size;
+    let (aw, ah) = (aspect.0 as u64, aspect.1 as u64);
+    if w as u64 * ah > h as u64 * aw {
+        // Window is too wide, pillarbox.
+        let locked_w = (h as u64 * aw / ah) as u32;
+        ((w - locked_w) / 2, 0, locked_w, h)
+    } else {
+        // Window is too tall, letterbox.
+        let locked_h = (w as u64 * ah / aw) as u32;
+        (0, (h - locked_h) / 2, w, locked_h)
+    }
+}
+
 /// Builds new render targets.
 ///
 /// By default, it creates render targets with one color buffer and no
@@ -122,6 +218,10 @@ pub struct TargetBuilder {
     name: String,
     has_depth_buf: bool,
     num_color_bufs: usize,
+    #[serde(default)]
+    srgb: bool,
+    #[serde(default)]
+    samples: u8,
 }
 
 impl TargetBuilder {
@@ -132,6 +232,8 @@ impl TargetBuilder {
             name: name.into(),
             has_depth_buf: false,
             num_color_bufs: 1,
+            srgb: false,
+            samples: 1,
         }
     }
 
@@ -152,6 +254,26 @@ impl TargetBuilder {
         self
     }
 
+    /// Sets whether the passes drawing to the render target encode their output to sRGB.
+    ///
+    /// By default, render targets hold linear colors, to be read by other passes.
+    pub fn with_srgb(mut self, srgb: bool) -> Self {
+        self.srgb = srgb;
+        self
+    }
+
+    /// Sets the number of samples per pixel of the render target, to draw it with multisampling
+    /// anti-aliasing.
+    ///
+    /// Its color buffers are resolved once each stage drawing to the target is done, so they can
+    /// be read by the passes of the following stages. Its depth buffer can't be read.
+    ///
+    /// By default, render targets have a single sample per pixel.
+    pub fn with_multisampling(mut self, samples: u8) -> Self {
+        self.samples = samples;
+        self
+    }
+
     /// Specifies a custom target size.
     pub fn with_size(mut self, size: (u32, u32)) -> Self {
         self.custom_size = Some(size);
@@ -161,39 +283,137 @@ impl TargetBuilder {
     /// Builds and returns the new render target.
     pub(crate) fn build(self, fac: &mut Factory, size: (u32, u32)) -> Result<(String, Target)> {
         use gfx::Factory;
+        use gfx::memory::Typed;
 
         let size = self.custom_size.unwrap_or(size);
+        let (w, h) = (size.0 as u16, size.1 as u16);
 
         let color_bufs = (0..self.num_color_bufs)
             .into_iter()
             .map(|_| {
-                let (w, h) = (size.0 as u16, size.1 as u16);
-                let (_, res, rt) = fac.create_render_target(w, h)?;
+                let (tex, res, rt) = fac.create_render_target(w, h)?;
                 Ok(ColorBuffer {
                     as_input: Some(res),
                     as_output: rt,
+                    texture: Some(tex.raw().clone()),
                 })
             })
             .collect::<Result<_>>()?;
 
-        let depth_buf = if self.has_depth_buf {
-            let (w, h) = (size.0 as u16, size.1 as u16);
+        let depth_buf = if !self.has_depth_buf {
+            None
+        } else if self.samples > 1 {
+            Some(DepthBuffer {
+                as_input: None,
+                as_output: create_multisampled_depth(fac, (w, h), self.samples)?,
+            })
+        } else {
             let (_, res, dt) = fac.create_depth_stencil(w, h)?;
-            let depth = DepthBuffer {
+            Some(DepthBuffer {
                 as_input: Some(res),
                 as_output: dt,
-            };
-            Some(depth)
-        } else {
-            None
+            })
         };
 
-        let target = Target {
+        let mut target = Target {
             color_bufs: color_bufs,
             depth_buf: depth_buf,
             size: size,
+            aspect_lock: None,
+            srgb: self.srgb,
+            multisampled: None,
         };
 
+        if self.samples > 1 {
+            // Passes draw to the multisampled buffers, and read the resolved ones.
+            let mut sources = Vec::with_capacity(target.color_bufs.len());
+            let resolved = target.color_bufs.clone();
+            for buf in &mut target.color_bufs {
+                let (res, rt) = create_multisampled_color(fac, (w, h), self.samples)?;
+                sources.push(res);
+                buf.as_output = rt;
+            }
+            target.multisampled = Some(Multisampled {
+                sources,
+                samples: self.samples,
+                resolved: Box::new(Target {
+                    color_bufs: resolved,
+                    depth_buf: None,
+                    size: size,
+                    aspect_lock: None,
+                    srgb: false,
+                    multisampled: None,
+                }),
+            });
+        }
+
         Ok((self.name, target))
     }
 }
+
+/// Creates a multisampled color buffer, returning its shader resource and target views.
+fn create_multisampled_color(
+    fac: &mut Factory,
+    (w, h): (u16, u16),
+    samples: u8,
+) -> Result<(RawShaderResourceView, RenderTargetView)> {
+    use gfx::{CombinedError, Factory};
+    use gfx::format::{ChannelTyped, Swizzle};
+    use gfx::memory::{Bind, Typed, Usage};
+    use gfx::texture::{AaMode, Kind};
+    use types::{ChannelFormat, ColorFormat, SurfaceFormat};
+
+    let tex = fac.create_texture::<SurfaceFormat>(
+        Kind::D2(w, h, AaMode::Multi(samples)),
+        1,
+        Bind::RENDER_TARGET | Bind::SHADER_RESOURCE,
+        Usage::Data,
+        Some(ChannelFormat::get_channel_type()),
+    )?;
+    let res = fac.view_texture_as_shader_resource::<ColorFormat>(&tex, (0, 0), Swizzle::new())?;
+    let rt = fac.view_texture_as_render_target::<ColorFormat>(&tex, 0, None)
+        .map_err(CombinedError::from)?;
+    Ok((res.raw().clone(), rt))
+}
+
+/// Creates a multisampled depth-stencil buffer, which can't be read by shaders.
+fn create_multisampled_depth(
+    fac: &mut Factory,
+    (w, h): (u16, u16),
+    samples: u8,
+) -> Result<DepthStencilView> {
+    use gfx::{CombinedError, Factory};
+    use gfx::format::{ChannelTyped, Formatted};
+    use gfx::memory::{Bind, Usage};
+    use gfx::texture::{AaMode, Kind};
+    use types::DepthFormat;
+
+    type Surface = <DepthFormat as Formatted>::Surface;
+    type Channel = <DepthFormat as Formatted>::Channel;
+
+    let tex = fac.create_texture::<Surface>(
+        Kind::D2(w, h, AaMode::Multi(samples)),
+        1,
+        Bind::DEPTH_STENCIL,
+        Usage::Data,
+        Some(Channel::get_channel_type()),
+    )?;
+    let view = fac.view_texture_as_depth_stencil_trivial(&tex)
+        .map_err(CombinedError::from)?;
+    Ok(view)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::locked_area;
+
+    #[test]
+    fn locked_area_pillarbox() {
+        assert_eq!(locked_area((2000, 900), (16, 9)), (200, 0, 1600, 900));
+    }
+
+    #[test]
+    fn locked_area_letterbox() {
+        assert_eq!(locked_area((1600, 1000), (16, 9)), (0, 50, 1600, 900));
+    }
+}
@@ -7,6 +7,7 @@ use pipe::{ColorBuffer, DepthBuffer, PipelineBuild, PipelineData, PolyPipeline,
            TargetBuilder};
 use tex::{Texture, TextureBuilder};
 use types::{ColorFormat, DepthFormat, Device, Encoder, Factory, Window};
+use upscale::Upscale;
 use winit::{EventsLoop, Window as WinitWindow, WindowBuilder};
 
 /// Generic renderer.
@@ -21,6 +22,10 @@ pub struct Renderer {
     events: EventsLoop,
     multisampling: u16,
     cached_size: (u32, u32),
+    max_texture_size: u32,
+    srgb: bool,
+    /// Drawing of the offscreen main target on the window, with an internal resolution.
+    upscale: Option<Upscale>,
 }
 
 impl Renderer {
@@ -53,7 +58,29 @@ impl Renderer {
         D: AsRef<[T]>,
         T: Pod + Copy,
     {
-        tb.build(&mut self.factory)
+        use gfx::handle::ShaderResourceView;
+        use gfx::memory::Typed;
+
+        let generate_mipmaps = tb.generate_mipmaps;
+        let texture = tb.build(&mut self.factory)?;
+        if generate_mipmaps {
+            let view: ShaderResourceView<_, ColorFormat> = Typed::new(texture.view().clone());
+            self.encoder.generate_mipmap(&view);
+        }
+        Ok(texture)
+    }
+
+    /// Returns the maximum width and height of textures, measured in pixels (px).
+    ///
+    /// This is the limit of the GPU, lowered by `DisplayConfig::max_texture_size` if set.
+    pub fn max_texture_size(&self) -> u32 {
+        self.max_texture_size
+    }
+
+    /// Returns true if images are loaded as sRGB textures by default, as set by
+    /// `DisplayConfig::srgb`.
+    pub fn srgb(&self) -> bool {
+        self.srgb
     }
 
     /// Builds a new renderer pipeline.
@@ -82,6 +109,9 @@ impl Renderer {
         }
 
         pipe.apply(&mut self.encoder, self.factory.clone(), data);
+        if let Some(ref mut upscale) = self.upscale {
+            upscale.apply(&mut self.encoder);
+        }
         self.encoder.flush(&mut self.device);
         self.device.cleanup();
 
@@ -91,20 +121,38 @@ impl Renderer {
             .expect("OpenGL context has been lost");
     }
 
+    /// Retrieve an immutable borrow of the events loop
+    pub fn events(&self) -> &EventsLoop {
+        &self.events
+    }
+
     /// Retrieve a mutable borrow of the events loop
     pub fn events_mut(&mut self) -> &mut EventsLoop {
         &mut self.events
     }
 
     /// Resize the targets associated with this renderer and pipeline.
+    ///
+    /// With an internal resolution, the main target and the other targets keep its size.
     pub fn resize<P: PolyPipeline>(&mut self, pipe: &mut P, new_size: (u32, u32)) {
-        self.main_target.resize_main_target(&self.window);
+        let new_size = match self.upscale {
+            Some(ref mut upscale) => {
+                upscale.resize(&self.window);
+                self.main_target.size()
+            }
+            None => {
+                self.main_target.resize_main_target(&self.window);
+                new_size
+            }
+        };
         let mut targets = HashMap::default();
         targets.insert("".to_string(), self.main_target.clone());
         for (key, value) in pipe.targets().iter().filter(|&(k, _)| !k.is_empty()) {
             let (key, target) = TargetBuilder::new(key.clone())
                 .with_num_color_bufs(value.color_bufs().len())
                 .with_depth_buf(value.depth_buf().is_some())
+                .with_multisampling(value.samples())
+                .with_srgb(value.srgb())
                 .build(&mut self.factory, new_size)
                 .unwrap();
             targets.insert(key, target);
@@ -196,13 +244,38 @@ impl RendererBuilder {
 
     /// Consumes the builder and creates the new `Renderer`.
     pub fn build(self) -> Result<Renderer> {
-        let Backend(device, mut factory, main_target, window) =
+        let Backend(device, mut factory, mut main_target, window) =
             init_backend(self.winit_builder.clone(), &self.events, &self.config)?;
+        // With an internal resolution, the pipeline draws to an offscreen target which is then
+        // upscaled to the window.
+        let (main_target, upscale) = match self.config.internal_resolution {
+            Some(size) => {
+                let (_, offscreen) = TargetBuilder::new("")
+                    .with_depth_buf(true)
+                    .with_srgb(self.config.srgb)
+                    .with_size(size)
+                    .build(&mut factory, size)?;
+                let upscale = Upscale::new(&mut factory, main_target, &offscreen)?;
+                (offscreen, Some(upscale))
+            }
+            None => {
+                main_target.set_aspect_lock(self.config.lock_aspect);
+                main_target.set_srgb(self.config.srgb);
+                (main_target, None)
+            }
+        };
 
         let cached_size = window
             .get_inner_size()
             .expect("Unable to fetch window size, as the window went away!");
         let encoder = factory.create_command_buffer().into();
+        let max_texture_size = {
+            use gfx::Device;
+            let supported = device.get_capabilities().max_texture_size as u32;
+            self.config
+                .max_texture_size
+                .map_or(supported, |max| max.min(supported))
+        };
         Ok(Renderer {
             device,
             encoder,
@@ -212,6 +285,9 @@ impl RendererBuilder {
             events: self.events,
             multisampling: self.config.multisampling,
             cached_size,
+            max_texture_size,
+            srgb: self.config.srgb,
+            upscale,
         })
     }
 }
@@ -235,6 +311,7 @@ fn init_backend(wb: WindowBuilder, el: &EventsLoop, config: &DisplayConfig) -> R
         ColorBuffer {
             as_input: None,
             as_output: color,
+            texture: None,
         },
         DepthBuffer {
             as_input: None,
@@ -260,6 +337,7 @@ fn init_backend(wb: WindowBuilder, el: &EventsLoop, config: &DisplayConfig) -> R
         ColorBuffer {
             as_input: None,
             as_output: color,
+            texture: None,
         },
         DepthBuffer {
             as_input: None,
@@ -289,6 +367,7 @@ fn init_backend(wb: WindowBuilder, el: &EventsLoop, config: &DisplayConfig) -> R
         ColorBuffer {
             as_input: None,
             as_output: color,
+            texture: None,
         },
         DepthBuffer {
             as_input: None,
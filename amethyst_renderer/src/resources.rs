@@ -1,9 +1,13 @@
 //! `amethyst` rendering ecs resources
 
 use smallvec::SmallVec;
-use winit::Window;
+use winit::{EventsLoop, MonitorId, Window};
 
+use cam::Camera;
 use color::Rgba;
+use error::{Error, Result};
+use pipe::locked_area;
+use upscale::scaled_area;
 
 /// The ambient color of a scene
 #[derive(Clone, Debug, Default)]
@@ -45,6 +49,11 @@ impl WindowMessages {
 }
 
 /// World resource that stores screen dimensions.
+///
+/// When the aspect ratio is locked with `DisplayConfig::lock_aspect`, the width and height are
+/// the ones of the play area, the centered part of the window which is drawn to. With
+/// `DisplayConfig::internal_resolution`, they are the internal resolution, and the play area is
+/// the part of the window it is upscaled to.
 #[derive(Debug)]
 pub struct ScreenDimensions {
     /// Screen width in pixels (px).
@@ -53,21 +62,58 @@ pub struct ScreenDimensions {
     h: f32,
     /// Width divided by height.
     aspect_ratio: f32,
+    /// Window width and height in pixels (px).
+    window: (u32, u32),
+    /// Offset of the play area from the top left corner of the window in pixels (px).
+    offset: (f32, f32),
+    /// Pixels of the play area per pixel of the window, on each axis.
+    scale: (f32, f32),
+    aspect_lock: Option<(u32, u32)>,
+    internal_resolution: Option<(u32, u32)>,
     pub(crate) dirty: bool,
 }
 
 impl ScreenDimensions {
     /// Creates a new screen dimensions object with the given width and height.
     pub fn new(w: u32, h: u32) -> ScreenDimensions {
-        ScreenDimensions {
-            w: w as f32,
-            h: h as f32,
-            aspect_ratio: w as f32 / h as f32,
+        ScreenDimensions::with_aspect_lock(w, h, None)
+    }
+
+    /// Creates a new screen dimensions object for a window with the given width and height,
+    /// whose play area is locked to the given aspect ratio.
+    pub fn with_aspect_lock(w: u32, h: u32, aspect_lock: Option<(u32, u32)>) -> ScreenDimensions {
+        ScreenDimensions::build(w, h, aspect_lock, None)
+    }
+
+    /// Creates a new screen dimensions object for a window with the given width and height,
+    /// which the given internal resolution is upscaled to.
+    pub fn with_internal_resolution(w: u32, h: u32, internal: (u32, u32)) -> ScreenDimensions {
+        ScreenDimensions::build(w, h, None, Some(internal))
+    }
+
+    fn build(
+        w: u32,
+        h: u32,
+        aspect_lock: Option<(u32, u32)>,
+        internal_resolution: Option<(u32, u32)>,
+    ) -> ScreenDimensions {
+        let mut dimensions = ScreenDimensions {
+            w: 0.0,
+            h: 0.0,
+            aspect_ratio: 0.0,
+            window: (0, 0),
+            offset: (0.0, 0.0),
+            scale: (1.0, 1.0),
+            aspect_lock,
+            internal_resolution,
             dirty: false,
-        }
+        };
+        dimensions.update(w, h);
+        dimensions.dirty = false;
+        dimensions
     }
 
-    /// Returns the current width of the window.
+    /// Returns the current width of the play area.
     ///
     /// This is returned as a float for user convenience, as this is typically used with other
     /// float values.  This will only ever be a non-negative integer though.
@@ -75,7 +121,7 @@ impl ScreenDimensions {
         self.w
     }
 
-    /// Returns the current height of the window.
+    /// Returns the current height of the play area.
     ///
     /// This is returned as a float for user convenience, as this is typically used with other
     /// float values.  This will only ever be a non-negative integer though.
@@ -83,21 +129,295 @@ impl ScreenDimensions {
         self.h
     }
 
-    /// Returns the current aspect ratio of the window.
+    /// Returns the current aspect ratio of the play area.
     pub fn aspect_ratio(&self) -> f32 {
         self.aspect_ratio
     }
 
-    /// Updates the width and height of the screen and recomputes the aspect
+    /// Returns the size of the whole window, including the borders around a locked play area.
+    pub fn window_size(&self) -> (u32, u32) {
+        self.window
+    }
+
+    /// Returns the offset of the play area from the top left corner of the window.
+    ///
+    /// Use `to_play_area` to get the position of the mouse in the play area.
+    pub fn play_area_offset(&self) -> (f32, f32) {
+        self.offset
+    }
+
+    /// Converts a position in the window, such as the mouse position, to a position in the play
+    /// area, both measured in pixels (px) from their top left corner.
+    ///
+    /// This accounts for the borders around a locked play area and for the scaling of the
+    /// internal resolution.
+    pub fn to_play_area(&self, (x, y): (f32, f32)) -> (f32, f32) {
+        (
+            (x - self.offset.0) * self.scale.0,
+            (y - self.offset.1) * self.scale.1,
+        )
+    }
+
+    /// Updates the width and height of the window and recomputes the play area and aspect
     /// ratio.
     ///
     /// Only use this if you need to programmatically set the resolution of your game.
     /// This resource is updated automatically by the engine when a resize occurs so you don't need
     /// this unless you want to resize the game window.
     pub fn update(&mut self, w: u32, h: u32) {
-        self.w = w as f32;
-        self.h = h as f32;
-        self.aspect_ratio = w as f32 / h as f32;
+        let (x, y, area_w, area_h) = match (self.internal_resolution, self.aspect_lock) {
+            (Some(internal), _) => {
+                // The upscaled area is measured from the bottom left corner of the window.
+                let (x, bottom, area_w, area_h) = scaled_area((w, h), internal);
+                (x, h - bottom - area_h, area_w, area_h)
+            }
+            (None, Some(aspect)) => locked_area((w, h), aspect),
+            (None, None) => (0, 0, w, h),
+        };
+        let (play_w, play_h) = self.internal_resolution.unwrap_or((area_w, area_h));
+        self.window = (w, h);
+        self.offset = (x as f32, y as f32);
+        self.scale = (
+            play_w as f32 / area_w.max(1) as f32,
+            play_h as f32 / area_h.max(1) as f32,
+        );
+        self.w = play_w as f32;
+        self.h = play_h as f32;
+        self.aspect_ratio = play_w as f32 / play_h as f32;
         self.dirty = true;
     }
 }
+
+/// World resource that stores the title of the window.
+///
+/// Setting the title is cheap, the window itself is only updated when the title changes.
+#[derive(Clone, Debug, Default)]
+pub struct WindowTitle {
+    title: String,
+    pub(crate) dirty: bool,
+}
+
+impl WindowTitle {
+    /// Creates a new window title resource with the given title.
+    pub fn new<S: Into<String>>(title: S) -> WindowTitle {
+        WindowTitle {
+            title: title.into(),
+            dirty: false,
+        }
+    }
+
+    /// Returns the current title of the window.
+    pub fn title(&self) -> &str {
+        &self.title
+    }
+
+    /// Sets the title of the window.
+    ///
+    /// The window will only be updated next frame if the new title differs from the current one.
+    pub fn set<S: AsRef<str> + Into<String>>(&mut self, title: S) {
+        if self.title != title.as_ref() {
+            self.title = title.into();
+            self.dirty = true;
+        }
+    }
+}
+
+/// World resource telling whether the window has the input focus, updated by the `RenderSystem`
+/// from `WindowEvent::Focused`.
+///
+/// Systems can read it to pause the gameplay or the audio while the window is in the background.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct WindowFocus(pub bool);
+
+impl WindowFocus {
+    /// Returns true if the window has the focus.
+    pub fn is_focused(&self) -> bool {
+        self.0
+    }
+}
+
+impl Default for WindowFocus {
+    fn default() -> Self {
+        WindowFocus(true)
+    }
+}
+
+/// World resource that sets how the mesh passes rasterize triangles.
+///
+/// Can be changed at runtime, for example to inspect the geometry of meshes in wireframe.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum PolygonMode {
+    /// Triangles are filled, this is the default.
+    Fill,
+    /// Only the edges of the triangles are drawn.
+    Line,
+}
+
+impl Default for PolygonMode {
+    fn default() -> Self {
+        PolygonMode::Fill
+    }
+}
+
+/// World resource that stores the resolution presets of the `DisplayConfig`.
+///
+/// Presets can be applied at runtime, for example from an options menu.
+#[derive(Clone, Debug, Default)]
+pub struct ResolutionPresets {
+    presets: Vec<(u32, u32)>,
+    fullscreen: bool,
+    monitor_dimensions: Option<(u32, u32)>,
+}
+
+impl ResolutionPresets {
+    /// Creates a new resolution presets resource.
+    ///
+    /// When `fullscreen` is set, presets bigger than `monitor_dimensions`, the dimensions of the
+    /// monitor the window is on, can't be applied.
+    pub fn new(
+        presets: Vec<(u32, u32)>,
+        fullscreen: bool,
+        monitor_dimensions: Option<(u32, u32)>,
+    ) -> Self {
+        ResolutionPresets {
+            presets,
+            fullscreen,
+            monitor_dimensions,
+        }
+    }
+
+    /// Returns the available presets.
+    pub fn presets(&self) -> &[(u32, u32)] {
+        &self.presets
+    }
+
+    /// Checks whether the given resolution fits in the monitor of the window, when fullscreen.
+    ///
+    /// winit doesn't list the video modes of the monitors, so this doesn't check that the
+    /// monitor has a mode with this exact resolution.
+    pub fn fits_monitor(&self, (w, h): (u32, u32)) -> bool {
+        match self.monitor_dimensions {
+            Some((max_w, max_h)) if self.fullscreen => w <= max_w && h <= max_h,
+            _ => true,
+        }
+    }
+
+    /// Applies the preset at `index`, resizing the window next frame and updating the aspect
+    /// ratio of the given cameras.
+    pub fn apply<'a, I>(
+        &self,
+        index: usize,
+        dimensions: &mut ScreenDimensions,
+        cameras: I,
+    ) -> Result<()>
+    where
+        I: IntoIterator<Item = &'a mut Camera>,
+    {
+        let (w, h) = *self.presets.get(index).ok_or(Error::NoSuchPreset(index))?;
+        if !self.fits_monitor((w, h)) {
+            return Err(Error::UnsupportedResolution(w, h));
+        }
+        dimensions.update(w, h);
+        for camera in cameras {
+            camera.set_aspect_ratio(dimensions.aspect_ratio());
+        }
+        Ok(())
+    }
+}
+
+/// Information about a connected monitor.
+///
+/// winit's `MonitorId` doesn't expose the video modes of the monitor nor its refresh rate, so
+/// only its current resolution is known.
+#[derive(Clone, Debug, PartialEq)]
+pub struct MonitorInfo {
+    /// Name of the monitor, if known.
+    pub name: Option<String>,
+    /// Current resolution of the monitor, in pixels.
+    pub dimensions: (u32, u32),
+    /// Position of the top left corner of the monitor on the desktop, in pixels.
+    pub position: (i32, i32),
+    /// Ratio between the physical pixels and the logical pixels of the monitor.
+    pub hidpi_factor: f32,
+}
+
+impl<'a> From<&'a MonitorId> for MonitorInfo {
+    fn from(monitor: &'a MonitorId) -> Self {
+        MonitorInfo {
+            name: monitor.get_name(),
+            dimensions: monitor.get_dimensions(),
+            position: monitor.get_position(),
+            hidpi_factor: monitor.get_hidpi_factor(),
+        }
+    }
+}
+
+/// World resource listing the monitors connected at startup.
+///
+/// The supported video modes and refresh rates of the monitors can't be listed: winit's
+/// `MonitorId` only gives their current resolution. Fullscreen options can be built from
+/// `Monitors::resolutions` and the presets fitting in them instead.
+#[derive(Clone, Debug, Default)]
+pub struct Monitors {
+    monitors: Vec<MonitorInfo>,
+    primary: Option<usize>,
+    current: Option<usize>,
+}
+
+impl Monitors {
+    /// Lists the monitors available to the events loop, and the one the window is on.
+    pub fn new(events: &EventsLoop, window: &Window) -> Self {
+        let monitors: Vec<MonitorInfo> = events
+            .get_available_monitors()
+            .map(|monitor| MonitorInfo::from(&monitor))
+            .collect();
+        let primary = MonitorInfo::from(&events.get_primary_monitor());
+        let current = MonitorInfo::from(&window.get_current_monitor());
+        Monitors {
+            primary: monitors.iter().position(|monitor| *monitor == primary),
+            current: monitors.iter().position(|monitor| *monitor == current),
+            monitors,
+        }
+    }
+
+    /// Returns all the monitors.
+    pub fn monitors(&self) -> &[MonitorInfo] {
+        &self.monitors
+    }
+
+    /// Returns the primary monitor of the system.
+    pub fn primary(&self) -> Option<&MonitorInfo> {
+        self.primary.map(|index| &self.monitors[index])
+    }
+
+    /// Returns the monitor the window was on at startup.
+    pub fn current(&self) -> Option<&MonitorInfo> {
+        self.current.map(|index| &self.monitors[index])
+    }
+
+    /// Returns the distinct resolutions of the monitors, from the smallest to the biggest.
+    pub fn resolutions(&self) -> Vec<(u32, u32)> {
+        let mut resolutions: Vec<(u32, u32)> = self.monitors
+            .iter()
+            .map(|monitor| monitor.dimensions)
+            .collect();
+        resolutions.sort();
+        resolutions.dedup();
+        resolutions
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ScreenDimensions;
+
+    #[test]
+    fn internal_resolution_maps_window_positions() {
+        let dimensions = ScreenDimensions::with_internal_resolution(1000, 600, (320, 180));
+        assert_eq!((dimensions.width(), dimensions.height()), (320., 180.));
+        assert_eq!(dimensions.window_size(), (1000, 600));
+        assert_eq!(dimensions.play_area_offset(), (20., 30.));
+        assert_eq!(dimensions.to_play_area((20., 30.)), (0., 0.));
+        assert_eq!(dimensions.to_play_area((500., 300.)), (160., 90.));
+    }
+}
@@ -1,5 +1,12 @@
 use amethyst_assets::{Asset, Handle};
-use amethyst_core::specs::VecStorage;
+use amethyst_core::GlobalTransform;
+use amethyst_core::cgmath::{Matrix4, Vector2};
+use amethyst_core::specs::{Component, DenseVecStorage, VecStorage};
+use fnv::FnvHashMap;
+
+use formats::{TextureData, TextureMetadata};
+use mtl::{Material, MaterialDefaults, TextureOffset};
+use tex::TextureHandle;
 
 /// An asset handle to sprite sheet metadata.
 pub type SpriteSheetHandle = Handle<SpriteSheet>;
@@ -11,8 +18,48 @@ pub type SpriteSheetHandle = Handle<SpriteSheet>;
 pub struct SpriteSheet {
     /// Index of the texture for this sprite sheet.
     pub index: usize,
+    /// Texture of the sprite sheet, if the sheet was created with it.
+    ///
+    /// Texture animations still look the texture up by `index` in the `MaterialTextureSet`.
+    pub texture: Option<TextureHandle>,
     /// A list of sprites in this sprite sheet.
     pub sprites: Vec<Sprite>,
+    /// Indices of the sprites which have a name, by name.
+    pub names: FnvHashMap<String, usize>,
+}
+
+impl SpriteSheet {
+    /// Create a sprite sheet owning its texture.
+    pub fn with_texture(index: usize, texture: TextureHandle, sprites: Vec<Sprite>) -> Self {
+        SpriteSheet {
+            index,
+            texture: Some(texture),
+            sprites,
+            names: FnvHashMap::default(),
+        }
+    }
+
+    /// Get the index of the sprite with the given name.
+    pub fn index_of(&self, name: &str) -> Option<usize> {
+        self.names.get(name).cloned()
+    }
+
+    /// Create a material drawing the sprite at `sprite` with the texture of the sheet, the other
+    /// textures being the defaults.
+    ///
+    /// Returns `None` if the sheet has no texture or no sprite at `sprite`.
+    pub fn material(&self, sprite: usize, defaults: &MaterialDefaults) -> Option<Material> {
+        let texture = self.texture.as_ref()?;
+        let sprite = self.sprites.get(sprite)?;
+        Some(Material {
+            albedo: texture.clone(),
+            albedo_offset: TextureOffset {
+                u: (sprite.left, sprite.right),
+                v: (sprite.top, sprite.bottom),
+            },
+            ..defaults.0.clone()
+        })
+    }
 }
 
 impl Asset for SpriteSheet {
@@ -61,9 +108,337 @@ impl From<[f32; 4]> for Sprite {
     }
 }
 
+/// Side of a sprite a `SpriteCrop` keeps.
+#[derive(Clone, Copy, Debug, Deserialize, Eq, PartialEq, Serialize)]
+pub enum CropDirection {
+    /// Keep the left part, the sprite fills from left to right.
+    LeftToRight,
+    /// Keep the right part, the sprite fills from right to left.
+    RightToLeft,
+    /// Keep the bottom part, the sprite fills from bottom to top.
+    BottomToTop,
+    /// Keep the top part, the sprite fills from top to bottom.
+    TopToBottom,
+}
+
+/// Shows only a fraction of the sprite drawn by the entity, such as the filled part of a health
+/// bar.
+///
+/// Used by the `DrawFlat` passes, which don't draw the cropped part. The mesh texture
+/// coordinates are expected to go from 0 to 1, with the bottom left at (0, 0), as in sprite quads.
+#[derive(Clone, Debug, PartialEq)]
+pub struct SpriteCrop {
+    /// Fraction of the sprite shown, from 0 to 1.
+    pub fraction: f32,
+    /// Side of the sprite which is shown.
+    pub direction: CropDirection,
+}
+
+impl SpriteCrop {
+    /// Create a new crop
+    pub fn new(fraction: f32, direction: CropDirection) -> Self {
+        SpriteCrop {
+            fraction,
+            direction,
+        }
+    }
+
+    /// Texture coordinates of the mesh which are shown, as `[min_u, min_v, max_u, max_v]`.
+    pub(crate) fn bounds(&self) -> [f32; 4] {
+        let fraction = self.fraction.max(0.).min(1.);
+        match self.direction {
+            CropDirection::LeftToRight => [0., 0., fraction, 1.],
+            CropDirection::RightToLeft => [1. - fraction, 0., 1., 1.],
+            CropDirection::BottomToTop => [0., 0., 1., fraction],
+            CropDirection::TopToBottom => [0., 1. - fraction, 1., 1.],
+        }
+    }
+}
+
+impl Component for SpriteCrop {
+    type Storage = DenseVecStorage<Self>;
+}
+
+/// Scales the drawing of the entity on its x and y axes, without changing its `GlobalTransform`.
+///
+/// Used by the `DrawFlat` and `DrawSpriteArray` passes, so an icon can pulse while its
+/// `Collider` and children keep the size of its transform.
+#[derive(Clone, Debug, PartialEq)]
+pub struct RenderScale(pub Vector2<f32>);
+
+impl RenderScale {
+    /// Create a new render scale
+    pub fn new(x: f32, y: f32) -> Self {
+        RenderScale(Vector2::new(x, y))
+    }
+
+    /// Apply the scale to the transform the entity is drawn with.
+    pub(crate) fn apply(&self, global: &GlobalTransform) -> GlobalTransform {
+        GlobalTransform(global.0 * Matrix4::from_nonuniform_scale(self.0.x, self.0.y, 1.))
+    }
+}
+
+impl Default for RenderScale {
+    fn default() -> Self {
+        RenderScale::new(1., 1.)
+    }
+}
+
+impl Component for RenderScale {
+    type Storage = DenseVecStorage<Self>;
+}
+
+/// Packs RGBA8 images into a single atlas texture at runtime, creating the matching `SpriteSheet`.
+///
+/// Sprites are placed in rows, in the order they were added. Each sprite can be surrounded by
+/// `padding` pixels duplicating its border pixels, so that filtering doesn't sample the
+/// neighbouring sprites.
+#[derive(Clone, Debug)]
+pub struct SpriteSheetBuilder {
+    width: u32,
+    padding: u32,
+    force_pot: bool,
+    images: Vec<(u32, u32, Vec<u8>)>,
+}
+
+impl SpriteSheetBuilder {
+    /// Create a new builder for an atlas of the given width in pixels.
+    ///
+    /// The atlas is widened if a sprite doesn't fit, its height depends on the sprites added.
+    pub fn new(width: u32) -> Self {
+        SpriteSheetBuilder {
+            width,
+            padding: 0,
+            force_pot: false,
+            images: Vec::new(),
+        }
+    }
+
+    /// Set how many pixels of border extrusion surround each sprite.
+    pub fn with_padding(mut self, padding: u32) -> Self {
+        self.padding = padding;
+        self
+    }
+
+    /// Pad the atlas to power of two dimensions, for the GPUs not supporting other texture
+    /// sizes. The sprite coordinates take the extra transparent space into account.
+    pub fn with_force_pot(mut self, force_pot: bool) -> Self {
+        self.force_pot = force_pot;
+        self
+    }
+
+    /// Add a sprite, given its size and RGBA8 pixels with rows going from top to bottom.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `pixels` doesn't contain `width * height` pixels.
+    pub fn with_sprite(mut self, width: u32, height: u32, pixels: Vec<u8>) -> Self {
+        assert_eq!(
+            pixels.len(),
+            (width * height * 4) as usize,
+            "Sprite pixels don't match its size"
+        );
+        self.images.push((width, height, pixels));
+        self
+    }
+
+    /// Pack the sprites, returning the atlas texture data and a sprite sheet using `index` as its
+    /// texture index. Sprites keep the order in which they were added.
+    pub fn build(self, index: usize) -> (TextureData, SpriteSheet) {
+        let padding = self.padding;
+        let mut width = self.images
+            .iter()
+            .map(|&(w, _, _)| w + 2 * padding)
+            .fold(self.width.max(1), u32::max);
+
+        // Place the sprites in rows.
+        let mut positions = Vec::with_capacity(self.images.len());
+        let (mut x, mut y, mut row_height) = (0, 0, 0);
+        for &(w, h, _) in &self.images {
+            let (cell_w, cell_h) = (w + 2 * padding, h + 2 * padding);
+            if x + cell_w > width {
+                x = 0;
+                y += row_height;
+                row_height = 0;
+            }
+            positions.push((x + padding, y + padding));
+            x += cell_w;
+            row_height = row_height.max(cell_h);
+        }
+        let mut height = (y + row_height).max(1);
+        if self.force_pot {
+            width = width.next_power_of_two();
+            height = height.next_power_of_two();
+        }
+
+        let mut data = vec![0u8; (width * height * 4) as usize];
+        let mut sprites = Vec::with_capacity(self.images.len());
+        for (&(w, h, ref pixels), &(left, top)) in self.images.iter().zip(&positions) {
+            let (padding, w, h) = (padding as i64, w as i64, h as i64);
+            let rows = if w > 0 && h > 0 { -padding..h + padding } else { 0..0 };
+            for dy in rows {
+                for dx in -padding..w + padding {
+                    // Clamping to the sprite duplicates its border pixels into the padding.
+                    let src_x = dx.max(0).min(w - 1);
+                    let src_y = dy.max(0).min(h - 1);
+                    let src = ((src_y * w + src_x) * 4) as usize;
+                    let dst_x = left as i64 + dx;
+                    let dst_y = top as i64 + dy;
+                    let dst = ((dst_y * width as i64 + dst_x) * 4) as usize;
+                    data[dst..dst + 4].copy_from_slice(&pixels[src..src + 4]);
+                }
+            }
+            sprites.push(Sprite {
+                left: left as f32 / width as f32,
+                right: (left as f32 + w as f32) / width as f32,
+                top: top as f32 / height as f32,
+                bottom: (top as f32 + h as f32) / height as f32,
+            });
+        }
+
+        let metadata = TextureMetadata::default().with_size(width as u16, height as u16);
+        (
+            TextureData::U8(data, metadata),
+            SpriteSheet {
+                index,
+                texture: None,
+                sprites,
+                names: FnvHashMap::default(),
+            },
+        )
+    }
+}
+
+/// Sprite sheets stored as the layers of a single texture array, so the `DrawSpriteArray` pass
+/// draws the sprites of all the sheets in one draw call.
+///
+/// Inserted as a resource, usually created with a `SpriteArrayBuilder`.
+#[derive(Clone, Debug)]
+pub struct SpriteArray {
+    /// Texture array holding a sheet in each layer, nothing is drawn while it is `None`.
+    pub texture: Option<TextureHandle>,
+    /// Size of the layers, in pixels.
+    pub size: (u32, u32),
+    /// Sprites of each layer, in coordinates normalized to the size of the layers.
+    pub layers: Vec<Vec<Sprite>>,
+}
+
+impl SpriteArray {
+    /// Get the sprite `sprite` of the layer `layer`.
+    pub fn sprite(&self, layer: usize, sprite: usize) -> Option<&Sprite> {
+        self.layers.get(layer).and_then(|sprites| sprites.get(sprite))
+    }
+}
+
+/// Draws a sprite of the `SpriteArray` at the position of the entity, with the `DrawSpriteArray`
+/// pass.
+///
+/// The sprite is centered on the entity and measures one world unit per pixel, before the scale
+/// of the `Transform`.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct ArraySprite {
+    /// Layer of the sheet in the `SpriteArray`
+    pub layer: usize,
+    /// Index of the sprite in the sheet
+    pub sprite: usize,
+}
+
+impl ArraySprite {
+    /// Create a new array sprite
+    pub fn new(layer: usize, sprite: usize) -> Self {
+        ArraySprite { layer, sprite }
+    }
+}
+
+impl Component for ArraySprite {
+    type Storage = DenseVecStorage<Self>;
+}
+
+/// Stacks RGBA8 sprite sheets into the layers of a texture array.
+///
+/// The layers take the size of the largest sheet, smaller sheets are placed in the top left
+/// corner of their layer and their sprites adjusted to match.
+#[derive(Clone, Debug, Default)]
+pub struct SpriteArrayBuilder {
+    sheets: Vec<(u32, u32, Vec<u8>, Vec<Sprite>)>,
+}
+
+impl SpriteArrayBuilder {
+    /// Create a new empty builder
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Add a sheet as the next layer, given its size, RGBA8 pixels with rows going from top to
+    /// bottom, and its sprites normalized to its size.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `pixels` doesn't contain `width * height` pixels.
+    pub fn with_sheet(
+        mut self,
+        width: u32,
+        height: u32,
+        pixels: Vec<u8>,
+        sprites: Vec<Sprite>,
+    ) -> Self {
+        assert_eq!(
+            pixels.len(),
+            (width * height * 4) as usize,
+            "Sheet pixels don't match its size"
+        );
+        self.sheets.push((width, height, pixels, sprites));
+        self
+    }
+
+    /// Stack the sheets, returning the data of the texture array and a sprite array without
+    /// texture. Load the texture data and set the handle as the texture of the array.
+    pub fn build(self) -> (TextureData, SpriteArray) {
+        let width = self.sheets.iter().map(|sheet| sheet.0).fold(1, u32::max);
+        let height = self.sheets.iter().map(|sheet| sheet.1).fold(1, u32::max);
+        let layer_len = (width * height * 4) as usize;
+        let layers = self.sheets.len().max(1);
+
+        let mut data = vec![0u8; layer_len * layers];
+        let mut sprites = Vec::with_capacity(self.sheets.len());
+        for (layer, (w, h, pixels, sheet_sprites)) in self.sheets.into_iter().enumerate() {
+            let row_len = (w * 4) as usize;
+            for y in 0..h as usize {
+                let dst = layer * layer_len + y * (width * 4) as usize;
+                data[dst..dst + row_len].copy_from_slice(&pixels[y * row_len..(y + 1) * row_len]);
+            }
+            let (scale_x, scale_y) = (w as f32 / width as f32, h as f32 / height as f32);
+            sprites.push(
+                sheet_sprites
+                    .into_iter()
+                    .map(|sprite| Sprite {
+                        left: sprite.left * scale_x,
+                        right: sprite.right * scale_x,
+                        top: sprite.top * scale_y,
+                        bottom: sprite.bottom * scale_y,
+                    })
+                    .collect(),
+            );
+        }
+
+        let metadata = TextureMetadata::default()
+            .with_size(width as u16, height as u16)
+            .with_layers(layers as u16);
+        (
+            TextureData::U8(data, metadata),
+            SpriteArray {
+                texture: None,
+                size: (width, height),
+                layers: sprites,
+            },
+        )
+    }
+}
+
 #[cfg(test)]
 mod test {
-    use super::Sprite;
+    use super::{CropDirection, Sprite, SpriteArrayBuilder, SpriteCrop, SpriteSheetBuilder};
+    use formats::TextureData;
 
     #[test]
     fn sprite_from_tuple_maps_coordinates_correctly() {
@@ -90,4 +465,91 @@ mod test {
             [0.0, 0.5, 0.75, 1.0].into()
         );
     }
+
+    #[test]
+    fn sprite_crop_bounds_follow_direction() {
+        let crop = SpriteCrop::new(0.6, CropDirection::LeftToRight);
+        assert_eq!(crop.bounds(), [0., 0., 0.6, 1.]);
+        let crop = SpriteCrop::new(1.5, CropDirection::TopToBottom);
+        assert_eq!(crop.bounds(), [0., 0., 1., 1.]);
+    }
+
+    #[test]
+    fn sprite_sheet_builder_extrudes_borders() {
+        let red = [255, 0, 0, 255];
+        let blue = [0, 0, 255, 255];
+        let (data, sheet) = SpriteSheetBuilder::new(8)
+            .with_padding(1)
+            .with_sprite(1, 1, red.to_vec())
+            .with_sprite(1, 1, blue.to_vec())
+            .build(0);
+        let pixels = match data {
+            TextureData::U8(pixels, _) => pixels,
+            _ => panic!("Expected byte data"),
+        };
+        // Each sprite takes a 3x3 cell.
+        assert_eq!(pixels.len(), 8 * 3 * 4);
+        for y in 0..3 {
+            for x in 0..3 {
+                let i = (y * 8 + x) * 4;
+                assert_eq!(&pixels[i..i + 4], &red);
+                let i = (y * 8 + x + 3) * 4;
+                assert_eq!(&pixels[i..i + 4], &blue);
+            }
+        }
+        assert_eq!(
+            sheet.sprites[1],
+            Sprite {
+                left: 4. / 8.,
+                right: 5. / 8.,
+                top: 1. / 3.,
+                bottom: 2. / 3.,
+            }
+        );
+    }
+
+    #[test]
+    fn sprite_sheet_builder_pads_to_power_of_two() {
+        let red = [255, 0, 0, 255];
+        let sprite = red.iter().cycle().take(5 * 3 * 4).cloned().collect();
+        let (data, sheet) = SpriteSheetBuilder::new(5)
+            .with_force_pot(true)
+            .with_sprite(5, 3, sprite)
+            .build(0);
+        let pixels = match data {
+            TextureData::U8(pixels, _) => pixels,
+            _ => panic!("Expected byte data"),
+        };
+        assert_eq!(pixels.len(), 8 * 4 * 4);
+        assert_eq!(&pixels[(2 * 8 + 4) * 4..(2 * 8 + 5) * 4], &red);
+        assert_eq!(&pixels[(2 * 8 + 5) * 4..(2 * 8 + 6) * 4], &[0; 4]);
+        assert_eq!(
+            sheet.sprites[0],
+            Sprite {
+                left: 0.,
+                right: 5. / 8.,
+                top: 0.,
+                bottom: 3. / 4.,
+            }
+        );
+    }
+
+    #[test]
+    fn sprite_array_builder_pads_smaller_sheets() {
+        let red = [255, 0, 0, 255];
+        let sheet = red.iter().cycle().take(16).cloned().collect();
+        let (data, array) = SpriteArrayBuilder::new()
+            .with_sheet(2, 2, sheet, vec![[0., 1., 0., 1.].into()])
+            .with_sheet(1, 1, red.to_vec(), vec![[0., 1., 0., 1.].into()])
+            .build();
+        let pixels = match data {
+            TextureData::U8(pixels, _) => pixels,
+            _ => panic!("Expected byte data"),
+        };
+        assert_eq!(array.size, (2, 2));
+        assert_eq!(pixels.len(), 2 * 2 * 2 * 4);
+        assert_eq!(&pixels[16..20], &red);
+        assert_eq!(&pixels[20..24], &[0; 4]);
+        assert_eq!(array.sprite(1, 0), Some(&Sprite::from([0., 0.5, 0., 0.5])));
+    }
 }
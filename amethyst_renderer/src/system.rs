@@ -4,7 +4,7 @@
 use std::mem;
 use std::sync::Arc;
 
-use amethyst_assets::{AssetStorage, HotReloadStrategy};
+use amethyst_assets::{AssetReloaded, AssetStorage, HotReloadStrategy};
 use amethyst_core::Time;
 use amethyst_core::shred::Resources;
 use amethyst_core::specs::{Fetch, FetchMut, RunNow, SystemData};
@@ -18,7 +18,7 @@ use formats::{create_mesh_asset, create_texture_asset};
 use mesh::Mesh;
 use pipe::{PipelineBuild, PipelineData, PolyPipeline};
 use renderer::Renderer;
-use resources::{ScreenDimensions, WindowMessages};
+use resources::{Monitors, ScreenDimensions, WindowFocus, WindowMessages, WindowTitle};
 use tex::Texture;
 
 /// Rendering system.
@@ -75,9 +75,27 @@ where
         self.renderer.window().get_inner_size()
     }
 
+    /// Returns the size in pixels of the monitor the window is on.
+    pub fn monitor_size(&self) -> (u32, u32) {
+        self.renderer.window().get_current_monitor().get_dimensions()
+    }
+
+    /// Returns the monitors connected to the system.
+    pub fn monitors(&self) -> Monitors {
+        Monitors::new(self.renderer.events(), self.renderer.window())
+    }
+
     fn asset_loading(
         &mut self,
-        (time, pool, strategy, mut mesh_storage, mut texture_storage): AssetLoadingData,
+        (
+            time,
+            pool,
+            strategy,
+            mut mesh_storage,
+            mut texture_storage,
+            mesh_reloads,
+            texture_reloads,
+        ): AssetLoadingData,
     ) {
         use std::ops::Deref;
 
@@ -96,30 +114,40 @@ where
             &**pool,
             strategy,
         );
+
+        if let Some(mut mesh_reloads) = mesh_reloads {
+            mesh_storage.write_reload_events(&mut mesh_reloads);
+        }
+        if let Some(mut texture_reloads) = texture_reloads {
+            texture_storage.write_reload_events(&mut texture_reloads);
+        }
     }
 
-    fn window_management(&mut self, (mut window_messages, mut screen_dimensions): WindowData) {
+    fn window_management(
+        &mut self,
+        (mut window_messages, mut screen_dimensions, mut window_title): WindowData,
+    ) {
         // Process window commands
         for mut command in window_messages.queue.drain() {
             command(self.renderer.window());
         }
 
+        // Send title changes to the window
+        if window_title.dirty {
+            self.renderer.window().set_title(window_title.title());
+            window_title.dirty = false;
+        }
+
         // Send resource size changes to the window
         if screen_dimensions.dirty {
-            self.renderer.window().set_inner_size(
-                screen_dimensions.width() as u32,
-                screen_dimensions.height() as u32,
-            );
+            let (width, height) = screen_dimensions.window_size();
+            self.renderer.window().set_inner_size(width, height);
             screen_dimensions.dirty = false;
         }
 
         if let Some(size) = self.renderer.window().get_inner_size() {
             // Send window size changes to the resource
-            if size
-                != (
-                    screen_dimensions.width() as u32,
-                    screen_dimensions.height() as u32,
-                ) {
+            if size != screen_dimensions.window_size() {
                 screen_dimensions.update(size.0, size.1);
 
                 // We don't need to send the updated size of the window back to the window itself,
@@ -129,11 +157,18 @@ where
         }
     }
 
-    fn render(&mut self, (mut event_handler, data): RenderData<P>) {
+    fn render(&mut self, (mut event_handler, mut focus, data): RenderData<P>) {
         self.renderer.draw(&mut self.pipe, data);
 
         let mut events: Vec<Event> = Vec::new();
         self.renderer.events_mut().poll_events(|new_event| {
+            if let Event::WindowEvent {
+                event: WindowEvent::Focused(focused),
+                ..
+            } = new_event
+            {
+                focus.0 = focused;
+            }
             compress_events(&mut events, new_event);
         });
 
@@ -147,12 +182,19 @@ type AssetLoadingData<'a> = (
     Option<Fetch<'a, HotReloadStrategy>>,
     FetchMut<'a, AssetStorage<Mesh>>,
     FetchMut<'a, AssetStorage<Texture>>,
+    Option<FetchMut<'a, EventChannel<AssetReloaded<Mesh>>>>,
+    Option<FetchMut<'a, EventChannel<AssetReloaded<Texture>>>>,
 );
 
-type WindowData<'a> = (FetchMut<'a, WindowMessages>, FetchMut<'a, ScreenDimensions>);
+type WindowData<'a> = (
+    FetchMut<'a, WindowMessages>,
+    FetchMut<'a, ScreenDimensions>,
+    FetchMut<'a, WindowTitle>,
+);
 
 type RenderData<'a, P> = (
     FetchMut<'a, EventChannel<Event>>,
+    FetchMut<'a, WindowFocus>,
     <P as PipelineData<'a>>::Data,
 );
 
@@ -61,6 +61,8 @@ pub struct TextureBuilder<D, T> {
     info: Info,
     channel_type: ChannelType,
     sampler: SamplerInfo,
+    pub(crate) generate_mipmaps: bool,
+    layers: u16,
     pd: PhantomData<T>,
 }
 
@@ -102,6 +104,8 @@ where
             },
             channel_type: ChannelFormat::get_channel_type(),
             sampler: SamplerInfo::new(FilterMethod::Scale, WrapMode::Clamp),
+            generate_mipmaps: false,
+            layers: 1,
             pd: PhantomData,
         }
     }
@@ -120,6 +124,19 @@ where
         self
     }
 
+    /// Sets whether the mipmap levels are generated from the texture data.
+    ///
+    /// If no level count has been set with `mip_levels`, a full mipmap chain is generated. This
+    /// also makes the sampler filter trilinearly between the levels, which reduces shimmering
+    /// on textures seen at varying scales. Keep this disabled for pixel art.
+    pub fn generate_mipmaps(mut self, generate: bool) -> Self {
+        self.generate_mipmaps = generate;
+        if generate {
+            self.sampler.filter = FilterMethod::Trilinear;
+        }
+        self
+    }
+
     /// Sets the texture width and height in pixels.
     pub fn with_size(mut self, w: u16, h: u16) -> Self {
         use gfx::texture::{AaMode, Kind};
@@ -127,6 +144,16 @@ where
         self
     }
 
+    /// Sets the number of layers of the texture, making it a 2D texture array when there is more
+    /// than one.
+    ///
+    /// The data holds the layers one after the other, each of the size set by `with_size`. Arrays
+    /// are sampled with a `sampler2DArray` in shaders.
+    pub fn with_layers(mut self, layers: u16) -> Self {
+        self.layers = layers.max(1);
+        self
+    }
+
     /// Sets whether the texture is mutable or not.
     pub fn dynamic(mut self, mutable: bool) -> Self {
         use gfx::memory::Usage;
@@ -140,6 +167,17 @@ where
         self
     }
 
+    /// Sets whether the texture data is sRGB encoded, which makes the GPU convert it to linear
+    /// colors when it is sampled. Only applies to 8 bits per channel formats.
+    pub fn with_srgb(mut self, srgb: bool) -> Self {
+        self.channel_type = if srgb {
+            ChannelType::Srgb
+        } else {
+            ChannelType::Unorm
+        };
+        self
+    }
+
     /// Sets the texture channel type
     pub fn with_channel_type(mut self, channel_type: ChannelType) -> Self {
         self.channel_type = channel_type;
@@ -159,33 +197,58 @@ where
         let mut v_flip_buffer;
         let mut data = self.data.as_ref();
 
+        let layers = self.layers as usize;
         if cfg!(feature = "opengl") {
             let pixel_width = (self.info.format.get_total_bits() / 8) as usize / size_of::<T>();
             v_flip_buffer = Vec::with_capacity(data.len());
             let (w, h, _, _) = self.info.kind.get_dimensions();
             let w = w as usize;
             let h = h as usize;
-            for y in 0..h {
-                for x in 0..(w * pixel_width) {
-                    v_flip_buffer.push(data[x + (h - y - 1) * w * pixel_width]);
-                    // Uncomment this if you need to debug this.
-                    //println!("x: {}, y: {}, w: {}, h: {}, pw: {}", x, y, w, h, pixel_width);
+            // Each layer is flipped on its own.
+            for layer in 0..layers {
+                let start = layer * w * h * pixel_width;
+                for y in 0..h {
+                    for x in 0..(w * pixel_width) {
+                        v_flip_buffer.push(data[start + x + (h - y - 1) * w * pixel_width]);
+                        // Uncomment this if you need to debug this.
+                        //println!("x: {}, y: {}, w: {}, h: {}, pw: {}", x, y, w, h, pixel_width);
+                    }
                 }
             }
             data = &v_flip_buffer;
         }
 
+        let mut info = self.info;
+        if layers > 1 {
+            use gfx::texture::{AaMode, Kind};
+            let (w, h, _, _) = info.kind.get_dimensions();
+            info.kind = Kind::D2Array(w, h, self.layers, AaMode::Single);
+        }
+        let mipmap = if self.generate_mipmaps {
+            if info.levels <= 1 {
+                let (w, h, _, _) = info.kind.get_dimensions();
+                let size = w.max(h).max(1) as f32;
+                info.levels = size.log2().floor() as u8 + 1;
+            }
+            Mipmap::Allocated
+        } else {
+            Mipmap::Provided
+        };
+
+        // The data of a texture array is given one layer at a time.
+        let data = cast_slice(data);
+        let slices = data.chunks((data.len() / layers).max(1)).collect::<Vec<_>>();
         let tex = fac.create_texture_raw(
-            self.info,
+            info,
             Some(self.channel_type),
-            Some((&[cast_slice(data)], Mipmap::Provided)),
+            Some((&slices[..], mipmap)),
         )?;
 
         let desc = ResourceDesc {
             channel: self.channel_type,
             layer: None,
             min: 1,
-            max: self.info.levels,
+            max: info.levels,
             swizzle: Swizzle::new(),
         };
 
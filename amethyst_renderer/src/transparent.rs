@@ -10,3 +10,51 @@ pub struct Transparent;
 impl Component for Transparent {
     type Storage = NullStorage<Self>;
 }
+
+/// Common ways of blending the output of a pass with what is already drawn.
+///
+/// Can be used instead of a `Blend` when enabling transparency on a pass.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum BlendMode {
+    /// Draws over by the alpha of the source, like `ALPHA`.
+    Alpha,
+    /// Adds the source, weighted by its alpha, to the destination. Useful for glows and lights.
+    Additive,
+    /// Multiplies the destination by the source. Useful for shadows and tinting.
+    Multiply,
+    /// Replaces the destination, like `REPLACE`.
+    Replace,
+}
+
+impl From<BlendMode> for Blend {
+    fn from(mode: BlendMode) -> Blend {
+        match mode {
+            BlendMode::Alpha => ALPHA,
+            BlendMode::Additive => Blend {
+                color: BlendChannel {
+                    equation: Equation::Add,
+                    source: Factor::ZeroPlus(BlendValue::SourceAlpha),
+                    destination: Factor::One,
+                },
+                alpha: BlendChannel {
+                    equation: Equation::Add,
+                    source: Factor::Zero,
+                    destination: Factor::One,
+                },
+            },
+            BlendMode::Multiply => Blend {
+                color: BlendChannel {
+                    equation: Equation::Add,
+                    source: Factor::ZeroPlus(BlendValue::DestColor),
+                    destination: Factor::Zero,
+                },
+                alpha: BlendChannel {
+                    equation: Equation::Add,
+                    source: Factor::Zero,
+                    destination: Factor::One,
+                },
+            },
+            BlendMode::Replace => REPLACE,
+        }
+    }
+}
@@ -0,0 +1,167 @@
+//! Upscaling of a fixed internal resolution to the window, see
+//! `DisplayConfig::internal_resolution`.
+
+use gfx::pso::buffer::ElemStride;
+
+use error::{Error, Result};
+use mesh::Mesh;
+use pipe::{Effect, NewEffect, Target};
+use types::{Encoder, Factory, RawShaderResourceView, Sampler, Window};
+use vertex::{PosTex, VertexFormat};
+
+static VERT_SRC: &[u8] = include_bytes!("pass/shaders/vertex/upscale.glsl");
+static FRAG_SRC: &[u8] = include_bytes!("pass/shaders/fragment/upscale.glsl");
+
+#[derive(Copy, Clone, Debug)]
+#[allow(dead_code)] // This is used by the shaders
+#[repr(C)]
+struct UpscaleArgs {
+    scale: [f32; 2],
+    offset: [f32; 2],
+}
+
+/// Draws the first color buffer of the offscreen main target on the window.
+pub(crate) struct Upscale {
+    window: Target,
+    effect: Effect,
+    mesh: Mesh,
+    source: RawShaderResourceView,
+    sampler: Sampler,
+    internal_size: (u32, u32),
+}
+
+impl Upscale {
+    /// Create the upscaling of `source`, which must have been built with a `TargetBuilder`, to
+    /// the window target.
+    pub fn new(fac: &mut Factory, window: Target, source: &Target) -> Result<Self> {
+        use gfx::Factory;
+        use gfx::memory::Typed;
+        use gfx::texture::{FilterMethod, SamplerInfo, WrapMode};
+        use std::mem;
+
+        let source_view = source
+            .color_buf(0)
+            .and_then(|buf| buf.as_input.as_ref())
+            .map(|view| view.raw().clone())
+            .ok_or(Error::NoSuchTarget("".into()))?;
+        let vertices = vec![
+            PosTex {
+                position: [-1., -1., 0.],
+                tex_coord: [0., 0.],
+            },
+            PosTex {
+                position: [1., -1., 0.],
+                tex_coord: [1., 0.],
+            },
+            PosTex {
+                position: [1., 1., 0.],
+                tex_coord: [1., 1.],
+            },
+            PosTex {
+                position: [-1., -1., 0.],
+                tex_coord: [0., 0.],
+            },
+            PosTex {
+                position: [1., 1., 0.],
+                tex_coord: [1., 1.],
+            },
+            PosTex {
+                position: [-1., 1., 0.],
+                tex_coord: [0., 1.],
+            },
+        ];
+        let mesh = Mesh::build(vertices).build(fac)?;
+        let sampler = fac.create_sampler(SamplerInfo::new(FilterMethod::Scale, WrapMode::Clamp));
+        let effect = NewEffect::new(fac, &window, 0)
+            .simple(VERT_SRC, FRAG_SRC)
+            .with_raw_constant_buffer("UpscaleArgs", mem::size_of::<UpscaleArgs>(), 1)
+            .with_raw_vertex_buffer(PosTex::ATTRIBUTES, PosTex::size() as ElemStride, 0)
+            .with_texture("source")
+            .with_output("color", None)
+            .build()?;
+        Ok(Upscale {
+            window,
+            effect,
+            mesh,
+            source: source_view,
+            sampler,
+            internal_size: source.size(),
+        })
+    }
+
+    /// Follow the new size of the window.
+    pub fn resize(&mut self, window: &Window) {
+        self.window.resize_main_target(window);
+        {
+            let data = &mut self.effect.data;
+            data.out_colors.clear();
+            data.out_colors.extend(
+                self.window
+                    .color_bufs()
+                    .iter()
+                    .map(|buf| buf.as_output.clone()),
+            );
+            data.out_depth = self.window
+                .depth_buf()
+                .map(|buf| (buf.as_output.clone(), (0, 0)));
+        }
+        self.effect.set_viewport(&self.window);
+    }
+
+    /// Draw the internal resolution on the window, with black borders around it.
+    pub fn apply(&mut self, encoder: &mut Encoder) {
+        let vbuf = match self.mesh.buffer(PosTex::ATTRIBUTES) {
+            Some(vbuf) => vbuf.clone(),
+            None => return,
+        };
+        self.window.clear_color(encoder, [0., 0., 0., 1.]);
+        let (window_w, window_h) = self.window.size();
+        let (x, y, w, h) = scaled_area((window_w, window_h), self.internal_size);
+        let (window_w, window_h) = (window_w.max(1) as f32, window_h.max(1) as f32);
+        let args = UpscaleArgs {
+            scale: [w as f32 / window_w, h as f32 / window_h],
+            offset: [
+                (2 * x + w) as f32 / window_w - 1.,
+                (2 * y + h) as f32 / window_h - 1.,
+            ],
+        };
+        self.effect.update_constant_buffer("UpscaleArgs", &args, encoder);
+        self.effect.data.vertex_bufs.push(vbuf);
+        self.effect.data.textures.push(self.source.clone());
+        self.effect.data.samplers.push(self.sampler.clone());
+        self.effect.draw(self.mesh.slice(), encoder);
+        self.effect.clear();
+    }
+}
+
+/// Area of the window showing the internal resolution, measured in pixels from the bottom left
+/// corner as `(x, y, width, height)`.
+///
+/// The internal resolution is scaled by the largest whole factor fitting in the window. If the
+/// window is smaller than the internal resolution, it is shrunk to fit instead.
+pub(crate) fn scaled_area(window: (u32, u32), internal: (u32, u32)) -> (u32, u32, u32, u32) {
+    let (internal_w, internal_h) = (internal.0.max(1), internal.1.max(1));
+    let factor = (window.0 / internal_w).min(window.1 / internal_h);
+    let (w, h) = if factor > 0 {
+        (internal_w * factor, internal_h * factor)
+    } else {
+        let fit = (window.0 as f32 / internal_w as f32).min(window.1 as f32 / internal_h as f32);
+        (
+            (internal_w as f32 * fit) as u32,
+            (internal_h as f32 * fit) as u32,
+        )
+    };
+    ((window.0 - w) / 2, (window.1 - h) / 2, w, h)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::scaled_area;
+
+    #[test]
+    fn scales_by_whole_factors() {
+        assert_eq!(scaled_area((1920, 1080), (320, 180)), (0, 0, 1920, 1080));
+        assert_eq!(scaled_area((1000, 600), (320, 180)), (20, 30, 960, 540));
+        assert_eq!(scaled_area((160, 180), (320, 180)), (0, 45, 160, 90));
+    }
+}
@@ -57,6 +57,16 @@ impl Attribute for TexCoord {
     type Repr = [f32; 2];
 }
 
+/// Type for texture array layer attribute of vertex
+#[derive(Clone, Debug)]
+pub enum TexLayer {}
+impl Attribute for TexLayer {
+    const NAME: &'static str = "tex_layer";
+    const FORMAT: Format = Format(SurfaceType::R32, ChannelType::Float);
+    const SIZE: u32 = 4;
+    type Repr = [f32; 1];
+}
+
 /// Type for texture coord attribute of vertex
 #[derive(Clone, Debug)]
 pub enum Normal {}
@@ -217,6 +227,92 @@ impl With<TexCoord> for PosTex {
     };
 }
 
+/// Vertex format with position, UV texture coordinate and RGBA color attributes.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct PosTexColor {
+    /// Position of the vertex in 3D space.
+    pub position: [f32; 3],
+    /// UV texture coordinates used by the vertex.
+    pub tex_coord: [f32; 2],
+    /// RGBA color value of the vertex.
+    pub color: [f32; 4],
+}
+
+unsafe impl Pod for PosTexColor {}
+
+impl VertexFormat for PosTexColor {
+    const ATTRIBUTES: Attributes<'static> = &[
+        (Position::NAME, <Self as With<Position>>::FORMAT),
+        (TexCoord::NAME, <Self as With<TexCoord>>::FORMAT),
+        (Color::NAME, <Self as With<Color>>::FORMAT),
+    ];
+}
+
+impl With<Position> for PosTexColor {
+    const FORMAT: AttributeFormat = Element {
+        offset: 0,
+        format: Position::FORMAT,
+    };
+}
+
+impl With<TexCoord> for PosTexColor {
+    const FORMAT: AttributeFormat = Element {
+        offset: Position::SIZE,
+        format: TexCoord::FORMAT,
+    };
+}
+
+impl With<Color> for PosTexColor {
+    const FORMAT: AttributeFormat = Element {
+        offset: Position::SIZE + TexCoord::SIZE,
+        format: Color::FORMAT,
+    };
+}
+
+/// Vertex format with position, UV texture coordinate and texture array layer attributes.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct PosTexLayer {
+    /// Position of the vertex in 3D space.
+    pub position: [f32; 3],
+    /// UV texture coordinates used by the vertex.
+    pub tex_coord: [f32; 2],
+    /// Layer of the texture array sampled by the vertex.
+    pub tex_layer: [f32; 1],
+}
+
+unsafe impl Pod for PosTexLayer {}
+
+impl VertexFormat for PosTexLayer {
+    const ATTRIBUTES: Attributes<'static> = &[
+        (Position::NAME, <Self as With<Position>>::FORMAT),
+        (TexCoord::NAME, <Self as With<TexCoord>>::FORMAT),
+        (TexLayer::NAME, <Self as With<TexLayer>>::FORMAT),
+    ];
+}
+
+impl With<Position> for PosTexLayer {
+    const FORMAT: AttributeFormat = Element {
+        offset: 0,
+        format: Position::FORMAT,
+    };
+}
+
+impl With<TexCoord> for PosTexLayer {
+    const FORMAT: AttributeFormat = Element {
+        offset: Position::SIZE,
+        format: TexCoord::FORMAT,
+    };
+}
+
+impl With<TexLayer> for PosTexLayer {
+    const FORMAT: AttributeFormat = Element {
+        offset: Position::SIZE + TexCoord::SIZE,
+        format: TexLayer::FORMAT,
+    };
+}
+
 /// Vertex format with position, normal, and UV texture coordinate attributes.
 #[repr(C)]
 #[derive(Clone, Copy, Debug, PartialEq)]
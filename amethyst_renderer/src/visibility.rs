@@ -1,11 +1,14 @@
 use std::cmp::Ordering;
 
 use amethyst_core::GlobalTransform;
-use amethyst_core::cgmath::{EuclideanSpace, InnerSpace, MetricSpace, Point3, Transform, Vector3};
-use amethyst_core::specs::{Entities, Entity, Fetch, FetchMut, Join, ReadStorage, System};
+use amethyst_core::cgmath::{EuclideanSpace, InnerSpace, Matrix4, Point3, SquareMatrix,
+                            Transform, Vector3};
+use amethyst_core::specs::{Component, DenseVecStorage, Entities, Entity, Fetch, FetchMut, Join,
+                           NullStorage, ReadStorage, System};
 use hibitset::BitSet;
 
 use cam::{ActiveCamera, Camera};
+use error::{Error, Result};
 use transparent::Transparent;
 
 /// Resource for controlling what entities should be rendered, and whether to draw them ordered or
@@ -14,12 +17,264 @@ use transparent::Transparent;
 pub struct Visibility {
     /// Visible entities that can be drawn in any order
     pub visible_unordered: BitSet,
-    /// Visible entities that need to be drawn in the given order
+    /// Visible entities that need to be drawn in the given order, back to front
     pub visible_ordered: Vec<Entity>,
 }
 
+/// Resource configuring how the `VisibilitySortingSystem` orders transparent entities.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct TransparencySorting {
+    /// Precision of the depth sorting, in world units. Depths are rounded to a multiple of it, and
+    /// transparent entities with the same rounded depth are drawn in the order of their ids.
+    ///
+    /// Stacked sprites meant to be coplanar can end up with slightly different depths from
+    /// rounding errors in their transforms, which makes their order change between frames. With
+    /// the default of zero, only entities at exactly the same depth are ordered by id.
+    pub epsilon: f32,
+}
+
+impl TransparencySorting {
+    /// Create a new sorting configuration with the given epsilon
+    pub fn new(epsilon: f32) -> Self {
+        TransparencySorting { epsilon }
+    }
+
+    /// Depth used for sorting, in multiples of the epsilon rounded to the nearest one.
+    fn sort_depth(&self, depth: f32) -> f32 {
+        if self.epsilon > 0. {
+            (depth / self.epsilon).round()
+        } else {
+            depth
+        }
+    }
+}
+
+/// Entities with this component are not rendered.
+#[derive(Clone, Debug, Default)]
+pub struct Hidden;
+
+impl Component for Hidden {
+    type Storage = NullStorage<Self>;
+}
+
+/// Bitmask of the layers an entity is drawn on, or of the layers a `Camera` sees.
+///
+/// Entities are only drawn if their layers intersect the layers of the camera. Entities without
+/// this component are on the first layer, and cameras see every layer by default.
+#[derive(Clone, Copy, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+pub struct RenderLayers(pub u32);
+
+impl RenderLayers {
+    /// Number of layers a mask can contain, their indices go from 0 to 31
+    pub const COUNT: u32 = 32;
+
+    /// Create a mask containing only the layer with the given index
+    ///
+    /// Fails with `Error::NoSuchLayer` if the index is not below `RenderLayers::COUNT`.
+    pub fn layer(index: u32) -> Result<Self> {
+        RenderLayers::none().with(index)
+    }
+
+    /// Create a mask containing every layer
+    pub fn all() -> Self {
+        RenderLayers(!0)
+    }
+
+    /// Create a mask containing no layer, entities on it are never drawn
+    pub fn none() -> Self {
+        RenderLayers(0)
+    }
+
+    /// Add the layer with the given index to the mask
+    ///
+    /// Fails with `Error::NoSuchLayer` if the index is not below `RenderLayers::COUNT`.
+    pub fn with(self, index: u32) -> Result<Self> {
+        Ok(RenderLayers(self.0 | RenderLayers::bit(index)?))
+    }
+
+    /// Remove the layer with the given index from the mask
+    ///
+    /// Fails with `Error::NoSuchLayer` if the index is not below `RenderLayers::COUNT`.
+    pub fn without(self, index: u32) -> Result<Self> {
+        Ok(RenderLayers(self.0 & !RenderLayers::bit(index)?))
+    }
+
+    /// Check if the masks have a layer in common
+    pub fn intersects(&self, other: RenderLayers) -> bool {
+        self.0 & other.0 != 0
+    }
+
+    fn bit(index: u32) -> Result<u32> {
+        1u32.checked_shl(index).ok_or(Error::NoSuchLayer(index))
+    }
+}
+
+impl Default for RenderLayers {
+    fn default() -> Self {
+        RenderLayers(1)
+    }
+}
+
+impl Component for RenderLayers {
+    type Storage = DenseVecStorage<Self>;
+}
+
+/// Resource naming the sorting layers of the `SortingLayer` component, in drawing order.
+///
+/// Sprites on the first layer are drawn first, behind the sprites of the following layers.
+/// Entities without a `SortingLayer`, or on a layer missing from the list, are on the first
+/// layer. The default has a single `"default"` layer.
+///
+/// ## Example
+///
+/// ```rust,ignore
+/// world.add_resource(SortingLayers::new(vec!["background", "entities", "foreground", "ui"]));
+/// world
+///     .create_entity()
+///     .with(SortingLayer::new("foreground", 0))
+///     .build();
+/// ```
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+pub struct SortingLayers {
+    names: Vec<String>,
+}
+
+impl SortingLayers {
+    /// Create the sorting layers with the given names, from back to front
+    pub fn new<I, S>(names: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        SortingLayers {
+            names: names.into_iter().map(Into::into).collect(),
+        }
+    }
+
+    /// Names of the layers, from back to front
+    pub fn names(&self) -> &[String] {
+        &self.names
+    }
+
+    /// Position of the layer with the given name in the drawing order, if it exists
+    pub fn order(&self, name: &str) -> Option<usize> {
+        self.names.iter().position(|layer| layer == name)
+    }
+
+}
+
+/// Key sorting entities by layer, then by order in their layer
+///
+/// Without the `SortingLayers` resource, every entity is on the first layer like with the default
+/// layers.
+pub(crate) fn sort_key(
+    layers: Option<&SortingLayers>,
+    layer: Option<&SortingLayer>,
+) -> (usize, i32) {
+    layer
+        .map(|layer| {
+            let order = layers.and_then(|layers| layers.order(&layer.name));
+            (order.unwrap_or(0), layer.order_in_layer)
+        })
+        .unwrap_or((0, 0))
+}
+
+impl Default for SortingLayers {
+    fn default() -> Self {
+        SortingLayers::new(vec!["default"])
+    }
+}
+
+/// Named layer of the `SortingLayers` resource the entity is drawn on, placing it in front of the
+/// entities of the previous layers regardless of their depth.
+///
+/// Within a layer, entities with a higher `order_in_layer` are drawn in front. Entities with this
+/// component are drawn in order, like `Transparent` entities.
+#[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+pub struct SortingLayer {
+    /// Name of the layer in the `SortingLayers` resource.
+    pub name: String,
+    /// Order of the entity in its layer, entities with higher orders being drawn in front.
+    pub order_in_layer: i32,
+}
+
+impl SortingLayer {
+    /// Put the entity on the named layer, with the given order in it
+    pub fn new<S: Into<String>>(name: S, order_in_layer: i32) -> Self {
+        SortingLayer {
+            name: name.into(),
+            order_in_layer,
+        }
+    }
+}
+
+impl Component for SortingLayer {
+    type Storage = DenseVecStorage<Self>;
+}
+
+/// Bounding sphere used to cull entities outside of the camera's view, centered on the origin of
+/// the entity.
+///
+/// The radius is scaled with the largest scale of the entity's `GlobalTransform`.
+/// Entities without a bounding sphere are never culled.
+#[derive(Clone, Debug, PartialEq)]
+pub struct BoundingSphere {
+    /// Radius of the sphere, in local units.
+    pub radius: f32,
+}
+
+impl BoundingSphere {
+    /// Create a new bounding sphere
+    pub fn new(radius: f32) -> Self {
+        BoundingSphere { radius }
+    }
+
+    /// Create a bounding sphere enclosing a sprite of the given size, in local units.
+    pub fn from_size(width: f32, height: f32) -> Self {
+        Self::new((width * width + height * height).sqrt() / 2.)
+    }
+
+    fn in_view(
+        &self,
+        proj: &Matrix4<f32>,
+        view_proj: &Matrix4<f32>,
+        global: &GlobalTransform,
+    ) -> bool {
+        let m = &global.0;
+        let scale = m.x
+            .truncate()
+            .magnitude()
+            .max(m.y.truncate().magnitude())
+            .max(m.z.truncate().magnitude());
+        let radius = self.radius * scale;
+        let clip = view_proj * m.transform_point(Point3::origin()).to_homogeneous();
+        if clip.w <= 0. {
+            // The center is on or behind the eye plane of a perspective camera, where dividing
+            // by `w` would flip the test. Keep the entity, the centers behind the camera are
+            // filtered out by the `VisibilitySortingSystem` afterwards.
+            return true;
+        }
+        let (x, y) = (clip.x / clip.w, clip.y / clip.w);
+        let (rx, ry) = (proj.x.x.abs() * radius / clip.w, proj.y.y.abs() * radius / clip.w);
+        x.abs() - rx <= 1. && y.abs() - ry <= 1.
+    }
+}
+
+impl Component for BoundingSphere {
+    type Storage = DenseVecStorage<Self>;
+}
+
 /// Determine what entities are visible to the camera, and which are not. Will also sort transparent
-/// entities back to front based on distance from camera.
+/// entities back to front based on their depth along the camera's view direction, so that
+/// overlapping sprites seen by a top-down camera composite correctly. Transparent entities with
+/// the same depth once rounded to a multiple of the epsilon of the `TransparencySorting` resource,
+/// if there is one, are drawn in the order of their ids.
+///
+/// Entities with a `SortingLayer` are drawn in order too, sorted by the order of their layer in
+/// the `SortingLayers` resource and their order in the layer before their depth.
+///
+/// `Hidden` entities are never visible, and entities with a `BoundingSphere` are culled when
+/// outside of the camera's view.
 ///
 /// Note that this should run after `GlobalTransform` has been updated for the current frame, and
 /// before rendering occurs.
@@ -32,8 +287,9 @@ pub struct VisibilitySortingSystem {
 struct Internals {
     entity: Entity,
     transparent: bool,
+    layer: (usize, i32),
     centroid: Point3<f32>,
-    camera_distance: f32,
+    camera_depth: f32,
     from_camera: Vector3<f32>,
 }
 
@@ -51,49 +307,93 @@ impl<'a> System<'a> for VisibilitySortingSystem {
     type SystemData = (
         Entities<'a>,
         FetchMut<'a, Visibility>,
+        Option<Fetch<'a, TransparencySorting>>,
+        Option<Fetch<'a, SortingLayers>>,
         Option<Fetch<'a, ActiveCamera>>,
         ReadStorage<'a, Camera>,
         ReadStorage<'a, Transparent>,
+        ReadStorage<'a, SortingLayer>,
         ReadStorage<'a, GlobalTransform>,
+        ReadStorage<'a, Hidden>,
+        ReadStorage<'a, BoundingSphere>,
     );
 
     fn run(
         &mut self,
-        (entities, mut visibility, active, camera, transparent, global): Self::SystemData,
+        (
+            entities,
+            mut visibility,
+            sorting,
+            layers,
+            active,
+            camera,
+            transparent,
+            sorting_layer,
+            global,
+            hidden,
+            bounds,
+        ): Self::SystemData,
     ) {
         let origin = Point3::origin();
+        let layers = layers.as_ref().map(|layers| &**layers);
 
-        let camera: Option<&GlobalTransform> = active
-            .and_then(|a| global.get(a.entity))
-            .or_else(|| (&camera, &global).join().map(|cg| cg.1).next());
+        let camera: Option<(&Camera, &GlobalTransform)> = active
+            .and_then(|a| camera.get(a.entity).into_iter().zip(global.get(a.entity)).next())
+            .or_else(|| (&camera, &global).join().next());
         let camera_backward = camera
-            .map(|c| c.0.z.truncate())
+            .map(|c| (c.1).0.z.truncate().normalize())
             .unwrap_or(Vector3::unit_z());
         let camera_centroid = camera
-            .map(|g| g.0.transform_point(origin))
+            .map(|c| (c.1).0.transform_point(origin))
             .unwrap_or(origin.clone());
+        let camera_proj = camera.and_then(|(cam, global)| {
+            global
+                .0
+                .invert()
+                .map(|view| (cam.proj, cam.proj * view))
+        });
 
         self.centroids.clear();
         self.centroids.extend(
-            (&*entities, &global)
+            (&*entities, &global, !&hidden)
                 .join()
-                .map(|(entity, global)| (entity, global.0.transform_point(origin)))
-                .map(|(entity, centroid)| Internals {
-                    entity,
-                    transparent: transparent.get(entity).is_some(),
-                    centroid,
-                    camera_distance: centroid.distance2(camera_centroid),
-                    from_camera: centroid - camera_centroid,
+                .filter(|&(entity, global, _)| match (camera_proj, bounds.get(entity)) {
+                    (Some((ref proj, ref view_proj)), Some(bounds)) => {
+                        bounds.in_view(proj, view_proj, global)
+                    }
+                    _ => true,
+                })
+                .map(|(entity, global, _)| (entity, global.0.transform_point(origin)))
+                .map(|(entity, centroid)| {
+                    let from_camera = centroid - camera_centroid;
+                    Internals {
+                        entity,
+                        transparent: transparent.get(entity).is_some()
+                            || sorting_layer.get(entity).is_some(),
+                        layer: sort_key(layers, sorting_layer.get(entity)),
+                        centroid,
+                        camera_depth: -from_camera.dot(camera_backward),
+                        from_camera,
+                    }
                 })
                 .filter(|c| c.from_camera.dot(camera_backward) < 0.), // filter entities behind the camera
         );
         self.transparent.clear();
         self.transparent
             .extend(self.centroids.iter().filter(|c| c.transparent).cloned());
+        // Entities at the same depth are ordered by id, so they keep a consistent order between
+        // frames.
+        let sorting = sorting.map(|s| s.clone()).unwrap_or_default();
+        for c in &mut self.transparent {
+            c.camera_depth = sorting.sort_depth(c.camera_depth);
+        }
         self.transparent.sort_by(|a, b| {
-            b.camera_distance
-                .partial_cmp(&a.camera_distance)
-                .unwrap_or(Ordering::Equal)
+            a.layer.cmp(&b.layer).then_with(|| {
+                b.camera_depth
+                    .partial_cmp(&a.camera_depth)
+                    .unwrap_or(Ordering::Equal)
+                    .then_with(|| a.entity.id().cmp(&b.entity.id()))
+            })
         });
         visibility.visible_unordered.clear();
         for c in &self.centroids {
@@ -107,3 +407,31 @@ impl<'a> System<'a> for VisibilitySortingSystem {
             .extend(self.transparent.iter().map(|c| c.entity));
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::{RenderLayers, TransparencySorting};
+
+    #[test]
+    fn layers_are_bound_checked() {
+        let layers = RenderLayers::layer(0).unwrap().with(31).unwrap();
+        assert_eq!(layers, RenderLayers(1 | 1 << 31));
+        assert_eq!(layers.without(0).unwrap(), RenderLayers(1 << 31));
+        assert!(RenderLayers::layer(32).is_err());
+        assert!(layers.with(32).is_err());
+        assert!(layers.without(40).is_err());
+    }
+
+    #[test]
+    fn sort_depth_rounds_to_the_epsilon() {
+        let sorting = TransparencySorting::new(0.5);
+        assert_eq!(sorting.sort_depth(1.1), sorting.sort_depth(0.9));
+        assert_eq!(sorting.sort_depth(-2.1), sorting.sort_depth(-1.9));
+        assert!(sorting.sort_depth(1.0) < sorting.sort_depth(1.5));
+        // Depths closer than the epsilon still sort apart across a rounding boundary.
+        assert!(sorting.sort_depth(1.2) < sorting.sort_depth(1.3));
+
+        let exact = TransparencySorting::default();
+        assert!(exact.sort_depth(1.0) < exact.sort_depth(1.0001));
+    }
+}
@@ -3,7 +3,8 @@
 use amethyst_assets::{AssetStorage, Handle, Processor};
 use amethyst_core::Parent;
 use amethyst_core::bundle::{ECSBundle, Result};
-use amethyst_core::specs::{DispatcherBuilder, World};
+use amethyst_core::schedule::ScheduleBuilder;
+use amethyst_core::specs::World;
 use shrev::EventChannel;
 use std::hash::Hash;
 use std::marker::PhantomData;
@@ -40,9 +41,13 @@ where
     fn build(
         self,
         world: &mut World,
-        builder: DispatcherBuilder<'a, 'b>,
-    ) -> Result<DispatcherBuilder<'a, 'b>> {
+        builder: ScheduleBuilder<'a, 'b>,
+    ) -> Result<ScheduleBuilder<'a, 'b>> {
         world.register::<UiImage>();
+        world.register::<UiSolid>();
+        world.register::<UiShape>();
+        world.register::<UiTint>();
+        world.register::<UiTransition>();
         world.register::<UiTransform>();
         world.register::<UiText>();
         world.register::<TextEditing>();
@@ -69,7 +74,8 @@ where
             .add(UiSystem::new(reader_1), "ui_system", &["font_processor"])
             .add(ResizeSystem::new(reader_2), "ui_resize_system", &[])
             .add(UiMouseSystem::<A, B>::new(), "ui_mouse_system", &[])
-            .add(UiLayoutSystem::new(), "ui_layout", &["ui_system"])
+            .add(UiTransitionSystem::new(), "ui_transition", &[])
+            .add(UiLayoutSystem::new(), "ui_layout", &["ui_system", "ui_transition"])
             .add(UiParentSystem::new(), "ui_parent", &["ui_layout"]))
     }
 }
@@ -0,0 +1,66 @@
+use std::mem;
+use std::sync::Mutex;
+
+use super::*;
+
+/// Resource drawing text on top of the UI without creating entities, for debug information and
+/// simple HUDs.
+///
+/// Text is queued with `draw` at any point of the frame, drawn by the `DrawUi` pass, then
+/// forgotten, so it needs to be queued again every frame it should be shown.
+///
+/// ## Example
+///
+/// ```rust,ignore
+/// world.add_resource(DebugText::new(font));
+///
+/// // Later, in a system or a state
+/// let fps = 1.0 / world.read_resource::<Time>().delta_seconds();
+/// world
+///     .read_resource::<DebugText>()
+///     .draw(format!("FPS: {:.0}", fps), 10., 10., 20., [1., 1., 1., 1.]);
+/// ```
+pub struct DebugText {
+    /// The font used to draw the text
+    pub font: FontHandle,
+    queue: Mutex<Vec<DebugTextEntry>>,
+}
+
+/// A line of text queued in the `DebugText` resource.
+#[derive(Clone, Debug)]
+pub(crate) struct DebugTextEntry {
+    pub text: String,
+    pub position: (f32, f32),
+    pub size: f32,
+    pub color: [f32; 4],
+}
+
+impl DebugText {
+    /// Create a new `DebugText` drawing with the given font
+    pub fn new(font: FontHandle) -> Self {
+        DebugText {
+            font,
+            queue: Mutex::new(Vec::new()),
+        }
+    }
+
+    /// Queue a line of text to draw this frame, with its top left corner at the given position
+    /// in pixels from the top left corner of the play area, `size` pixels high and in the given
+    /// RGBA color.
+    pub fn draw<S>(&self, text: S, x: f32, y: f32, size: f32, color: [f32; 4])
+    where
+        S: Into<String>,
+    {
+        self.queue.lock().unwrap().push(DebugTextEntry {
+            text: text.into(),
+            position: (x, y),
+            size,
+            color,
+        });
+    }
+
+    /// Take the text queued since the last call.
+    pub(crate) fn take(&self) -> Vec<DebugTextEntry> {
+        mem::replace(&mut *self.queue.lock().unwrap(), Vec::new())
+    }
+}
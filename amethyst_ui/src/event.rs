@@ -1,7 +1,7 @@
 use amethyst_core::specs::{Component, Entities, Entity, Fetch, FetchMut, Join, NullStorage,
                            ReadStorage, System};
 use amethyst_input::InputHandler;
-use amethyst_renderer::MouseButton;
+use amethyst_renderer::{MouseButton, ScreenDimensions};
 use shrev::EventChannel;
 use std::hash::Hash;
 use std::marker::PhantomData;
@@ -22,6 +22,8 @@ pub enum UiEventType {
     HoverStart,
     /// When the cursor stops being over an element.
     HoverStop,
+    /// When the `UiTransition` of an element ends.
+    TransitionEnd,
 }
 
 /// A ui event instance.
@@ -83,18 +85,21 @@ where
         ReadStorage<'a, UiTransform>,
         ReadStorage<'a, MouseReactive>,
         Fetch<'a, InputHandler<A, B>>,
+        Fetch<'a, ScreenDimensions>,
         FetchMut<'a, EventChannel<UiEvent>>,
     );
 
-    fn run(&mut self, (entities, transform, react, input, mut events): Self::SystemData) {
+    fn run(
+        &mut self,
+        (entities, transform, react, input, screen_dimensions, mut events): Self::SystemData,
+    ) {
         let down = input.mouse_button_is_down(MouseButton::Left);
 
         // to replace on InputHandler generate OnMouseDown and OnMouseUp events
         let click_started = down && !self.was_down;
         let click_stopped = !down && self.was_down;
         if let Some((pos_x, pos_y)) = input.mouse_position() {
-            let x = pos_x as f32;
-            let y = pos_y as f32;
+            let (x, y) = screen_dimensions.to_play_area((pos_x as f32, pos_y as f32));
             for (tr, e, _) in (&transform, &*entities, &react).join() {
                 let is_in_rect = tr.position_inside(x, y);
                 let was_in_rect = tr.position_inside(self.old_pos.0, self.old_pos.1);
@@ -11,3 +11,110 @@ pub struct UiImage {
 impl Component for UiImage {
     type Storage = VecStorage<Self>;
 }
+
+/// A component filling this entity's `UiTransform` with a solid color, drawn under its
+/// `UiImage` and `UiText` if it has any.
+///
+/// Useful for panel backgrounds, or health bars by changing the width of the transform.
+#[derive(Clone, Debug, PartialEq)]
+pub struct UiSolid {
+    /// The RGBA color of the rectangle
+    pub color: [f32; 4],
+}
+
+impl UiSolid {
+    /// Create a new `UiSolid` with the given color
+    pub fn new(color: [f32; 4]) -> Self {
+        UiSolid { color }
+    }
+}
+
+impl Component for UiSolid {
+    type Storage = VecStorage<Self>;
+}
+
+/// The outline of a `UiShape`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum UiShapeKind {
+    /// A rectangle with corners rounded with the given radius, in pixels. The radius is limited
+    /// to half of the smallest side of the `UiTransform`, which gives a pill shape.
+    RoundedRect(f32),
+    /// The largest circle fitting in the `UiTransform`, centered on it.
+    Circle,
+}
+
+/// A component drawing an anti-aliased shape with an optional border in this entity's
+/// `UiTransform`, drawn under its `UiImage` and `UiText` if it has any.
+///
+/// The shape is computed per pixel by the shader instead of being sampled from a texture, so its
+/// edges stay smooth whatever the size of the transform, which makes it a good background for
+/// buttons.
+#[derive(Clone, Debug, PartialEq)]
+pub struct UiShape {
+    /// The outline of the shape
+    pub kind: UiShapeKind,
+    /// The RGBA color inside the border
+    pub fill: [f32; 4],
+    /// Width of the border drawn inside the outline, in pixels. No border is drawn when zero.
+    pub border_width: f32,
+    /// The RGBA color of the border
+    pub border_color: [f32; 4],
+}
+
+impl UiShape {
+    /// Create a rectangle with rounded corners of the given radius, in pixels
+    pub fn rounded_rect(radius: f32, fill: [f32; 4]) -> Self {
+        UiShape {
+            kind: UiShapeKind::RoundedRect(radius),
+            fill,
+            border_width: 0.,
+            border_color: [0.; 4],
+        }
+    }
+
+    /// Create a circle
+    pub fn circle(fill: [f32; 4]) -> Self {
+        UiShape {
+            kind: UiShapeKind::Circle,
+            ..UiShape::rounded_rect(0., fill)
+        }
+    }
+
+    /// Add a border of the given width, in pixels
+    pub fn with_border(mut self, width: f32, color: [f32; 4]) -> Self {
+        self.border_width = width;
+        self.border_color = color;
+        self
+    }
+}
+
+impl Component for UiShape {
+    type Storage = VecStorage<Self>;
+}
+
+/// A component multiplying the colors of this entity's `UiSolid`, `UiShape`, `UiImage` and
+/// `UiText` when they are drawn, and the colors of the children of this entity through `Parent`.
+///
+/// Changing the alpha fades the whole element with its children, see `UiTransition`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct UiTint {
+    /// The RGBA color multiplied into the element
+    pub color: [f32; 4],
+}
+
+impl UiTint {
+    /// Create a new `UiTint` with the given color
+    pub fn new(color: [f32; 4]) -> Self {
+        UiTint { color }
+    }
+}
+
+impl Default for UiTint {
+    fn default() -> Self {
+        UiTint::new([1.0; 4])
+    }
+}
+
+impl Component for UiTint {
+    type Storage = VecStorage<Self>;
+}
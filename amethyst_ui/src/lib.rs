@@ -13,6 +13,7 @@ extern crate derivative;
 extern crate fnv;
 extern crate gfx;
 extern crate gfx_glyph;
+extern crate hetseq;
 extern crate hibitset;
 extern crate rusttype;
 extern crate shred;
@@ -30,6 +31,7 @@ extern crate thread_profiler;
 
 mod bundle;
 mod button;
+mod debug_text;
 mod event;
 mod focused;
 mod format;
@@ -39,19 +41,22 @@ mod pass;
 mod resize;
 mod text;
 mod transform;
+mod transition;
 
 pub use self::bundle::UiBundle;
 pub use self::button::{UiButton, UiButtonBuilder, UiButtonResources};
+pub use self::debug_text::DebugText;
 pub use self::event::{MouseReactive, UiEvent, UiEventType, UiMouseSystem};
 pub use self::focused::UiFocused;
 pub use self::format::{FontAsset, FontHandle, OtfFormat, TtfFormat};
-pub use self::image::UiImage;
+pub use self::image::{UiImage, UiShape, UiShapeKind, UiSolid, UiTint};
 pub use self::layout::{Anchor, Anchored, ScaleMode, Stretch, Stretched, UiLayoutSystem,
                        UiParentSystem};
 pub use self::pass::DrawUi;
 pub use self::resize::{ResizeSystem, UiResize};
 pub use self::text::{TextEditing, UiSystem, UiText};
 pub use self::transform::UiTransform;
+pub use self::transition::{UiTransition, UiTransitionEnd, UiTransitionKind, UiTransitionSystem};
 
 /// How many times the cursor blinks per second while editing text.
 const CURSOR_BLINK_RATE: f32 = 2.0;
@@ -4,12 +4,13 @@ use std::cmp::{Ordering, PartialOrd};
 use std::hash::{Hash, Hasher};
 
 use amethyst_assets::{AssetStorage, Loader, WeakHandle};
+use amethyst_core::Parent;
 use amethyst_core::cgmath::vec4;
 use amethyst_core::specs::{Entities, Entity, Fetch, Join, ReadStorage, WriteStorage};
 use amethyst_renderer::{Encoder, Factory, Mesh, PosTex, Resources, ScreenDimensions, Texture,
                         TextureData, TextureHandle, TextureMetadata, VertexFormat};
 use amethyst_renderer::error::Result;
-use amethyst_renderer::pipe::{Effect, NewEffect};
+use amethyst_renderer::pipe::{Effect, NewEffect, Stage, StageBuilder};
 use amethyst_renderer::pipe::pass::{Pass, PassData};
 use fnv::FnvHashMap as HashMap;
 use gfx::preset::blend;
@@ -17,6 +18,7 @@ use gfx::pso::buffer::ElemStride;
 use gfx::state::ColorMask;
 use gfx_glyph::{BuiltInLineBreaker, FontId, GlyphBrush, GlyphBrushBuilder, GlyphCruncher,
                 HorizontalAlign, Layout, Scale, SectionText, VariedSection, VerticalAlign};
+use hetseq::Queue;
 use hibitset::BitSet;
 use rusttype::Point;
 use unicode_segmentation::UnicodeSegmentation;
@@ -33,6 +35,54 @@ struct VertexArgs {
     proj_vec: [f32; 4],
     coord: [f32; 2],
     dimension: [f32; 2],
+    offset: [f32; 2],
+    _pad: [f32; 2],
+}
+
+#[derive(Copy, Clone, Debug)]
+#[allow(dead_code)] // This is used by the shaders
+#[repr(C)]
+struct FragmentArgs {
+    tint: [f32; 4],
+    shape_fill: [f32; 4],
+    shape_border_color: [f32; 4],
+    shape_size: [f32; 2],
+    shape_radius: f32,
+    shape_border: f32,
+    /// 0 samples the texture, 1 draws a rounded rectangle and 2 a circle.
+    shape_kind: i32,
+    _pad: [i32; 3],
+}
+
+impl FragmentArgs {
+    fn textured(tint: [f32; 4]) -> Self {
+        FragmentArgs {
+            tint,
+            shape_fill: [0.; 4],
+            shape_border_color: [0.; 4],
+            shape_size: [0.; 2],
+            shape_radius: 0.,
+            shape_border: 0.,
+            shape_kind: 0,
+            _pad: [0; 3],
+        }
+    }
+
+    fn shape(tint: [f32; 4], shape: &UiShape, size: [f32; 2]) -> Self {
+        let (kind, radius) = match shape.kind {
+            UiShapeKind::RoundedRect(radius) => (1, radius),
+            UiShapeKind::Circle => (2, 0.),
+        };
+        FragmentArgs {
+            shape_fill: shape.fill,
+            shape_border_color: shape.border_color,
+            shape_size: size,
+            shape_radius: radius,
+            shape_border: shape.border_width,
+            shape_kind: kind,
+            ..FragmentArgs::textured(tint)
+        }
+    }
 }
 
 #[derive(Clone, Debug)]
@@ -59,7 +109,11 @@ impl Hash for KeyColor {
     }
 }
 
-/// Draw Ui elements.  UI won't display without this.  It's recommended this be your last pass.
+/// Draw Ui elements.  UI won't display without this.  It's recommended this be your last pass,
+/// preferably in its own stage created with `DrawUi::stage`.
+///
+/// The UI is drawn with its own projection matching the screen dimensions in pixels, so it isn't
+/// affected by the game camera.
 pub struct DrawUi {
     mesh: Option<Mesh>,
     cached_draw_order: CachedDrawOrder,
@@ -90,6 +144,13 @@ impl DrawUi {
             next_brush_cache_id: 0,
         }
     }
+
+    /// Create a stage drawing the UI on the backbuffer, on top of what the previous stages drew.
+    ///
+    /// Add it as the last stage of the pipeline, so the UI is always drawn on top of the scene.
+    pub fn stage() -> StageBuilder<Queue<(Queue<()>, DrawUi)>> {
+        Stage::with_backbuffer().with_pass(DrawUi::new())
+    }
 }
 
 impl<'a> PassData<'a> for DrawUi {
@@ -101,9 +162,14 @@ impl<'a> PassData<'a> for DrawUi {
         Fetch<'a, AssetStorage<FontAsset>>,
         Fetch<'a, UiFocused>,
         ReadStorage<'a, UiImage>,
+        ReadStorage<'a, UiSolid>,
+        ReadStorage<'a, UiShape>,
+        ReadStorage<'a, UiTint>,
         ReadStorage<'a, UiTransform>,
+        ReadStorage<'a, Parent>,
         WriteStorage<'a, UiText>,
         ReadStorage<'a, TextEditing>,
+        Option<Fetch<'a, DebugText>>,
     );
 }
 
@@ -141,6 +207,7 @@ impl Pass for DrawUi {
         effect
             .simple(VERT_SRC, FRAG_SRC)
             .with_raw_constant_buffer("VertexArgs", mem::size_of::<VertexArgs>(), 1)
+            .with_raw_constant_buffer("FragmentArgs", mem::size_of::<FragmentArgs>(), 1)
             .with_raw_vertex_buffer(PosTex::ATTRIBUTES, PosTex::size() as ElemStride, 0)
             .with_texture("albedo")
             .with_blended_output("color", ColorMask::all(), blend::ALPHA, None)
@@ -160,11 +227,19 @@ impl Pass for DrawUi {
             font_storage,
             focused,
             ui_image,
+            ui_solid,
+            ui_shape,
+            ui_tint,
             ui_transform,
+            parents,
             mut ui_text,
             editing,
+            debug_text,
         ): <Self as PassData>::Data,
     ) {
+        // Taken first, so the queued text is cleared every frame.
+        let debug_lines = debug_text.as_ref().map(|debug_text| debug_text.take());
+
         // Populate and update the draw order cache.
         {
             let bitset = &mut self.cached_draw_order.cached;
@@ -207,13 +282,22 @@ impl Pass for DrawUi {
         // Sort from largest z value to smallest z value.
         // Most of the time this shouldn't do anything but you still need it for if the z values
         // change.
-        self.cached_draw_order
-            .cache
-            .sort_unstable_by(|&(z1, _), &(z2, _)| z2.partial_cmp(&z1).unwrap_or(Ordering::Equal));
+        // Elements with the same z are drawn in creation order, so the most recently created one
+        // ends up on top, matching the element focused when clicking.
+        self.cached_draw_order.cache.sort_unstable_by(|&(z1, e1), &(z2, e2)| {
+            z2.partial_cmp(&z1)
+                .unwrap_or(Ordering::Equal)
+                .then(e1.cmp(&e2))
+        });
 
+        // Fit the UI in the viewport, which is smaller than the window when its aspect ratio is
+        // locked.
+        let (scale_x, scale_y) = effect.viewport_scale();
+        // Text is positioned in target coordinates.
+        let (offset_x, offset_y) = effect.viewport_offset();
         let proj_vec = vec4(
-            2. / screen_dimensions.width(),
-            -2. / screen_dimensions.height(),
+            2. * scale_x / screen_dimensions.width(),
+            -2. * scale_y / screen_dimensions.height(),
             -2.,
             1.,
         );
@@ -244,8 +328,39 @@ impl Pass for DrawUi {
                     ui_transform.global_y - ui_transform.height / 2.0,
                 ],
                 dimension: [ui_transform.width, ui_transform.height],
+                offset: [-scale_x, scale_y],
+                _pad: [0.0; 2],
             };
             effect.update_constant_buffer("VertexArgs", &vertex_args, encoder);
+            let tint = inherited_tint(entity, &ui_tint, &parents);
+            let fragment_args = FragmentArgs::textured(tint);
+            effect.update_constant_buffer("FragmentArgs", &fragment_args, encoder);
+            if let Some(texture) = ui_solid.get(entity).and_then(|solid| {
+                let cache = &mut self.cached_color_textures;
+                tex_storage.get(&cached_color_texture(cache, solid.color, &loader, &tex_storage))
+            }) {
+                effect.data.textures.push(texture.view().clone());
+                effect.data.samplers.push(texture.sampler().clone());
+                effect.draw(mesh.slice(), encoder);
+                effect.data.textures.clear();
+                effect.data.samplers.clear();
+            }
+            if let Some((shape, texture)) = ui_shape.get(entity).and_then(|shape| {
+                let cache = &mut self.cached_color_textures;
+                tex_storage
+                    .get(&cached_color_texture(cache, [1.0; 4], &loader, &tex_storage))
+                    .map(|texture| (shape, texture))
+            }) {
+                let size = [ui_transform.width, ui_transform.height];
+                let shape_args = FragmentArgs::shape(tint, shape, size);
+                effect.update_constant_buffer("FragmentArgs", &shape_args, encoder);
+                effect.data.textures.push(texture.view().clone());
+                effect.data.samplers.push(texture.sampler().clone());
+                effect.draw(mesh.slice(), encoder);
+                effect.data.textures.clear();
+                effect.data.samplers.clear();
+                effect.update_constant_buffer("FragmentArgs", &fragment_args, encoder);
+            }
             if let Some(image) = ui_image
                 .get(entity)
                 .and_then(|image| tex_storage.get(&image.texture))
@@ -264,25 +379,13 @@ impl Pass for DrawUi {
                         Some(font) => font,
                         None => continue,
                     };
-                    let mut new_id = self.glyph_brushes
-                        .iter()
-                        .filter_map(|(id, ref value)| value.1.upgrade().map(|h| (id, h)))
-                        .find(|&(_id, ref handle)| *handle == ui_text.font)
-                        .map(|(id, _handle)| *id);
-
-                    if new_id.is_none() {
-                        new_id = Some(self.next_brush_cache_id);
-                        self.glyph_brushes.insert(
-                            self.next_brush_cache_id,
-                            (
-                                GlyphBrushBuilder::using_font(font.0.clone())
-                                    .build(factory.clone()),
-                                ui_text.font.downgrade(),
-                            ),
-                        );
-                        self.next_brush_cache_id += 1;
-                    }
-                    ui_text.brush_id = new_id;
+                    ui_text.brush_id = Some(glyph_brush_id(
+                        &mut self.glyph_brushes,
+                        &mut self.next_brush_cache_id,
+                        &ui_text.font,
+                        font,
+                        &factory,
+                    ));
                     ui_text.cached_font = ui_text.font.clone();
                 }
                 // Build text sections.
@@ -327,19 +430,19 @@ impl Pass for DrawUi {
                             SectionText {
                                 text: &((rendered_string)[0..start_byte]),
                                 scale: Scale::uniform(ui_text.font_size),
-                                color: ui_text.color,
+                                color: tinted(ui_text.color, tint),
                                 font_id: FontId(0),
                             },
                             SectionText {
                                 text: &((rendered_string)[start_byte..end_byte]),
                                 scale: Scale::uniform(ui_text.font_size),
-                                color: editing.selected_text_color,
+                                color: tinted(editing.selected_text_color, tint),
                                 font_id: FontId(0),
                             },
                             SectionText {
                                 text: &((rendered_string)[end_byte..]),
                                 scale: Scale::uniform(ui_text.font_size),
-                                color: ui_text.color,
+                                color: tinted(ui_text.color, tint),
                                 font_id: FontId(0),
                             },
                         ]
@@ -348,7 +451,7 @@ impl Pass for DrawUi {
                         SectionText {
                             text: rendered_string,
                             scale: Scale::uniform(ui_text.font_size),
-                            color: ui_text.color,
+                            color: tinted(ui_text.color, tint),
                             font_id: FontId(0),
                         },
                     ]);
@@ -361,8 +464,8 @@ impl Pass for DrawUi {
                 };
                 let section = VariedSection {
                     screen_position: (
-                        ui_transform.global_x - ui_transform.width / 2.0,
-                        ui_transform.global_y - ui_transform.height / 2.0,
+                        ui_transform.global_x - ui_transform.width / 2.0 + offset_x,
+                        ui_transform.global_y - ui_transform.height / 2.0 + offset_y,
                     ),
                     bounds: (ui_transform.width, ui_transform.height),
                     z: ui_transform.global_z / highest_abs_z,
@@ -421,8 +524,10 @@ impl Pass for DrawUi {
                         let pos = glyph.position();
                         let vertex_args = VertexArgs {
                             proj_vec: proj_vec.into(),
-                            coord: [pos.x, pos.y - ascent],
+                            coord: [pos.x - offset_x, pos.y - ascent - offset_y],
                             dimension: [width, height],
+                            offset: [-scale_x, scale_y],
+                            _pad: [0.0; 2],
                         };
                         effect.update_constant_buffer("VertexArgs", &vertex_args, encoder);
                         effect.draw(mesh.slice(), encoder);
@@ -499,8 +604,9 @@ impl Pass for DrawUi {
                                 width = 2.0;
                             }
                             let pos = glyph.map(|g| g.position()).unwrap_or(Point {
-                                x: ui_transform.global_x - ui_transform.width / 2.0,
-                                y: ui_transform.global_y - ui_transform.height / 2.0 + ascent,
+                                x: ui_transform.global_x - ui_transform.width / 2.0 + offset_x,
+                                y: ui_transform.global_y - ui_transform.height / 2.0 + ascent
+                                    + offset_y,
                             });
                             let mut x = pos.x;
                             if let Some(glyph) = glyph {
@@ -514,8 +620,10 @@ impl Pass for DrawUi {
                             }
                             let vertex_args = VertexArgs {
                                 proj_vec: proj_vec.into(),
-                                coord: [x, y],
+                                coord: [x - offset_x, y - offset_y],
                                 dimension: [width, height],
+                                offset: [-scale_x, scale_y],
+                                _pad: [0.0; 2],
                             };
                             effect.update_constant_buffer("VertexArgs", &vertex_args, encoder);
                             effect.draw(mesh.slice(), encoder);
@@ -526,9 +634,82 @@ impl Pass for DrawUi {
                 }
             }
         }
+
+        // Draw the debug text on top of everything else.
+        if let (Some(debug_text), Some(lines)) = (debug_text, debug_lines) {
+            let font = match font_storage.get(&debug_text.font) {
+                Some(font) => font,
+                None => return,
+            };
+            if lines.is_empty() {
+                return;
+            }
+            let id = glyph_brush_id(
+                &mut self.glyph_brushes,
+                &mut self.next_brush_cache_id,
+                &debug_text.font,
+                font,
+                &factory,
+            );
+            let brush = &mut self.glyph_brushes.get_mut(&id).unwrap().0;
+            for line in &lines {
+                brush.queue(VariedSection {
+                    screen_position: (line.position.0 + offset_x, line.position.1 + offset_y),
+                    bounds: (f32::INFINITY, f32::INFINITY),
+                    z: 0.,
+                    layout: Layout::SingleLine {
+                        line_breaker: BuiltInLineBreaker::UnicodeLineBreaker,
+                        h_align: HorizontalAlign::Left,
+                        v_align: VerticalAlign::Top,
+                    },
+                    text: vec![
+                        SectionText {
+                            text: &line.text,
+                            scale: Scale::uniform(line.size),
+                            color: line.color,
+                            font_id: FontId(0),
+                        },
+                    ],
+                });
+            }
+            if let Err(err) = brush.draw_queued(
+                encoder,
+                &effect.data.out_blends[0],
+                &effect.data.out_depth.as_ref().unwrap().0,
+            ) {
+                eprintln!("Unable to draw debug text! Error: {:?}", err);
+            }
+        }
     }
 }
 
+/// Find the glyph brush drawing with a font, creating it if there is none yet.
+fn glyph_brush_id(
+    brushes: &mut GlyphBrushCache,
+    next_id: &mut u32,
+    handle: &FontHandle,
+    font: &FontAsset,
+    factory: &Factory,
+) -> u32 {
+    let existing = brushes
+        .iter()
+        .filter_map(|(id, ref value)| value.1.upgrade().map(|h| (id, h)))
+        .find(|&(_id, ref h)| h == handle)
+        .map(|(id, _h)| *id);
+    existing.unwrap_or_else(|| {
+        let id = *next_id;
+        brushes.insert(
+            id,
+            (
+                GlyphBrushBuilder::using_font(font.0.clone()).build(factory.clone()),
+                handle.downgrade(),
+            ),
+        );
+        *next_id += 1;
+        id
+    })
+}
+
 fn cached_color_texture(
     cache: &mut HashMap<KeyColor, TextureHandle>,
     color: [f32; 4],
@@ -548,15 +729,40 @@ fn cached_color_texture(
         .entry(key)
         .or_insert_with(|| {
             let meta = TextureMetadata {
-                sampler: None,
                 mip_levels: Some(1),
                 size: Some((1, 1)),
-                dynamic: false,
-                format: None,
-                channel: None,
+                ..TextureMetadata::default()
             };
             let texture_data = TextureData::Rgba(color, meta);
             loader.load_from_data(texture_data, (), storage)
         })
         .clone()
 }
+
+/// The tint of an element multiplied by the tints of its parents, so fading a menu fades its
+/// children too.
+fn inherited_tint(
+    entity: Entity,
+    tints: &ReadStorage<UiTint>,
+    parents: &ReadStorage<Parent>,
+) -> [f32; 4] {
+    let mut tint = [1.0; 4];
+    let mut current = Some(entity);
+    while let Some(entity) = current {
+        if let Some(own) = tints.get(entity) {
+            tint = tinted(tint, own.color);
+        }
+        current = parents.get(entity).map(|parent| parent.entity);
+    }
+    tint
+}
+
+/// Multiply a color by the tint of an element.
+fn tinted(color: [f32; 4], tint: [f32; 4]) -> [f32; 4] {
+    [
+        color[0] * tint[0],
+        color[1] * tint[1],
+        color[2] * tint[2],
+        color[3] * tint[3],
+    ]
+}
@@ -2,6 +2,18 @@
 
 #version 150 core
 
+// std140 is a cross platform layout.
+layout (std140) uniform FragmentArgs {
+    uniform vec4 tint;
+    uniform vec4 shape_fill;
+    uniform vec4 shape_border_color;
+    uniform vec2 shape_size;
+    uniform float shape_radius;
+    uniform float shape_border;
+    // 0 samples the texture, 1 draws a rounded rectangle and 2 a circle.
+    uniform int shape_kind;
+};
+
 uniform sampler2D albedo;
 
 in VertexData {
@@ -11,6 +23,32 @@ in VertexData {
 
 out vec4 color;
 
+// Signed distance to the outline of the shape, in pixels, negative inside.
+float shape_distance(vec2 pos) {
+    vec2 half_size = shape_size * 0.5;
+    float max_radius = min(half_size.x, half_size.y);
+    if (shape_kind == 2) {
+        return length(pos) - max_radius;
+    }
+    float radius = clamp(shape_radius, 0.0, max_radius);
+    vec2 q = abs(pos) - half_size + radius;
+    return length(max(q, 0.0)) + min(max(q.x, q.y), 0.0) - radius;
+}
+
 void main() {
-    color = texture(albedo, vertex.tex_coord);
+    if (shape_kind == 0) {
+        color = texture(albedo, vertex.tex_coord) * tint;
+    } else {
+        float dist = shape_distance((vertex.tex_coord - 0.5) * shape_size);
+        // Blend over about a pixel, so the edges are smooth at any size.
+        float aa = max(fwidth(dist), 0.0001);
+        float coverage = clamp(0.5 - dist / aa, 0.0, 1.0);
+        float border = shape_border > 0.0
+            ? clamp(0.5 + (dist + shape_border) / aa, 0.0, 1.0)
+            : 0.0;
+        color = mix(shape_fill, shape_border_color, border);
+        color.a *= coverage;
+        color *= tint;
+    }
+    color.rgb = srgb_encode(color.rgb);
 }
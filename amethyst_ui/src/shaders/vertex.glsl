@@ -7,6 +7,7 @@ layout (std140) uniform VertexArgs {
     uniform vec4 proj_vec;
     uniform vec2 coord;
     uniform vec2 dimension;
+    uniform vec2 offset;
 };
 
 in vec3 position;
@@ -22,7 +23,7 @@ void main() {
     vertex.position *= vec4(dimension, 1, 1);
     vertex.position += vec4(coord, 0, 0);
     vertex.position *= proj_vec;
-    vertex.position += vec4(-1, 1, 0, 0);
+    vertex.position += vec4(offset, 0, 0);
     vertex.tex_coord = tex_coord;
     gl_Position = vertex.position;
 }
@@ -4,6 +4,7 @@ use std::ops::Range;
 use amethyst_core::specs::{Component, DenseVecStorage, Entities, Entity, Fetch, FetchMut, Join,
                            ReadStorage, System, WriteStorage};
 use amethyst_core::timing::Time;
+use amethyst_renderer::ScreenDimensions;
 use clipboard::{ClipboardContext, ClipboardProvider};
 use hibitset::BitSet;
 use rusttype::PositionedGlyph;
@@ -156,11 +157,21 @@ impl<'a> System<'a> for UiSystem {
         FetchMut<'a, UiFocused>,
         Fetch<'a, EventChannel<Event>>,
         Fetch<'a, Time>,
+        Fetch<'a, ScreenDimensions>,
     );
 
     fn run(
         &mut self,
-        (entities, mut text, mut editable, transform, mut focused, events, time): Self::SystemData,
+        (
+            entities,
+            mut text,
+            mut editable,
+            transform,
+            mut focused,
+            events,
+            time,
+            screen_dimensions,
+        ): Self::SystemData,
     ) {
         // Populate and update the tab order cache.
         {
@@ -280,7 +291,8 @@ impl<'a> System<'a> for UiSystem {
                     event: WindowEvent::CursorMoved { position, .. },
                     ..
                 } => {
-                    self.mouse_position = (position.0 as f32, position.1 as f32);
+                    self.mouse_position =
+                        screen_dimensions.to_play_area((position.0 as f32, position.1 as f32));
                     if self.left_mouse_button_pressed {
                         let mut focused_text_edit = focused.entity.and_then(|entity| {
                             text.get_mut(entity)
@@ -16,7 +16,8 @@ pub struct UiTransform {
     /// Centered in the middle of the ui element.
     pub local_y: f32,
     /// Z order, entities with a lower Z order will be rendered on top of entities with a higher
-    /// Z order.
+    /// Z order. Entities with the same Z order are rendered in creation order, the most recently
+    /// created one being on top.
     pub local_z: f32,
     /// The width of this UI element in pixel.
     pub width: f32,
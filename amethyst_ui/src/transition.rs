@@ -0,0 +1,176 @@
+use amethyst_core::specs::{Component, DenseVecStorage, Entities, Entity, Fetch, FetchMut, Join,
+                           System, WriteStorage};
+use amethyst_core::timing::Time;
+use shrev::EventChannel;
+
+use event::{UiEvent, UiEventType};
+use image::UiTint;
+use transform::UiTransform;
+
+/// What a `UiTransition` animates.
+#[derive(Clone, Debug, PartialEq)]
+pub enum UiTransitionKind {
+    /// Animates the alpha of the `UiTint` of the element, which is added if missing.
+    Fade {
+        /// Alpha at the start of the transition
+        from: f32,
+        /// Alpha at the end of the transition
+        to: f32,
+    },
+    /// Animates the position of the `UiTransform` of the element, relative to its position when
+    /// the transition starts.
+    ///
+    /// Elements which are `Anchored` are positioned by the layout, so they can't slide.
+    Slide {
+        /// Offset in pixels at the start of the transition
+        from: (f32, f32),
+        /// Offset in pixels at the end of the transition
+        to: (f32, f32),
+    },
+}
+
+/// What happens to the element when its `UiTransition` ends.
+#[derive(Clone, Debug, PartialEq)]
+pub enum UiTransitionEnd {
+    /// Leave the element as it is at the end of the transition
+    Stay,
+    /// Delete the element entity
+    Delete,
+}
+
+/// A component animating the entity's `UiTransform` or `UiTint` over time, using the
+/// `UiTransitionSystem`.
+///
+/// The component is removed when the transition ends, and a `UiEvent` of type
+/// `UiEventType::TransitionEnd` is sent for the entity, which can be used to resume the game once a
+/// menu has faded out.
+#[derive(Clone, Debug)]
+pub struct UiTransition {
+    /// What the transition animates
+    pub kind: UiTransitionKind,
+    /// Duration of the transition, in seconds
+    pub duration: f32,
+    /// What happens to the element when the transition ends
+    pub end: UiTransitionEnd,
+    elapsed: f32,
+    origin: Option<(f32, f32)>,
+}
+
+impl UiTransition {
+    /// Create a new transition, leaving the element as it is when it ends.
+    pub fn new(kind: UiTransitionKind, duration: f32) -> Self {
+        UiTransition {
+            kind,
+            duration,
+            end: UiTransitionEnd::Stay,
+            elapsed: 0.0,
+            origin: None,
+        }
+    }
+
+    /// Create a transition fading the element in from transparent.
+    pub fn fade_in(duration: f32) -> Self {
+        Self::new(UiTransitionKind::Fade { from: 0.0, to: 1.0 }, duration)
+    }
+
+    /// Create a transition fading the element out to transparent.
+    pub fn fade_out(duration: f32) -> Self {
+        Self::new(UiTransitionKind::Fade { from: 1.0, to: 0.0 }, duration)
+    }
+
+    /// Create a transition sliding the element from an offset in pixels to another.
+    pub fn slide(from: (f32, f32), to: (f32, f32), duration: f32) -> Self {
+        Self::new(UiTransitionKind::Slide { from, to }, duration)
+    }
+
+    /// Set what happens to the element when the transition ends.
+    pub fn with_end(mut self, end: UiTransitionEnd) -> Self {
+        self.end = end;
+        self
+    }
+
+    /// Progress of the transition, from 0 to 1.
+    pub fn progress(&self) -> f32 {
+        if self.duration > 0.0 {
+            (self.elapsed / self.duration).min(1.0)
+        } else {
+            1.0
+        }
+    }
+}
+
+impl Component for UiTransition {
+    type Storage = DenseVecStorage<Self>;
+}
+
+/// Advances the `UiTransition`s, and applies them to the `UiTint` and `UiTransform` of the
+/// elements.
+pub struct UiTransitionSystem {
+    ended: Vec<(Entity, UiTransitionEnd)>,
+}
+
+impl UiTransitionSystem {
+    /// Create a new `UiTransitionSystem`
+    pub fn new() -> Self {
+        UiTransitionSystem { ended: Vec::new() }
+    }
+}
+
+impl<'a> System<'a> for UiTransitionSystem {
+    type SystemData = (
+        Entities<'a>,
+        Fetch<'a, Time>,
+        FetchMut<'a, EventChannel<UiEvent>>,
+        WriteStorage<'a, UiTransition>,
+        WriteStorage<'a, UiTint>,
+        WriteStorage<'a, UiTransform>,
+    );
+
+    fn run(
+        &mut self,
+        (entities, time, mut events, mut transitions, mut tints, mut transforms): Self::SystemData,
+    ) {
+        #[cfg(feature = "profiler")]
+        profile_scope!("ui_transition_system");
+
+        for (entity, transition) in (&*entities, &mut transitions).join() {
+            transition.elapsed += time.delta_seconds();
+            let progress = transition.progress();
+            match transition.kind {
+                UiTransitionKind::Fade { from, to } => {
+                    let alpha = from + (to - from) * progress;
+                    let has_tint = match tints.get_mut(entity) {
+                        Some(tint) => {
+                            tint.color[3] = alpha;
+                            true
+                        }
+                        None => false,
+                    };
+                    if !has_tint {
+                        tints.insert(entity, UiTint::new([1.0, 1.0, 1.0, alpha]));
+                    }
+                }
+                UiTransitionKind::Slide { from, to } => {
+                    if let Some(transform) = transforms.get_mut(entity) {
+                        let (x, y) = *transition
+                            .origin
+                            .get_or_insert((transform.local_x, transform.local_y));
+                        transform.local_x = x + from.0 + (to.0 - from.0) * progress;
+                        transform.local_y = y + from.1 + (to.1 - from.1) * progress;
+                    }
+                }
+            }
+            if progress >= 1.0 {
+                self.ended.push((entity, transition.end.clone()));
+            }
+        }
+
+        for (entity, end) in self.ended.drain(..) {
+            transitions.remove(entity);
+            events.single_write(UiEvent::new(UiEventType::TransitionEnd, entity));
+            if end == UiTransitionEnd::Delete {
+                let _ = entities.delete(entity);
+            }
+        }
+    }
+}
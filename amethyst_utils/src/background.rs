@@ -0,0 +1,143 @@
+//! Full screen background
+
+use amethyst_assets::{AssetStorage, Loader};
+use amethyst_core::cgmath::{SquareMatrix, Vector3, Vector4};
+use amethyst_core::specs::{Component, DenseVecStorage, Entity, Fetch, Join, ReadStorage, System,
+                           World, WriteStorage};
+use amethyst_core::transform::{GlobalTransform, Transform};
+use amethyst_renderer::{ActiveCamera, Camera, Material, MaterialDefaults, Mesh, PosTex,
+                        TextureHandle};
+
+/// Stretches the entity it is attached to over the whole view of the camera, using the
+/// `BackgroundSystem`.
+///
+/// The entity needs a `Transform` and a mesh going from -0.5 to 0.5 on the x and y axes, such as
+/// the one made by `create_background`.
+#[derive(Clone, Debug)]
+pub struct Background {
+    /// Depth of the background, in world units.
+    pub z: f32,
+}
+
+impl Background {
+    ///Creates a new Background at the given depth.
+    pub fn new(z: f32) -> Self {
+        Background { z }
+    }
+}
+
+impl Component for Background {
+    type Storage = DenseVecStorage<Self>;
+}
+
+/// Moves and scales the entities with a `Background` so they cover the view of the active camera,
+/// or of the first camera if there is no `ActiveCamera`.
+///
+/// The view is computed from the camera projection every frame, so backgrounds follow the camera
+/// when it moves, zooms, or when the window is resized. The rotation of the camera is ignored.
+///
+/// Should run after the `TransformSystem`, so the `GlobalTransform` of the camera is up to date.
+pub struct BackgroundSystem;
+
+impl<'a> System<'a> for BackgroundSystem {
+    type SystemData = (
+        Option<Fetch<'a, ActiveCamera>>,
+        ReadStorage<'a, Camera>,
+        ReadStorage<'a, GlobalTransform>,
+        ReadStorage<'a, Background>,
+        WriteStorage<'a, Transform>,
+    );
+
+    fn run(&mut self, (active, cameras, globals, backgrounds, mut transforms): Self::SystemData) {
+        #[cfg(feature = "profiler")]
+        profile_scope!("background_system");
+
+        let camera = active
+            .and_then(|active| {
+                let camera = cameras.get(active.entity);
+                let global = globals.get(active.entity);
+                camera.into_iter().zip(global.into_iter()).next()
+            })
+            .or_else(|| (&cameras, &globals).join().next());
+        let (camera, global) = match camera {
+            Some(camera) => camera,
+            None => return,
+        };
+        let to_world = match camera.proj.invert() {
+            Some(inverse) => global.0 * inverse,
+            None => return,
+        };
+        let corner = |x, y| {
+            let corner = to_world * Vector4::new(x, y, 0.0, 1.0);
+            corner.truncate() / corner.w
+        };
+        let min = corner(-1.0, -1.0);
+        let max = corner(1.0, 1.0);
+        let center = (min + max) / 2.0;
+
+        for (background, transform) in (&backgrounds, &mut transforms).join() {
+            transform.translation = Vector3::new(center.x, center.y, background.z);
+            transform.scale = Vector3::new((max.x - min.x).abs(), (max.y - min.y).abs(), 1.0);
+        }
+    }
+}
+
+///Creates an entity drawing `texture` over the whole view of the camera, at depth `z`.
+///
+///The `Background` component needs to be registered, and the `BackgroundSystem` running, for the
+///quad to cover the view.
+pub fn create_background(world: &mut World, texture: TextureHandle, z: f32) -> Entity {
+    let mesh = {
+        let loader = world.read_resource::<Loader>();
+        loader.load_from_data(
+            quad_vertices().into(),
+            (),
+            &world.read_resource::<AssetStorage<Mesh>>(),
+        )
+    };
+    let material = Material {
+        albedo: texture,
+        ..world.read_resource::<MaterialDefaults>().0.clone()
+    };
+
+    let mut transform = Transform::default();
+    transform.translation.z = z;
+    world
+        .create_entity()
+        .with(mesh)
+        .with(material)
+        .with(transform)
+        .with(GlobalTransform::default())
+        .with(Background::new(z))
+        .build()
+}
+
+fn quad_vertices() -> Vec<PosTex> {
+    // The texture coordinates are 0 at the bottom of the image, as in the render passes.
+    vec![
+        PosTex {
+            position: [-0.5, -0.5, 0.],
+            tex_coord: [0., 0.],
+        },
+        PosTex {
+            position: [0.5, -0.5, 0.],
+            tex_coord: [1., 0.],
+        },
+        PosTex {
+            position: [-0.5, 0.5, 0.],
+            tex_coord: [0., 1.],
+        },
+        PosTex {
+            position: [0.5, 0.5, 0.],
+            tex_coord: [1., 1.],
+        },
+        PosTex {
+            position: [-0.5, 0.5, 0.],
+            tex_coord: [0., 1.],
+        },
+        PosTex {
+            position: [0.5, -0.5, 0.],
+            tex_coord: [1., 0.],
+        },
+    ]
+}
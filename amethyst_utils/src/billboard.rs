@@ -0,0 +1,134 @@
+//! Billboards facing the camera
+
+use amethyst_core::cgmath::{InnerSpace, Matrix3, Quaternion};
+use amethyst_core::specs::{Component, Entities, Fetch, Join, NullStorage, ReadStorage, System,
+                           WriteStorage};
+use amethyst_core::transform::{GlobalTransform, Transform};
+use amethyst_renderer::{ActiveCamera, Camera};
+
+/// Makes the entity always face the camera, using the `BillboardSystem`.
+///
+/// Only the `GlobalTransform` is rotated to face the camera, the `Transform` is left as is. Its
+/// rotation is applied on top, relative to the camera, so a rotation around the z axis spins
+/// the billboard on screen.
+#[derive(Clone, Debug, Default)]
+pub struct Billboard;
+
+impl Component for Billboard {
+    type Storage = NullStorage<Self>;
+}
+
+/// Replaces the rotation of the `GlobalTransform` of entities with a `Billboard` by the rotation
+/// of the camera, keeping their translation and scale.
+///
+/// Uses the `ActiveCamera` if there is one, otherwise the first camera found.
+/// Should run after the `TransformSystem`, so the camera and the billboards are up to date.
+pub struct BillboardSystem;
+
+impl<'a> System<'a> for BillboardSystem {
+    type SystemData = (
+        Entities<'a>,
+        Option<Fetch<'a, ActiveCamera>>,
+        ReadStorage<'a, Camera>,
+        ReadStorage<'a, Transform>,
+        ReadStorage<'a, Billboard>,
+        WriteStorage<'a, GlobalTransform>,
+    );
+
+    fn run(
+        &mut self,
+        (entities, active, cameras, transforms, billboards, mut globals): Self::SystemData,
+    ) {
+        let camera = active
+            .and_then(|active| globals.get(active.entity))
+            .or_else(|| (&cameras, &globals).join().map(|(_, global)| global).next())
+            .map(camera_rotation);
+        let camera = match camera {
+            Some(camera) => camera,
+            None => return,
+        };
+
+        for (entity, _, global) in (&*entities, &billboards, &mut globals).join() {
+            let rotation = transforms
+                .get(entity)
+                .map_or(camera, |transform| camera * transform.rotation);
+            set_rotation(global, rotation);
+        }
+    }
+}
+
+/// Extracts the rotation of the camera, ignoring its scale.
+fn camera_rotation(global: &GlobalTransform) -> Quaternion<f32> {
+    let m = global.0;
+    Matrix3::from_cols(
+        m.x.truncate().normalize(),
+        m.y.truncate().normalize(),
+        m.z.truncate().normalize(),
+    ).into()
+}
+
+/// Replaces the rotation of the transform, keeping the scale along each axis and the translation.
+fn set_rotation(global: &mut GlobalTransform, rotation: Quaternion<f32>) {
+    let rotation = Matrix3::from(rotation);
+    let m = &mut global.0;
+    m.x = (rotation.x * m.x.truncate().magnitude()).extend(0.0);
+    m.y = (rotation.y * m.y.truncate().magnitude()).extend(0.0);
+    m.z = (rotation.z * m.z.truncate().magnitude()).extend(0.0);
+}
+
+#[cfg(test)]
+mod tests {
+    use amethyst_core::cgmath::{Deg, InnerSpace, Matrix3, Matrix4, Quaternion, Rotation3,
+                                Vector3, Vector4};
+    use amethyst_core::specs::{RunNow, World};
+    use amethyst_core::transform::{GlobalTransform, Transform};
+    use amethyst_renderer::Camera;
+
+    use super::{Billboard, BillboardSystem};
+
+    fn assert_close(a: Vector4<f32>, b: Vector4<f32>) {
+        assert!((a - b).magnitude() < 1e-5, "{:?} != {:?}", a, b);
+    }
+
+    #[test]
+    fn billboard_faces_a_rotated_camera() {
+        let mut world = World::new();
+        world.register::<Camera>();
+        world.register::<Transform>();
+        world.register::<GlobalTransform>();
+        world.register::<Billboard>();
+
+        let camera_rotation = Quaternion::from_angle_y(Deg(90.0));
+        world
+            .create_entity()
+            .with(Camera::standard_3d(800.0, 600.0))
+            .with(GlobalTransform(
+                Matrix4::from_translation(Vector3::new(0.0, 0.0, 5.0))
+                    * Matrix4::from(camera_rotation),
+            ))
+            .build();
+
+        let spin = Quaternion::from_angle_z(Deg(30.0));
+        let mut transform = Transform::default();
+        transform.rotation = spin;
+        let global = Matrix4::from_translation(Vector3::new(1.0, 2.0, 3.0))
+            * Matrix4::from(spin)
+            * Matrix4::from_scale(2.0);
+        let billboard = world
+            .create_entity()
+            .with(Billboard)
+            .with(transform.clone())
+            .with(GlobalTransform(global))
+            .build();
+
+        BillboardSystem.run_now(&world.res);
+
+        let expected = Matrix3::from(camera_rotation * spin) * 2.0;
+        let result = world.read::<GlobalTransform>().get(billboard).unwrap().0;
+        assert_close(result.x, expected.x.extend(0.0));
+        assert_close(result.y, expected.y.extend(0.0));
+        assert_close(result.z, expected.z.extend(0.0));
+        assert_close(result.w, Vector4::new(1.0, 2.0, 3.0, 1.0));
+        assert_eq!(world.read::<Transform>().get(billboard), Some(&transform));
+    }
+}
@@ -0,0 +1,82 @@
+//! Smooth camera pan and zoom
+
+use amethyst_core::cgmath::Vector3;
+use amethyst_core::specs::{Component, DenseVecStorage, Fetch, Join, System, WriteStorage};
+use amethyst_core::timing::Time;
+use amethyst_core::transform::Transform;
+
+/// Eases the `Transform` of the entity it is attached to, usually a camera, toward a target
+/// position and zoom, using the `CameraControllerSystem`.
+///
+/// The movement is applied relatively, so it composes with systems which also move the camera
+/// with `Transform::move_*`, such as a follow camera or the `CameraShakeSystem`.
+#[derive(Clone, Debug)]
+pub struct CameraController {
+    /// Position to pan to, in world units. The camera doesn't pan while this is `None`.
+    pub target_position: Option<Vector3<f32>>,
+    /// Zoom to reach, 2.0 showing everything twice as big. Zooming scales the `Transform`, so it
+    /// is meant for orthographic cameras.
+    pub target_zoom: f32,
+    /// How fast the camera reaches its targets, higher is faster. The camera covers about 63% of
+    /// the distance to its targets in `1 / speed` seconds.
+    pub speed: f32,
+    position: Option<Vector3<f32>>,
+    zoom: f32,
+}
+
+impl CameraController {
+    ///Creates a new CameraController with no target position and a zoom of 1.
+    pub fn new(speed: f32) -> Self {
+        CameraController {
+            target_position: None,
+            target_zoom: 1.0,
+            speed,
+            position: None,
+            zoom: 1.0,
+        }
+    }
+
+    ///Returns the current zoom of the camera.
+    pub fn zoom(&self) -> f32 {
+        self.zoom
+    }
+}
+
+impl Component for CameraController {
+    type Storage = DenseVecStorage<Self>;
+}
+
+/// Eases the entities with a `CameraController` toward their targets.
+///
+/// Should run before the `TransformSystem`.
+pub struct CameraControllerSystem;
+
+impl<'a> System<'a> for CameraControllerSystem {
+    type SystemData = (
+        Fetch<'a, Time>,
+        WriteStorage<'a, CameraController>,
+        WriteStorage<'a, Transform>,
+    );
+
+    fn run(&mut self, (time, mut controllers, mut transforms): Self::SystemData) {
+        for (controller, transform) in (&mut controllers, &mut transforms).join() {
+            // Exponential smoothing, independent from the frame rate.
+            let factor = 1.0 - (-controller.speed * time.delta_seconds()).exp();
+
+            if let Some(target) = controller.target_position {
+                let position = controller.position.unwrap_or(transform.translation);
+                let next = position + (target - position) * factor;
+                transform.move_global(next - position);
+                controller.position = Some(next);
+            } else {
+                controller.position = None;
+            }
+
+            let zoom = controller.zoom + (controller.target_zoom - controller.zoom) * factor;
+            if zoom > 0.0 {
+                transform.scale *= controller.zoom / zoom;
+                controller.zoom = zoom;
+            }
+        }
+    }
+}
@@ -0,0 +1,94 @@
+//! Camera shake
+
+use std::f32::consts::PI;
+
+use amethyst_core::cgmath::{Vector3, Zero};
+use amethyst_core::specs::{Component, DenseVecStorage, Entities, Fetch, Join, System,
+                           WriteStorage};
+use amethyst_core::timing::Time;
+use amethyst_core::transform::Transform;
+
+/// Shakes the `Transform` of the entity it is attached to, usually a camera.
+///
+/// The offset decays linearly over `duration` seconds, after which the original position is
+/// restored and the component is removed from the entity.
+///
+/// The offset is applied relatively, so systems which move the camera with `Transform::move_*`
+/// (such as a follow camera) compose with the shake.
+#[derive(Clone, Debug)]
+pub struct CameraShake {
+    /// Maximum offset from the original position, in world units.
+    pub magnitude: f32,
+    /// How long the shake lasts, in seconds.
+    pub duration: f32,
+    /// How many oscillations happen per second.
+    pub frequency: f32,
+    elapsed: f32,
+    offset: Vector3<f32>,
+}
+
+impl CameraShake {
+    ///Creates a new CameraShake.
+    pub fn new(magnitude: f32, duration: f32, frequency: f32) -> Self {
+        CameraShake {
+            magnitude,
+            duration,
+            frequency,
+            elapsed: 0.0,
+            offset: Vector3::zero(),
+        }
+    }
+
+    ///Returns true once the shake has lasted for its whole duration.
+    pub fn is_finished(&self) -> bool {
+        self.elapsed >= self.duration
+    }
+
+    fn next_offset(&self) -> Vector3<f32> {
+        let decay = 1.0 - (self.elapsed / self.duration).min(1.0);
+        let t = self.elapsed * self.frequency * 2.0 * PI;
+        Vector3::new(noise(t), noise(t + 17.3), 0.0) * self.magnitude * decay
+    }
+}
+
+impl Component for CameraShake {
+    type Storage = DenseVecStorage<Self>;
+}
+
+/// Cheap deterministic noise in the range [-1, 1].
+fn noise(t: f32) -> f32 {
+    (t.sin() + (t * 2.3).sin() * 0.5 + (t * 5.1).sin() * 0.25) / 1.75
+}
+
+/// Applies the `CameraShake` offsets to the entities' `Transform`.
+///
+/// Should run before the `TransformSystem`, and after any system moving the shaken entity.
+pub struct CameraShakeSystem;
+
+impl<'a> System<'a> for CameraShakeSystem {
+    type SystemData = (
+        Entities<'a>,
+        Fetch<'a, Time>,
+        WriteStorage<'a, CameraShake>,
+        WriteStorage<'a, Transform>,
+    );
+
+    fn run(&mut self, (entities, time, mut shakes, mut transforms): Self::SystemData) {
+        let mut finished = Vec::new();
+        for (entity, shake, transform) in (&*entities, &mut shakes, &mut transforms).join() {
+            transform.translation -= shake.offset;
+            shake.elapsed += time.delta_seconds();
+            if shake.is_finished() {
+                shake.offset = Vector3::zero();
+                finished.push(entity);
+            } else {
+                shake.offset = shake.next_offset();
+                transform.translation += shake.offset;
+            }
+        }
+
+        for entity in finished {
+            shakes.remove(entity);
+        }
+    }
+}
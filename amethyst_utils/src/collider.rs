@@ -0,0 +1,119 @@
+//! Axis aligned box colliders
+
+use amethyst_core::cgmath::Vector2;
+use amethyst_core::specs::{Component, DenseVecStorage};
+use amethyst_renderer::{Sprite, SpriteSheet};
+
+/// An axis aligned bounding box, relative to the position of the entity it is attached to.
+///
+/// Only describes the shape, use `Collider::intersects` to check for collisions. The systems doing
+/// so should skip `Disabled` entities, nothing else does it for them.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Collider {
+    /// Offset of the center of the box from the entity position, in world units.
+    pub offset: Vector2<f32>,
+    /// Half of the width and height of the box, in world units.
+    pub half_extents: Vector2<f32>,
+}
+
+impl Collider {
+    ///Creates a new Collider centered on the entity position.
+    pub fn new(width: f32, height: f32) -> Self {
+        Collider {
+            offset: Vector2::new(0.0, 0.0),
+            half_extents: Vector2::new(width / 2.0, height / 2.0),
+        }
+    }
+
+    ///Creates a Collider covering the whole sprite at `index` in the sheet.
+    ///
+    ///`texture_size` is the size in pixels of the sprite sheet texture, one pixel being one world
+    ///unit. Returns `None` if there is no sprite at `index`.
+    pub fn from_sprite(
+        sheet: &SpriteSheet,
+        index: usize,
+        texture_size: (u32, u32),
+    ) -> Option<Self> {
+        sheet.sprites.get(index).map(|sprite| {
+            let (width, height) = sprite_size(sprite, texture_size);
+            Collider::new(width, height)
+        })
+    }
+
+    ///Creates a Collider tightly fitting the non-transparent pixels of the sprite at `index`.
+    ///
+    ///`pixels` is the RGBA8 data of the sprite sheet texture, rows going from top to bottom. Pixels
+    ///with an alpha value not above `alpha_threshold` are considered transparent. Falls back to
+    ///`Collider::from_sprite` if the sprite is fully transparent, and returns `None` if there is
+    ///no sprite at `index` or `pixels` is too small for `texture_size`.
+    pub fn from_sprite_pixels(
+        sheet: &SpriteSheet,
+        index: usize,
+        texture_size: (u32, u32),
+        pixels: &[u8],
+        alpha_threshold: u8,
+    ) -> Option<Self> {
+        let (tex_width, tex_height) = texture_size;
+        if pixels.len() < (tex_width * tex_height * 4) as usize {
+            return None;
+        }
+        let sprite = sheet.sprites.get(index)?;
+        let left = (sprite.left * tex_width as f32).round() as u32;
+        let right = ((sprite.right * tex_width as f32).round() as u32).min(tex_width);
+        let top = (sprite.top * tex_height as f32).round() as u32;
+        let bottom = ((sprite.bottom * tex_height as f32).round() as u32).min(tex_height);
+
+        let mut bounds: Option<(u32, u32, u32, u32)> = None;
+        for y in top..bottom {
+            for x in left..right {
+                let alpha = pixels[((y * tex_width + x) * 4 + 3) as usize];
+                if alpha > alpha_threshold {
+                    bounds = Some(match bounds {
+                        Some((min_x, max_x, min_y, max_y)) => {
+                            (min_x.min(x), max_x.max(x), min_y.min(y), max_y.max(y))
+                        }
+                        None => (x, x, y, y),
+                    });
+                }
+            }
+        }
+
+        match bounds {
+            Some((min_x, max_x, min_y, max_y)) => {
+                let width = (max_x + 1 - min_x) as f32;
+                let height = (max_y + 1 - min_y) as f32;
+                // Offset of the box center from the sprite center, with y pointing up.
+                let center_x = (min_x + max_x + 1) as f32 / 2.0 - (left + right) as f32 / 2.0;
+                let center_y = (top + bottom) as f32 / 2.0 - (min_y + max_y + 1) as f32 / 2.0;
+                Some(Collider {
+                    offset: Vector2::new(center_x, center_y),
+                    half_extents: Vector2::new(width / 2.0, height / 2.0),
+                })
+            }
+            None => Collider::from_sprite(sheet, index, texture_size),
+        }
+    }
+
+    ///Returns true if the colliders overlap, given the positions of their entities.
+    pub fn intersects(
+        &self,
+        position: Vector2<f32>,
+        other: &Collider,
+        other_position: Vector2<f32>,
+    ) -> bool {
+        let delta = (position + self.offset) - (other_position + other.offset);
+        let extents = self.half_extents + other.half_extents;
+        delta.x.abs() < extents.x && delta.y.abs() < extents.y
+    }
+}
+
+impl Component for Collider {
+    type Storage = DenseVecStorage<Self>;
+}
+
+fn sprite_size(sprite: &Sprite, (width, height): (u32, u32)) -> (f32, f32) {
+    (
+        (sprite.right - sprite.left).abs() * width as f32,
+        (sprite.bottom - sprite.top).abs() * height as f32,
+    )
+}
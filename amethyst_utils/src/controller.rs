@@ -0,0 +1,209 @@
+//! Character controllers driving a `Velocity` from input
+//!
+//! The controllers only set the `Velocity` of the entity, so they need the `VelocitySystem` to
+//! move it, and compose with the systems reading it, such as the `FaceVelocitySystem`.
+//!
+//! A complete loop from the input to the movement and the animation of a platformer character,
+//! with the `"move_x"` axis and the `"jump"` action in the input bindings:
+//!
+//! ```rust,ignore
+//! let dispatcher = DispatcherBuilder::new()
+//!     .with(
+//!         PlatformerControllerSystem::<String, String>::new("move_x".into(), "jump".into()),
+//!         "platformer_controller",
+//!         &["input_system"],
+//!     )
+//!     .with(VelocitySystem, "velocity", &["platformer_controller"])
+//!     .with(CharacterAnimationSystem, "character_animation", &["platformer_controller"]);
+//!
+//! // Plays the sprite clips of the character from its movement
+//! impl<'a> System<'a> for CharacterAnimationSystem {
+//!     type SystemData = (
+//!         ReadStorage<'a, PlatformerController>,
+//!         ReadStorage<'a, Velocity>,
+//!         ReadStorage<'a, SpriteClips>,
+//!         WriteStorage<'a, AnimationControlSet<usize, Material>>,
+//!         Entities<'a>,
+//!     );
+//!
+//!     fn run(&mut self, data: Self::SystemData) {
+//!         let (controllers, velocities, clips, mut controls, entities) = data;
+//!         for (controller, velocity, clips, entity) in
+//!             (&controllers, &velocities, &clips, &*entities).join()
+//!         {
+//!             let clip = if !controller.grounded {
+//!                 "jump"
+//!             } else if velocity.0.x != 0.0 {
+//!                 "walk"
+//!             } else {
+//!                 "idle"
+//!             };
+//!             clips.play(clip, get_animation_set(&mut controls, entity));
+//!         }
+//!     }
+//! }
+//! ```
+
+use std::hash::Hash;
+use std::marker::PhantomData;
+
+use amethyst_core::Disabled;
+use amethyst_core::cgmath::{InnerSpace, Vector2};
+use amethyst_core::specs::{Component, DenseVecStorage, Fetch, Join, ReadStorage, System,
+                           WriteStorage};
+use amethyst_core::timing::Time;
+use amethyst_input::InputHandler;
+
+use velocity::Velocity;
+
+/// Moves the entity in the 8 directions of the input axes of the `TopDownControllerSystem`, at a
+/// constant speed.
+#[derive(Clone, Debug, PartialEq)]
+pub struct TopDownController {
+    /// Speed of the entity, in world units per second.
+    pub speed: f32,
+}
+
+impl TopDownController {
+    ///Creates a new TopDownController.
+    pub fn new(speed: f32) -> Self {
+        TopDownController { speed }
+    }
+}
+
+impl Component for TopDownController {
+    type Storage = DenseVecStorage<Self>;
+}
+
+/// Moves the entity horizontally from the input axis of the `PlatformerControllerSystem`, and
+/// makes it jump and fall.
+///
+/// There is no collision detection, the game tells the controller when the entity stands on the
+/// ground by setting `grounded`, usually from its `Collider` checks, or from a floor height.
+#[derive(Clone, Debug, PartialEq)]
+pub struct PlatformerController {
+    /// Horizontal speed, in world units per second.
+    pub move_speed: f32,
+    /// Upward speed given by a jump, in world units per second.
+    pub jump_impulse: f32,
+    /// Downward acceleration while in midair, in world units per second squared.
+    pub gravity: f32,
+    /// Whether the entity stands on the ground. It can only jump while grounded, and stops
+    /// falling once grounded. The `PlatformerControllerSystem` clears it when the entity jumps.
+    pub grounded: bool,
+}
+
+impl PlatformerController {
+    ///Creates a new PlatformerController, standing on the ground.
+    pub fn new(move_speed: f32, jump_impulse: f32, gravity: f32) -> Self {
+        PlatformerController {
+            move_speed,
+            jump_impulse,
+            gravity,
+            grounded: true,
+        }
+    }
+}
+
+impl Component for PlatformerController {
+    type Storage = DenseVecStorage<Self>;
+}
+
+/// Sets the `Velocity` of the entities with a `TopDownController` from two input axes.
+///
+/// Diagonal movements are as fast as straight ones. Should run after the `InputSystem` and
+/// before the `VelocitySystem`.
+pub struct TopDownControllerSystem<A, B> {
+    horizontal_axis: A,
+    vertical_axis: A,
+    _marker: PhantomData<B>,
+}
+
+impl<A, B> TopDownControllerSystem<A, B> {
+    ///Creates a new TopDownControllerSystem reading the given input axes.
+    pub fn new(horizontal_axis: A, vertical_axis: A) -> Self {
+        TopDownControllerSystem {
+            horizontal_axis,
+            vertical_axis,
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<'a, A, B> System<'a> for TopDownControllerSystem<A, B>
+where
+    A: Send + Sync + Hash + Eq + Clone + 'static,
+    B: Send + Sync + Hash + Eq + Clone + 'static,
+{
+    type SystemData = (
+        Fetch<'a, InputHandler<A, B>>,
+        ReadStorage<'a, TopDownController>,
+        ReadStorage<'a, Disabled>,
+        WriteStorage<'a, Velocity>,
+    );
+
+    fn run(&mut self, (input, controllers, disabled, mut velocities): Self::SystemData) {
+        let x = input.axis_value(&self.horizontal_axis).unwrap_or(0.0) as f32;
+        let y = input.axis_value(&self.vertical_axis).unwrap_or(0.0) as f32;
+        let mut direction = Vector2::new(x, y);
+        if direction.magnitude2() > 1.0 {
+            direction = direction.normalize();
+        }
+
+        for (controller, _, velocity) in (&controllers, !&disabled, &mut velocities).join() {
+            velocity.0 = direction * controller.speed;
+        }
+    }
+}
+
+/// Sets the `Velocity` of the entities with a `PlatformerController` from an input axis and a
+/// jump action.
+///
+/// Should run after the `InputSystem` and before the `VelocitySystem`.
+pub struct PlatformerControllerSystem<A, B> {
+    horizontal_axis: A,
+    jump_action: B,
+}
+
+impl<A, B> PlatformerControllerSystem<A, B> {
+    ///Creates a new PlatformerControllerSystem reading the given input axis and action.
+    pub fn new(horizontal_axis: A, jump_action: B) -> Self {
+        PlatformerControllerSystem {
+            horizontal_axis,
+            jump_action,
+        }
+    }
+}
+
+impl<'a, A, B> System<'a> for PlatformerControllerSystem<A, B>
+where
+    A: Send + Sync + Hash + Eq + Clone + 'static,
+    B: Send + Sync + Hash + Eq + Clone + 'static,
+{
+    type SystemData = (
+        Fetch<'a, Time>,
+        Fetch<'a, InputHandler<A, B>>,
+        WriteStorage<'a, PlatformerController>,
+        ReadStorage<'a, Disabled>,
+        WriteStorage<'a, Velocity>,
+    );
+
+    fn run(&mut self, (time, input, mut controllers, disabled, mut velocities): Self::SystemData) {
+        let x = input.axis_value(&self.horizontal_axis).unwrap_or(0.0) as f32;
+        let jump = input.action_pressed_now(&self.jump_action).unwrap_or(false);
+
+        for (controller, _, velocity) in (&mut controllers, !&disabled, &mut velocities).join() {
+            velocity.0.x = x * controller.move_speed;
+            if controller.grounded {
+                if jump {
+                    velocity.0.y = controller.jump_impulse;
+                    controller.grounded = false;
+                } else if velocity.0.y < 0.0 {
+                    velocity.0.y = 0.0;
+                }
+            } else {
+                velocity.0.y -= controller.gravity * time.delta_seconds();
+            }
+        }
+    }
+}
@@ -1,7 +1,7 @@
 //! Util Resources
 
-use amethyst_core::{ECSBundle, Result};
-use amethyst_core::specs::{DispatcherBuilder, Fetch, FetchMut, System, World};
+use amethyst_core::{ECSBundle, Result, ScheduleBuilder};
+use amethyst_core::specs::{Fetch, FetchMut, System, World};
 use amethyst_core::timing::{duration_to_nanos, Time};
 use circular_buffer::CircularBuffer;
 
@@ -87,8 +87,8 @@ impl<'a, 'b> ECSBundle<'a, 'b> for FPSCounterBundle {
     fn build(
         self,
         world: &mut World,
-        builder: DispatcherBuilder<'a, 'b>,
-    ) -> Result<DispatcherBuilder<'a, 'b>> {
+        builder: ScheduleBuilder<'a, 'b>,
+    ) -> Result<ScheduleBuilder<'a, 'b>> {
         world.add_resource(FPSCounter::new(self.samplesize));
         Ok(builder.add(FPSCounterSystem, "fps_counter_system", &[]))
     }
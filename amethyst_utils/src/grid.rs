@@ -0,0 +1,43 @@
+//! Grid snapping
+
+use amethyst_core::cgmath::Vector3;
+use amethyst_core::specs::{Component, DenseVecStorage, Join, ReadStorage, System, WriteStorage};
+use amethyst_core::transform::Transform;
+
+///Rounds each coordinate of the position to the nearest multiple of `cell`.
+pub fn snap_to_grid(position: Vector3<f32>, cell: f32) -> Vector3<f32> {
+    position.map(|coord| (coord / cell).round() * cell)
+}
+
+/// Snaps the translation of the entity's `Transform` to a grid, using the `SnapToGridSystem`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct SnapToGrid {
+    /// Size of a grid cell, in world units.
+    pub cell: f32,
+}
+
+impl SnapToGrid {
+    ///Creates a new SnapToGrid with the given cell size.
+    pub fn new(cell: f32) -> Self {
+        SnapToGrid { cell }
+    }
+}
+
+impl Component for SnapToGrid {
+    type Storage = DenseVecStorage<Self>;
+}
+
+/// Snaps the `Transform` of entities with a `SnapToGrid`.
+///
+/// Should run after the systems moving the entities, and before the `TransformSystem`.
+pub struct SnapToGridSystem;
+
+impl<'a> System<'a> for SnapToGridSystem {
+    type SystemData = (ReadStorage<'a, SnapToGrid>, WriteStorage<'a, Transform>);
+
+    fn run(&mut self, (snaps, mut transforms): Self::SystemData) {
+        for (snap, transform) in (&snaps, &mut transforms).join() {
+            transform.translation = snap_to_grid(transform.translation, snap.cell);
+        }
+    }
+}
@@ -0,0 +1,144 @@
+//! Health, damage and healing
+
+use amethyst_core::{ECSBundle, Result, ScheduleBuilder};
+use amethyst_core::specs::{Component, DenseVecStorage, Entity, Fetch, FetchMut, System, World,
+                           WriteStorage};
+use shrev::{EventChannel, ReaderId};
+
+/// The health of an entity.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Health {
+    /// Current health, between 0 and `max`.
+    pub current: f32,
+    /// Maximum health.
+    pub max: f32,
+}
+
+impl Health {
+    ///Creates a new Health at full health.
+    pub fn new(max: f32) -> Self {
+        Health { current: max, max }
+    }
+
+    ///Returns true if the current health has reached zero.
+    pub fn is_dead(&self) -> bool {
+        self.current <= 0.0
+    }
+}
+
+impl Component for Health {
+    type Storage = DenseVecStorage<Self>;
+}
+
+/// Write this event to the `EventChannel<DamageEvent>` to reduce the health of `target`.
+#[derive(Clone, Debug)]
+pub struct DamageEvent {
+    /// The entity receiving the damage.
+    pub target: Entity,
+    /// The amount of health to remove.
+    pub amount: f32,
+}
+
+/// Write this event to the `EventChannel<HealEvent>` to restore the health of `target`.
+#[derive(Clone, Debug)]
+pub struct HealEvent {
+    /// The entity being healed.
+    pub target: Entity,
+    /// The amount of health to restore, the health won't go over `Health::max`.
+    pub amount: f32,
+}
+
+/// Written by the `HealthSystem` to the `EventChannel<DeathEvent>` when the health of an entity
+/// reaches zero.
+#[derive(Clone, Debug)]
+pub struct DeathEvent {
+    /// The entity that died.
+    pub entity: Entity,
+}
+
+/// Applies `DamageEvent`s and `HealEvent`s to the `Health` of the targeted entities.
+///
+/// Entities are not deleted when they die, read the `DeathEvent`s to do so.
+pub struct HealthSystem {
+    damage_reader: ReaderId<DamageEvent>,
+    heal_reader: ReaderId<HealEvent>,
+}
+
+impl HealthSystem {
+    ///Creates a new HealthSystem reading from the given event readers.
+    pub fn new(damage_reader: ReaderId<DamageEvent>, heal_reader: ReaderId<HealEvent>) -> Self {
+        HealthSystem {
+            damage_reader,
+            heal_reader,
+        }
+    }
+}
+
+impl<'a> System<'a> for HealthSystem {
+    type SystemData = (
+        WriteStorage<'a, Health>,
+        Fetch<'a, EventChannel<DamageEvent>>,
+        Fetch<'a, EventChannel<HealEvent>>,
+        FetchMut<'a, EventChannel<DeathEvent>>,
+    );
+
+    fn run(&mut self, (mut healths, damages, heals, mut deaths): Self::SystemData) {
+        for heal in heals.read(&mut self.heal_reader) {
+            if let Some(health) = healths.get_mut(heal.target) {
+                if !health.is_dead() {
+                    health.current = (health.current + heal.amount).min(health.max);
+                }
+            }
+        }
+
+        for damage in damages.read(&mut self.damage_reader) {
+            if let Some(health) = healths.get_mut(damage.target) {
+                if health.is_dead() {
+                    continue;
+                }
+                health.current = (health.current - damage.amount).max(0.0);
+                if health.is_dead() {
+                    deaths.single_write(DeathEvent {
+                        entity: damage.target,
+                    });
+                }
+            }
+        }
+    }
+}
+
+///Registers the Health component, the damage, heal and death event channels and the HealthSystem.
+#[derive(Default)]
+pub struct HealthBundle;
+
+impl HealthBundle {
+    ///Creates a new HealthBundle.
+    pub fn new() -> Self {
+        HealthBundle
+    }
+}
+
+impl<'a, 'b> ECSBundle<'a, 'b> for HealthBundle {
+    fn build(
+        self,
+        world: &mut World,
+        builder: ScheduleBuilder<'a, 'b>,
+    ) -> Result<ScheduleBuilder<'a, 'b>> {
+        world.register::<Health>();
+        world.add_resource(EventChannel::<DeathEvent>::new());
+
+        let mut damages = EventChannel::<DamageEvent>::new();
+        let damage_reader = damages.register_reader();
+        world.add_resource(damages);
+
+        let mut heals = EventChannel::<HealEvent>::new();
+        let heal_reader = heals.register_reader();
+        world.add_resource(heals);
+
+        Ok(builder.add(
+            HealthSystem::new(damage_reader, heal_reader),
+            "health_system",
+            &[],
+        ))
+    }
+}
@@ -0,0 +1,170 @@
+//! Debug overlay showing the components of an entity
+
+use amethyst_core::Disabled;
+use amethyst_core::specs::{Entities, Entity, Fetch, FetchMut, ReadStorage, System};
+use amethyst_core::transform::{GlobalTransform, Transform};
+use amethyst_renderer::{ArraySprite, Hidden};
+use amethyst_ui::DebugText;
+
+use collider::Collider;
+use health::Health;
+use lifetime::Lifetime;
+use velocity::Velocity;
+
+/// Resource selecting the entity shown by the `InspectorSystem`, and where its components are
+/// drawn.
+///
+/// The entity is usually selected with the `Picking` resource when the player clicks:
+///
+/// ```rust,ignore
+/// if input.mouse_button_is_down(MouseButton::Left) {
+///     if let Some((x, y)) = input.mouse_position() {
+///         if let Some(entity) = picking.pick_at(x as u32, y as u32) {
+///             inspector.selected = Some(entity);
+///         }
+///     }
+/// }
+/// ```
+#[derive(Clone, Debug)]
+pub struct Inspector {
+    /// The inspected entity, cleared by the `InspectorSystem` once it's deleted.
+    pub selected: Option<Entity>,
+    /// Position of the top left corner of the overlay, in pixels from the top left corner of
+    /// the screen.
+    pub position: (f32, f32),
+    /// Height of a line of the overlay, in pixels.
+    pub size: f32,
+    /// RGBA color of the text.
+    pub color: [f32; 4],
+}
+
+impl Inspector {
+    ///Creates a new Inspector in the top left corner of the screen, without selection.
+    pub fn new() -> Self {
+        Default::default()
+    }
+}
+
+impl Default for Inspector {
+    fn default() -> Self {
+        Inspector {
+            selected: None,
+            position: (10.0, 10.0),
+            size: 16.0,
+            color: [1.0, 1.0, 1.0, 1.0],
+        }
+    }
+}
+
+/// Draws the components of the entity selected in the `Inspector` resource with the `DebugText`
+/// resource, one component per line, every frame.
+///
+/// Shows `Transform`, `GlobalTransform`, `Velocity`, `Collider`, `Health`, `Lifetime`,
+/// `ArraySprite`, `Hidden` and `Disabled`. Does nothing without both resources.
+pub struct InspectorSystem;
+
+impl<'a> System<'a> for InspectorSystem {
+    type SystemData = (
+        Entities<'a>,
+        Option<FetchMut<'a, Inspector>>,
+        Option<Fetch<'a, DebugText>>,
+        ReadStorage<'a, Transform>,
+        ReadStorage<'a, GlobalTransform>,
+        ReadStorage<'a, Velocity>,
+        ReadStorage<'a, Collider>,
+        ReadStorage<'a, Health>,
+        ReadStorage<'a, Lifetime>,
+        ReadStorage<'a, ArraySprite>,
+        ReadStorage<'a, Hidden>,
+        ReadStorage<'a, Disabled>,
+    );
+
+    fn run(
+        &mut self,
+        (
+            entities,
+            inspector,
+            debug_text,
+            transforms,
+            globals,
+            velocities,
+            colliders,
+            healths,
+            lifetimes,
+            sprites,
+            hidden,
+            disabled,
+        ): Self::SystemData,
+    ) {
+        let (mut inspector, debug_text) = match (inspector, debug_text) {
+            (Some(inspector), Some(debug_text)) => (inspector, debug_text),
+            _ => return,
+        };
+        let entity = match inspector.selected {
+            Some(entity) if entities.is_alive(entity) => entity,
+            Some(_) => {
+                inspector.selected = None;
+                return;
+            }
+            None => return,
+        };
+
+        let mut lines = vec![format!("Entity {}", entity.id())];
+        if let Some(transform) = transforms.get(entity) {
+            let t = transform.translation;
+            let r = transform.rotation;
+            let s = transform.scale;
+            lines.push(format!(
+                "Transform: translation ({:.2}, {:.2}, {:.2}), \
+                 rotation ({:.2}, {:.2}, {:.2}, {:.2}), scale ({:.2}, {:.2}, {:.2})",
+                t.x, t.y, t.z, r.s, r.v.x, r.v.y, r.v.z, s.x, s.y, s.z
+            ));
+        }
+        if let Some(global) = globals.get(entity) {
+            let p = global.0.w;
+            lines.push(format!(
+                "GlobalTransform: position ({:.2}, {:.2}, {:.2})",
+                p.x, p.y, p.z
+            ));
+        }
+        if let Some(velocity) = velocities.get(entity) {
+            lines.push(format!(
+                "Velocity: ({:.2}, {:.2})",
+                velocity.0.x, velocity.0.y
+            ));
+        }
+        if let Some(collider) = colliders.get(entity) {
+            lines.push(format!(
+                "Collider: offset ({:.2}, {:.2}), half extents ({:.2}, {:.2})",
+                collider.offset.x,
+                collider.offset.y,
+                collider.half_extents.x,
+                collider.half_extents.y
+            ));
+        }
+        if let Some(health) = healths.get(entity) {
+            lines.push(format!("Health: {:.1} / {:.1}", health.current, health.max));
+        }
+        if let Some(lifetime) = lifetimes.get(entity) {
+            lines.push(format!("Lifetime: {:.2}s", lifetime.remaining));
+        }
+        if let Some(sprite) = sprites.get(entity) {
+            lines.push(format!(
+                "ArraySprite: layer {}, sprite {}",
+                sprite.layer, sprite.sprite
+            ));
+        }
+        if hidden.get(entity).is_some() {
+            lines.push("Hidden".into());
+        }
+        if disabled.get(entity).is_some() {
+            lines.push("Disabled".into());
+        }
+
+        let (x, y) = inspector.position;
+        for (i, line) in lines.into_iter().enumerate() {
+            let line_y = y + i as f32 * inspector.size * 1.2;
+            debug_text.draw(line, x, line_y, inspector.size, inspector.color);
+        }
+    }
+}
@@ -1,9 +1,27 @@
+extern crate amethyst_assets;
 extern crate amethyst_core;
+extern crate amethyst_input;
+extern crate amethyst_renderer;
+extern crate amethyst_ui;
+extern crate shrev;
 extern crate winit;
 
 #[macro_use]
 #[cfg(feature = "profiler")]
 extern crate thread_profiler;
 
+pub mod background;
 pub mod fps_counter;
 pub mod circular_buffer;
+pub mod camera_shake;
+pub mod camera_controller;
+pub mod health;
+pub mod lifetime;
+pub mod grid;
+pub mod billboard;
+pub mod collider;
+pub mod velocity;
+pub mod controller;
+pub mod sort;
+pub mod inspector;
+pub mod spawner;
@@ -0,0 +1,84 @@
+//! Entity lifetime
+
+use amethyst_core::{Disabled, ECSBundle, Result, ScheduleBuilder};
+use amethyst_core::specs::{Component, DenseVecStorage, Entities, Entity, Fetch, FetchMut, Join,
+                           ReadStorage, System, World, WriteStorage};
+use amethyst_core::timing::Time;
+use shrev::EventChannel;
+
+/// Deletes the entity once `remaining` reaches zero.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Lifetime {
+    /// Time left before the entity is deleted, in seconds.
+    pub remaining: f32,
+}
+
+impl Lifetime {
+    ///Creates a new Lifetime lasting the given amount of seconds.
+    pub fn new(remaining: f32) -> Self {
+        Lifetime { remaining }
+    }
+}
+
+impl Component for Lifetime {
+    type Storage = DenseVecStorage<Self>;
+}
+
+/// Written by the `LifetimeSystem` to the `EventChannel<ExpiredEvent>`, if present, when an entity
+/// is deleted because its `Lifetime` expired.
+#[derive(Clone, Debug)]
+pub struct ExpiredEvent {
+    /// The deleted entity.
+    pub entity: Entity,
+}
+
+/// Decrements the `Lifetime` of entities and deletes them when it expires.
+///
+/// The lifetime of `Disabled` entities doesn't decrease.
+pub struct LifetimeSystem;
+
+impl<'a> System<'a> for LifetimeSystem {
+    type SystemData = (
+        Entities<'a>,
+        Fetch<'a, Time>,
+        WriteStorage<'a, Lifetime>,
+        ReadStorage<'a, Disabled>,
+        Option<FetchMut<'a, EventChannel<ExpiredEvent>>>,
+    );
+
+    fn run(&mut self, (entities, time, mut lifetimes, disabled, mut expired): Self::SystemData) {
+        for (entity, lifetime, _) in (&*entities, &mut lifetimes, !&disabled).join() {
+            lifetime.remaining -= time.delta_seconds();
+            if lifetime.remaining <= 0.0 {
+                let _ = entities.delete(entity);
+                if let Some(ref mut expired) = expired {
+                    expired.single_write(ExpiredEvent { entity });
+                }
+            }
+        }
+    }
+}
+
+///Registers the Lifetime component, the ExpiredEvent channel and the LifetimeSystem.
+#[derive(Default)]
+pub struct LifetimeBundle;
+
+impl LifetimeBundle {
+    ///Creates a new LifetimeBundle.
+    pub fn new() -> Self {
+        LifetimeBundle
+    }
+}
+
+impl<'a, 'b> ECSBundle<'a, 'b> for LifetimeBundle {
+    fn build(
+        self,
+        world: &mut World,
+        builder: ScheduleBuilder<'a, 'b>,
+    ) -> Result<ScheduleBuilder<'a, 'b>> {
+        world.register::<Lifetime>();
+        world.register::<Disabled>();
+        world.add_resource(EventChannel::<ExpiredEvent>::new());
+        Ok(builder.add(LifetimeSystem, "lifetime_system", &[]))
+    }
+}
@@ -0,0 +1,58 @@
+//! Deterministic processing order
+
+use amethyst_core::specs::{Component, DenseVecStorage, Entities, Entity, FetchMut, Join,
+                           ReadStorage, System};
+
+/// Key giving the entity its place in the processing order, lower keys being processed first.
+///
+/// Joins iterate in an unspecified order, use `sorted_entities` or the `SortedEntities` resource
+/// to process entities in key order.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct SortKey(pub i32);
+
+impl Component for SortKey {
+    type Storage = DenseVecStorage<Self>;
+}
+
+///Returns the entities with a SortKey, sorted by key.
+///
+///Entities with equal keys are sorted by entity, so the order is the same every time.
+pub fn sorted_entities(entities: &Entities, keys: &ReadStorage<SortKey>) -> Vec<Entity> {
+    let mut sorted = (&**entities, keys)
+        .join()
+        .map(|(entity, key)| (*key, entity))
+        .collect::<Vec<_>>();
+    sorted.sort();
+    sorted.into_iter().map(|(_, entity)| entity).collect()
+}
+
+/// Resource holding the entities with a `SortKey` in key order, updated by the `SortKeySystem`.
+#[derive(Clone, Debug, Default)]
+pub struct SortedEntities {
+    entities: Vec<Entity>,
+}
+
+impl SortedEntities {
+    ///Returns the sorted entities.
+    pub fn entities(&self) -> &[Entity] {
+        &self.entities
+    }
+}
+
+/// Sorts the entities with a `SortKey` into the `SortedEntities` resource.
+///
+/// The `SortedEntities` resource needs to be added to the world. Systems reading it should run
+/// after this system.
+pub struct SortKeySystem;
+
+impl<'a> System<'a> for SortKeySystem {
+    type SystemData = (
+        Entities<'a>,
+        ReadStorage<'a, SortKey>,
+        FetchMut<'a, SortedEntities>,
+    );
+
+    fn run(&mut self, (entities, keys, mut sorted): Self::SystemData) {
+        sorted.entities = sorted_entities(&entities, &keys);
+    }
+}
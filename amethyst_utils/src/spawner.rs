@@ -0,0 +1,201 @@
+//! Periodic spawning of prefabs
+
+use std::sync::Arc;
+
+use amethyst_core::{Disabled, ECSBundle, Prefab, Result, ScheduleBuilder};
+use amethyst_core::specs::{Component, DenseVecStorage, Entities, Entity, Fetch, Join, LazyUpdate,
+                           ReadStorage, System, World, WriteStorage};
+use amethyst_core::timing::Time;
+use amethyst_core::transform::Transform;
+
+/// Instantiates `prefab` every `interval` seconds, as long as less than `max_alive` of the
+/// entities it spawned are alive.
+///
+/// Spawned entities are placed at the `Transform` of the spawner, if it has one.
+///
+/// ## Example
+///
+/// ```rust,ignore
+/// let enemy = Arc::new(Prefab::new().with(mesh).with(material).with(Health::new(10.0)));
+/// world
+///     .create_entity()
+///     .with(Spawner::new(enemy, 2.0, 5))
+///     .with(spawn_point)
+///     .build();
+/// ```
+pub struct Spawner {
+    /// The template of the spawned entities.
+    pub prefab: Arc<Prefab>,
+    /// Time between two spawns, in seconds. With an interval of 0 or less, one entity is spawned
+    /// per frame.
+    pub interval: f32,
+    /// Maximum number of spawned entities alive at the same time.
+    pub max_alive: usize,
+    timer: f32,
+    spawned: Vec<Entity>,
+}
+
+impl Spawner {
+    ///Creates a new Spawner, spawning its first entity after `interval` seconds.
+    pub fn new(prefab: Arc<Prefab>, interval: f32, max_alive: usize) -> Self {
+        Spawner {
+            prefab,
+            interval,
+            max_alive,
+            timer: 0.0,
+            spawned: Vec::new(),
+        }
+    }
+
+    ///Returns the spawned entities which were alive last frame.
+    pub fn spawned(&self) -> &[Entity] {
+        &self.spawned
+    }
+}
+
+impl Component for Spawner {
+    type Storage = DenseVecStorage<Self>;
+}
+
+/// Spawns the prefabs of `Spawner`s once their interval elapsed.
+///
+/// The timer of spawners at their cap is kept at the interval, so they spawn as soon as one of
+/// their entities is deleted. `Disabled` spawners don't spawn.
+pub struct SpawnerSystem;
+
+impl<'a> System<'a> for SpawnerSystem {
+    type SystemData = (
+        Entities<'a>,
+        Fetch<'a, Time>,
+        Fetch<'a, LazyUpdate>,
+        WriteStorage<'a, Spawner>,
+        ReadStorage<'a, Transform>,
+        ReadStorage<'a, Disabled>,
+    );
+
+    fn run(
+        &mut self,
+        (entities, time, lazy, mut spawners, transforms, disabled): Self::SystemData,
+    ) {
+        for (entity, spawner, _) in (&*entities, &mut spawners, !&disabled).join() {
+            spawner.spawned.retain(|child| entities.is_alive(*child));
+            let interval = spawner.interval.max(0.0);
+            spawner.timer += time.delta_seconds();
+            while spawner.timer >= interval && spawner.spawned.len() < spawner.max_alive {
+                spawner.timer -= interval;
+                let child = entities.create();
+                spawner.prefab.instantiate_lazy(child, &lazy);
+                if let Some(transform) = transforms.get(entity) {
+                    lazy.insert(child, transform.clone());
+                }
+                spawner.spawned.push(child);
+                // The timer wouldn't decrease, so spawn again next frame.
+                if interval == 0.0 {
+                    spawner.timer = 0.0;
+                    break;
+                }
+            }
+            if spawner.spawned.len() >= spawner.max_alive {
+                spawner.timer = spawner.timer.min(interval);
+            }
+        }
+    }
+}
+
+///Registers the Spawner component and the SpawnerSystem.
+#[derive(Default)]
+pub struct SpawnerBundle;
+
+impl SpawnerBundle {
+    ///Creates a new SpawnerBundle.
+    pub fn new() -> Self {
+        SpawnerBundle
+    }
+}
+
+impl<'a, 'b> ECSBundle<'a, 'b> for SpawnerBundle {
+    fn build(
+        self,
+        world: &mut World,
+        builder: ScheduleBuilder<'a, 'b>,
+    ) -> Result<ScheduleBuilder<'a, 'b>> {
+        world.register::<Spawner>();
+        world.register::<Disabled>();
+        Ok(builder.add(SpawnerSystem, "spawner_system", &[]))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use amethyst_core::{Disabled, Prefab};
+    use amethyst_core::specs::{Component, Join, NullStorage, RunNow, World};
+    use amethyst_core::timing::Time;
+    use amethyst_core::transform::Transform;
+
+    use super::{Spawner, SpawnerSystem};
+
+    #[derive(Clone, Default)]
+    struct Enemy;
+
+    impl Component for Enemy {
+        type Storage = NullStorage<Self>;
+    }
+
+    fn world_with_spawner(interval: f32, max_alive: usize) -> World {
+        let mut world = World::new();
+        world.register::<Spawner>();
+        world.register::<Transform>();
+        world.register::<Disabled>();
+        world.register::<Enemy>();
+        world.add_resource(Time::default());
+        let prefab = Arc::new(Prefab::new().with(Enemy));
+        world
+            .create_entity()
+            .with(Spawner::new(prefab, interval, max_alive))
+            .build();
+        world
+    }
+
+    fn step(world: &mut World, seconds: f32) -> usize {
+        world.write_resource::<Time>().set_delta_seconds(seconds);
+        SpawnerSystem.run_now(&world.res);
+        world.maintain();
+        world.read::<Enemy>().join().count()
+    }
+
+    #[test]
+    fn spawns_once_per_interval_up_to_the_cap() {
+        let mut world = world_with_spawner(1.0, 3);
+        assert_eq!(step(&mut world, 0.5), 0);
+        assert_eq!(step(&mut world, 0.5), 1);
+        assert_eq!(step(&mut world, 2.0), 3);
+        assert_eq!(step(&mut world, 5.0), 3);
+    }
+
+    #[test]
+    fn non_positive_interval_spawns_once_per_frame() {
+        let mut world = world_with_spawner(0.0, 5);
+        assert_eq!(step(&mut world, 0.1), 1);
+        assert_eq!(step(&mut world, 0.1), 2);
+
+        let mut world = world_with_spawner(-1.0, 5);
+        assert_eq!(step(&mut world, 0.1), 1);
+    }
+
+    #[test]
+    fn non_positive_interval_respawns_once_below_the_cap() {
+        let mut world = world_with_spawner(-1.0, 1);
+        assert_eq!(step(&mut world, 0.1), 1);
+        assert_eq!(step(&mut world, 0.1), 1);
+
+        let enemy = {
+            let entities = world.entities();
+            let enemies = world.read::<Enemy>();
+            (&*entities, &enemies).join().next().unwrap().0
+        };
+        world.delete_entity(enemy).unwrap();
+        assert_eq!(step(&mut world, 0.1), 1);
+    }
+}
@@ -0,0 +1,106 @@
+//! Linear velocity
+
+use amethyst_core::Disabled;
+use amethyst_core::cgmath::{Quaternion, Rad, Rotation3, Vector2, Zero};
+use amethyst_core::specs::{Component, DenseVecStorage, Fetch, Join, ReadStorage, System,
+                           WriteStorage};
+use amethyst_core::timing::Time;
+use amethyst_core::transform::Transform;
+
+/// Velocity of the entity on the x and y axes, in world units per second.
+///
+/// Entities with a `Transform` are moved by the `VelocitySystem`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Velocity(pub Vector2<f32>);
+
+impl Velocity {
+    ///Creates a new Velocity.
+    pub fn new(x: f32, y: f32) -> Self {
+        Velocity(Vector2::new(x, y))
+    }
+}
+
+impl Default for Velocity {
+    fn default() -> Self {
+        Velocity(Vector2::zero())
+    }
+}
+
+impl Component for Velocity {
+    type Storage = DenseVecStorage<Self>;
+}
+
+/// Rotates the entity around the z axis to face the direction of its `Velocity`, using the
+/// `FaceVelocitySystem`.
+///
+/// The rotation of the entity's `Transform` is overwritten while it moves, and kept when it
+/// stops.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct FaceVelocity {
+    /// Angle added to the direction of the velocity, in radians.
+    ///
+    /// With no offset, the x axis of the entity points along the velocity, so a sprite drawn
+    /// pointing up needs an offset of `-PI / 2`.
+    pub offset: f32,
+}
+
+impl FaceVelocity {
+    ///Creates a new FaceVelocity with the given offset, in radians.
+    pub fn new(offset: f32) -> Self {
+        FaceVelocity { offset }
+    }
+}
+
+impl Component for FaceVelocity {
+    type Storage = DenseVecStorage<Self>;
+}
+
+/// Moves the `Transform` of entities by their `Velocity`, unless they are `Disabled`.
+pub struct VelocitySystem;
+
+impl<'a> System<'a> for VelocitySystem {
+    type SystemData = (
+        Fetch<'a, Time>,
+        ReadStorage<'a, Velocity>,
+        ReadStorage<'a, Disabled>,
+        WriteStorage<'a, Transform>,
+    );
+
+    fn run(&mut self, (time, velocities, disabled, mut transforms): Self::SystemData) {
+        let delta = time.delta_seconds();
+        for (velocity, _, transform) in (&velocities, !&disabled, &mut transforms).join() {
+            let movement = velocity.0 * delta;
+            transform.translation.x += movement.x;
+            transform.translation.y += movement.y;
+        }
+    }
+}
+
+/// Sets the rotation of entities with a `FaceVelocity` from the direction of their `Velocity`.
+///
+/// Should run before the `TransformSystem`.
+pub struct FaceVelocitySystem;
+
+impl<'a> System<'a> for FaceVelocitySystem {
+    type SystemData = (
+        ReadStorage<'a, Velocity>,
+        ReadStorage<'a, FaceVelocity>,
+        ReadStorage<'a, Disabled>,
+        WriteStorage<'a, Transform>,
+    );
+
+    fn run(&mut self, (velocities, faces, disabled, mut transforms): Self::SystemData) {
+        for (velocity, face, _, transform) in
+            (&velocities, &faces, !&disabled, &mut transforms).join()
+        {
+            if velocity.0 != Vector2::zero() {
+                transform.rotation = facing_rotation(velocity.0, face.offset);
+            }
+        }
+    }
+}
+
+/// Rotation around the z axis pointing the x axis along `direction`, plus `offset` radians.
+fn facing_rotation(direction: Vector2<f32>, offset: f32) -> Quaternion<f32> {
+    Quaternion::from_angle_z(Rad(direction.y.atan2(direction.x) + offset))
+}
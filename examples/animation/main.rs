@@ -54,7 +54,8 @@ impl Default for Example {
 }
 
 impl State for Example {
-    fn on_start(&mut self, world: &mut World) {
+    fn on_start(&mut self, data: StateData) {
+        let StateData { world, .. } = data;
         // Initialise the scene with an object, a light and a camera.
         let sphere_entity = initialise_sphere(world);
         self.sphere = Some(sphere_entity);
@@ -63,7 +64,8 @@ impl State for Example {
         initialise_camera(world);
     }
 
-    fn handle_event(&mut self, world: &mut World, event: Event) -> Trans {
+    fn handle_event(&mut self, data: StateData, event: Event) -> Trans {
+        let StateData { world, .. } = data;
         match event {
             Event::WindowEvent { event, .. } => match event {
                 WindowEvent::KeyboardInput {
@@ -1,8 +1,9 @@
 use {Ball, Paddle, ScoreBoard};
 use amethyst::config::Config;
 use amethyst::core::bundle::{ECSBundle, Result};
+use amethyst::core::schedule::ScheduleBuilder;
 use amethyst::core::timing::Time;
-use amethyst::ecs::{DispatcherBuilder, World};
+use amethyst::ecs::World;
 use config::PongConfig;
 use std::path::Path;
 use systems::{BounceSystem, MoveBallsSystem, PaddleSystem, WinnerSystem};
@@ -25,8 +26,8 @@ impl<'a, 'b> ECSBundle<'a, 'b> for PongBundle {
     fn build(
         self,
         world: &mut World,
-        builder: DispatcherBuilder<'a, 'b>,
-    ) -> Result<DispatcherBuilder<'a, 'b>> {
+        builder: ScheduleBuilder<'a, 'b>,
+    ) -> Result<ScheduleBuilder<'a, 'b>> {
         world.add_resource(self.config.arena);
         world.add_resource(self.config.ball);
         world.add_resource(self.config.paddles);
@@ -58,19 +58,20 @@ fn run() -> Result<()> {
         env!("CARGO_MANIFEST_DIR")
     );
     let assets_dir = format!("{}/examples/assets/", env!("CARGO_MANIFEST_DIR"));
-    let pipe = Pipeline::build().with_stage(
-        Stage::with_backbuffer()
-            .clear_target([0.0, 0.0, 0.0, 1.0], 1.0)
-            .with_pass(DrawFlat::<PosTex>::new())
-            .with_pass(DrawUi::new()),
-    );
+    let pipe = Pipeline::build()
+        .with_stage(
+            Stage::with_backbuffer()
+                .clear_target([0.0, 0.0, 0.0, 1.0], 1.0)
+                .with_pass(DrawFlat::<PosTex>::new()),
+        )
+        .with_stage(DrawUi::stage());
     let mut game = Application::build(assets_dir, Pong)?
         .with_frame_limit(
             FrameRateLimitStrategy::SleepAndYield(Duration::from_millis(2)),
             144,
         )
         .with_bundle(
-            InputBundle::<String, String>::new().with_bindings_from_file(&key_bindings_path),
+            InputBundle::<String, String>::new().with_bindings_from_file(&key_bindings_path)?,
         )?
         .with_bundle(PongBundle::new(&config))?
         .with_bundle(TransformBundle::new().with_dep(&["ball_system", "paddle_system"]))?
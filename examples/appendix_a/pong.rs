@@ -13,7 +13,8 @@ use systems::ScoreText;
 pub struct Pong;
 
 impl State for Pong {
-    fn on_start(&mut self, world: &mut World) {
+    fn on_start(&mut self, data: StateData) {
+        let StateData { world, .. } = data;
         use audio::initialise_audio;
 
         // Setup our game.
@@ -25,7 +26,7 @@ impl State for Pong {
         hide_cursor(world);
     }
 
-    fn handle_event(&mut self, _: &mut World, event: Event) -> Trans {
+    fn handle_event(&mut self, _: StateData, event: Event) -> Trans {
         match event {
             Event::WindowEvent { event, .. } => match event {
                 WindowEvent::KeyboardInput {
@@ -4,7 +4,7 @@
 extern crate amethyst;
 extern crate rayon;
 
-use amethyst::{Application, Error, State, Trans};
+use amethyst::{Application, Error, State, StateData, Trans};
 use amethyst::assets::{Loader, Result as AssetResult, SimpleFormat};
 use amethyst::config::Config;
 use amethyst::core::cgmath::{Array, Vector3};
@@ -59,7 +59,8 @@ impl SimpleFormat<Mesh> for Custom {
 struct AssetsExample;
 
 impl State for AssetsExample {
-    fn on_start(&mut self, world: &mut World) {
+    fn on_start(&mut self, data: StateData) {
+        let StateData { world, .. } = data;
         world.add_resource(0usize);
 
         initialise_camera(world);
@@ -95,7 +96,7 @@ impl State for AssetsExample {
             .build();
     }
 
-    fn handle_event(&mut self, _: &mut World, event: Event) -> Trans {
+    fn handle_event(&mut self, _: StateData, event: Event) -> Trans {
         match event {
             Event::WindowEvent { event, .. } => {
                 match event {
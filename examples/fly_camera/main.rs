@@ -2,7 +2,7 @@
 
 extern crate amethyst;
 
-use amethyst::{Application, Error, State, Trans};
+use amethyst::{Application, Error, State, StateData, Trans};
 use amethyst::assets::Loader;
 use amethyst::config::Config;
 use amethyst::controls::{FlyControlBundle, FlyControlTag};
@@ -19,7 +19,8 @@ use amethyst::renderer::{AmbientColor, Camera, DisplayConfig, DrawShaded, Elemen
 struct ExampleState;
 
 impl State for ExampleState {
-    fn on_start(&mut self, world: &mut World) {
+    fn on_start(&mut self, data: StateData) {
+        let StateData { world, .. } = data;
         initialise_camera(world);
 
         let assets = load_assets(&world);
@@ -38,7 +39,7 @@ impl State for ExampleState {
         world.add_resource(AmbientColor(Rgba::from([0.1; 3])));
     }
 
-    fn handle_event(&mut self, _: &mut World, event: Event) -> Trans {
+    fn handle_event(&mut self, _: StateData, event: Event) -> Trans {
         match event {
             Event::WindowEvent { event, .. } => match event {
                 WindowEvent::KeyboardInput {
@@ -121,7 +122,7 @@ fn run() -> Result<(), Error> {
         ))?
         .with_bundle(TransformBundle::new().with_dep(&["fly_movement"]))?
         .with_bundle(
-            InputBundle::<String, String>::new().with_bindings_from_file(&key_bindings_path),
+            InputBundle::<String, String>::new().with_bindings_from_file(&key_bindings_path)?,
         )?
         .with_bundle(RenderBundle::new(pipeline_builder, Some(display_config)))?
         .build()?;
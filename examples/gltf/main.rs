@@ -24,7 +24,8 @@ struct Scene {
 }
 
 impl State for Example {
-    fn on_start(&mut self, world: &mut World) {
+    fn on_start(&mut self, data: StateData) {
+        let StateData { world, .. } = data;
         let gltf_scene = load_gltf_mesh(
             &world,
             &*world.read_resource(),
@@ -87,7 +88,8 @@ impl State for Example {
         world.add_resource(AmbientColor(Rgba(0.2, 0.2, 0.2, 0.2)));
     }
 
-    fn handle_event(&mut self, world: &mut World, event: Event) -> Trans {
+    fn handle_event(&mut self, data: StateData, event: Event) -> Trans {
+        let StateData { world, .. } = data;
         match event {
             Event::WindowEvent { event, .. } => match event {
                 WindowEvent::KeyboardInput {
@@ -7,16 +7,16 @@ use amethyst::prelude::*;
 struct Example;
 
 impl State for Example {
-    fn on_start(&mut self, _: &mut World) {
+    fn on_start(&mut self, _: StateData) {
         println!("Begin!");
     }
 
-    fn update(&mut self, _: &mut World) -> Trans {
+    fn update(&mut self, _: StateData) -> Trans {
         println!("Hello from Amethyst!");
         Trans::Quit
     }
 
-    fn on_stop(&mut self, _: &mut World) {
+    fn on_stop(&mut self, _: StateData) {
         println!("End!");
     }
 }
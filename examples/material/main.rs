@@ -14,7 +14,8 @@ use genmesh::generators::SphereUV;
 struct Example;
 
 impl State for Example {
-    fn on_start(&mut self, world: &mut World) {
+    fn on_start(&mut self, data: StateData) {
+        let StateData { world, .. } = data;
         let mat_defaults = world.read_resource::<MaterialDefaults>().0.clone();
         let verts = gen_sphere(32, 32).into();
         let albedo = [1.0, 1.0, 1.0, 1.0].into();
@@ -99,7 +100,7 @@ impl State for Example {
             .build();
     }
 
-    fn handle_event(&mut self, _: &mut World, event: Event) -> Trans {
+    fn handle_event(&mut self, _: StateData, event: Event) -> Trans {
         match event {
             Event::WindowEvent { event, .. } => match event {
                 WindowEvent::KeyboardInput {
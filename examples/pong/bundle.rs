@@ -1,7 +1,8 @@
 use {Ball, Paddle, ScoreBoard};
 use amethyst::core::bundle::{ECSBundle, Result};
+use amethyst::core::schedule::ScheduleBuilder;
 use amethyst::core::timing::Time;
-use amethyst::ecs::{DispatcherBuilder, World};
+use amethyst::ecs::World;
 use systems::{BounceSystem, MoveBallsSystem, PaddleSystem, WinnerSystem};
 
 /// A bundle is a convenient way to initialise related resources, components and systems in a
@@ -12,8 +13,8 @@ impl<'a, 'b> ECSBundle<'a, 'b> for PongBundle {
     fn build(
         self,
         world: &mut World,
-        builder: DispatcherBuilder<'a, 'b>,
-    ) -> Result<DispatcherBuilder<'a, 'b>> {
+        builder: ScheduleBuilder<'a, 'b>,
+    ) -> Result<ScheduleBuilder<'a, 'b>> {
         world.add_resource(ScoreBoard::new());
         world.add_resource(Time::default());
         world.register::<Ball>();
@@ -13,12 +13,13 @@ const PADDLE_COLOUR: [f32; 4] = [0.0, 0.0, 1.0, 1.0];
 pub struct Pong;
 
 impl State for Pong {
-    fn on_start(&mut self, world: &mut World) {
+    fn on_start(&mut self, data: StateData) {
+        let StateData { world, .. } = data;
         world.register::<Paddle>();
         initialise_paddles(world);
         initialise_camera(world);
     }
-    fn handle_event(&mut self, _: &mut World, event: Event) -> Trans {
+    fn handle_event(&mut self, _: StateData, event: Event) -> Trans {
         match event {
             Event::WindowEvent { event, .. } => match event {
                 WindowEvent::KeyboardInput {
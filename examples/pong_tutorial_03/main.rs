@@ -31,7 +31,7 @@ fn run() -> Result<()> {
     );
 
     let input_bundle = InputBundle::<String, String>::new()
-      .with_bindings_from_file(binding_path);
+      .with_bindings_from_file(binding_path)?;
 
     let mut game = Application::build("./", Pong)?
         .with_bundle(TransformBundle::new())?
@@ -5,7 +5,7 @@
 
 extern crate amethyst;
 
-use amethyst::{Application, Error, State, Trans};
+use amethyst::{Application, Error, State, StateData, Trans};
 use amethyst::assets::{HotReloadBundle, Loader};
 use amethyst::config::Config;
 use amethyst::core::cgmath::{Array, Deg, Euler, Quaternion, Rad, Rotation, Rotation3, Vector3};
@@ -92,7 +92,8 @@ fn run(&mut self, (mut lights, time, camera, mut transforms, mut state, mut ui_t
 struct Example;
 
 impl State for Example {
-    fn on_start(&mut self, world: &mut World) {
+    fn on_start(&mut self, data: StateData) {
+        let StateData { world, .. } = data;
         initialise_camera(world);
 
         let assets = load_assets(&world);
@@ -212,7 +213,8 @@ impl State for Example {
         });
     }
 
-    fn handle_event(&mut self, world: &mut World, event: Event) -> Trans {
+    fn handle_event(&mut self, data: StateData, event: Event) -> Trans {
+        let StateData { world, .. } = data;
         let w = world;
         // Exit if user hits Escape or closes the window
         let mut state = w.write_resource::<DemoState>();
@@ -382,12 +384,13 @@ fn run() -> Result<(), Error> {
     );
 
     let display_config = DisplayConfig::load(display_config_path);
-    let pipeline_builder = Pipeline::build().with_stage(
-        Stage::with_backbuffer()
-            .clear_target([0.0, 0.0, 0.0, 1.0], 1.0)
-            .with_pass(DrawShaded::<PosNormTex>::new())
-            .with_pass(DrawUi::new()),
-    );
+    let pipeline_builder = Pipeline::build()
+        .with_stage(
+            Stage::with_backbuffer()
+                .clear_target([0.0, 0.0, 0.0, 1.0], 1.0)
+                .with_pass(DrawShaded::<PosNormTex>::new()),
+        )
+        .with_stage(DrawUi::stage());
     let mut game = Application::build(resources_directory, Example)?
         .with::<ExampleSystem>(ExampleSystem, "example_system", &[])
         .with_frame_limit(FrameRateLimitStrategy::Unlimited, 0)
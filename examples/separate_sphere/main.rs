@@ -23,14 +23,15 @@ const LIGHT_INTENSITY: f32 = 3.0;
 struct Example;
 
 impl State for Example {
-    fn on_start(&mut self, world: &mut World) {
+    fn on_start(&mut self, data: StateData) {
+        let StateData { world, .. } = data;
         // Initialise the scene with an object, a light and a camera.
         initialise_sphere(world);
         initialise_lights(world);
         initialise_camera(world);
     }
 
-    fn handle_event(&mut self, _: &mut World, event: Event) -> Trans {
+    fn handle_event(&mut self, _: StateData, event: Event) -> Trans {
         match event {
             Event::WindowEvent { event, .. } => match event {
                 WindowEvent::KeyboardInput {
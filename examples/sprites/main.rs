@@ -23,8 +23,9 @@ use amethyst::ecs::Entity;
 use amethyst::input::InputBundle;
 use amethyst::prelude::*;
 use amethyst::renderer::{Camera, ColorMask, DisplayConfig, DrawFlat, Event, KeyboardInput,
-                         Material, MaterialDefaults, Mesh, Pipeline, PosTex, Projection,
-                         RenderBundle, ScreenDimensions, Stage, VirtualKeyCode, WindowEvent, ALPHA};
+                         Material, MaterialDefaults, Mesh, Pipeline, PosTex, RenderBundle,
+                         ScreenCamera, ScreenDimensions, Stage, VirtualKeyCode, WindowEvent,
+                         ALPHA};
 use amethyst::ui::{DrawUi, UiBundle};
 use amethyst_animation::{get_animation_set, AnimationBundle, AnimationCommand, EndControl,
                          MaterialTextureSet};
@@ -38,7 +39,8 @@ struct Example {
 }
 
 impl State for Example {
-    fn on_start(&mut self, mut world: &mut World) {
+    fn on_start(&mut self, data: StateData) {
+        let StateData { mut world, .. } = data;
         initialise_camera(world);
 
         let sprite_sheet_texture = png_loader::load("texture/bat.32x32.png", world);
@@ -49,15 +51,16 @@ impl State for Example {
             sprite::SpriteSheetDefinition::new(sprite_w, sprite_h, 2, 6, false);
 
         let sprite_sheet_index = 0;
-        let sprite_sheet = sprite_sheet_loader::load(sprite_sheet_index, &sprite_sheet_definition);
-
-        let sprite_sheet_material = {
-            let mat_defaults = world.read_resource::<MaterialDefaults>();
-            Material {
-                albedo: sprite_sheet_texture.clone(),
-                ..mat_defaults.0.clone()
-            }
-        };
+        let sprite_sheet = sprite_sheet_loader::load(
+            sprite_sheet_index,
+            sprite_sheet_texture,
+            &sprite_sheet_definition,
+        );
+
+        // The default `Material`, showing the first sprite of the sheet.
+        let sprite_sheet_material = sprite_sheet
+            .material(0, &world.read_resource::<MaterialDefaults>())
+            .expect("Sprite sheet has a texture and sprites");
 
         // Load animations
         let grey_bat_animation = animation::grey_bat(&sprite_sheet, &mut world);
@@ -84,10 +87,10 @@ impl State for Example {
         );
 
         // Store sprite sheet texture in the world's `MaterialTextureSet` resource (singleton hash
-        // map)
+        // map), so the animations can find it by the sheet's index
         world
             .write_resource::<MaterialTextureSet>()
-            .insert(sprite_sheet_index, sprite_sheet_texture);
+            .insert_sprite_sheet(&sprite_sheet);
 
         // Create an entity per sprite.
         for i in 0..sprite_count {
@@ -146,7 +149,7 @@ impl State for Example {
         }
     }
 
-    fn handle_event(&mut self, _: &mut World, event: Event) -> Trans {
+    fn handle_event(&mut self, _: StateData, event: Event) -> Trans {
         match event {
             Event::WindowEvent { event, .. } => match event {
                 WindowEvent::KeyboardInput {
@@ -173,12 +176,8 @@ fn initialise_camera(world: &mut World) -> Entity {
     };
     world
         .create_entity()
-        .with(Camera::from(Projection::orthographic(
-            0.0,
-            width,
-            height,
-            0.0,
-        )))
+        .with(Camera::screen_2d(width, height))
+        .with(ScreenCamera::new())
         .with(GlobalTransform(Matrix4::from_translation(
             Vector3::new(0.0, 0.0, 1.0).into(),
         )))
@@ -194,12 +193,15 @@ fn run() -> Result<(), amethyst::Error> {
     let assets_directory = format!("{}/examples/assets/", env!("CARGO_MANIFEST_DIR"));
     let config = DisplayConfig::load(&path);
 
-    let pipe = Pipeline::build().with_stage(
-        Stage::with_backbuffer()
-            .clear_target(BACKGROUND_COLOUR, 1.0)
-            .with_pass(DrawFlat::<PosTex>::new().with_transparency(ColorMask::all(), ALPHA, None))
-            .with_pass(DrawUi::new()),
-    );
+    let pipe = Pipeline::build()
+        .with_stage(
+            Stage::with_backbuffer()
+                .clear_target(BACKGROUND_COLOUR, 1.0)
+                .with_pass(
+                    DrawFlat::<PosTex>::new().with_transparency(ColorMask::all(), ALPHA, None),
+                ),
+        )
+        .with_stage(DrawUi::stage());
 
     let mut game = Application::build(assets_directory, Example::default())?
         // RenderBundle gives us a window
@@ -21,3 +21,22 @@ where
         &world.read_resource::<AssetStorage<Texture>>(),
     )
 }
+
+/// Returns a `TextureHandle` to an image already in memory, such as one embedded in the binary
+/// with `include_bytes!`.
+///
+/// # Parameters
+///
+/// * `bytes`: Content of the PNG file.
+/// * `world`: `World` that stores resources.
+#[allow(dead_code)]
+pub fn load_bytes(bytes: &[u8], world: &World) -> TextureHandle {
+    let loader = world.read_resource::<Loader>();
+    loader.load_from_bytes(
+        bytes,
+        PngFormat,
+        Default::default(),
+        (),
+        &world.read_resource::<AssetStorage<Texture>>(),
+    )
+}
@@ -1,4 +1,4 @@
-use amethyst::renderer::{Sprite, SpriteSheet};
+use amethyst::renderer::{Sprite, SpriteSheet, TextureHandle};
 
 use sprite;
 
@@ -7,8 +7,13 @@ use sprite;
 /// # Parameters:
 ///
 /// * `index`: Index of the sprite sheet's texture in the `MaterialTextureSet`.
+/// * `texture`: Handle of the sprite sheet's texture.
 /// * `definition`: Definition of the sprite layout on the sprite sheet.
-pub fn load(index: usize, definition: &sprite::SpriteSheetDefinition) -> SpriteSheet {
+pub fn load(
+    index: usize,
+    texture: TextureHandle,
+    definition: &sprite::SpriteSheetDefinition,
+) -> SpriteSheet {
     let mut sprites = Vec::with_capacity(definition.row_count * definition.column_count);
     let (offset_w, offset_h) = offset_distances(&definition);
     let (image_w, image_h) = (
@@ -46,7 +51,7 @@ pub fn load(index: usize, definition: &sprite::SpriteSheetDefinition) -> SpriteS
         }
     }
 
-    SpriteSheet { index, sprites }
+    SpriteSheet::with_texture(index, texture, sprites)
 }
 
 /// Returns the pixel offset distances per sprite.
@@ -38,7 +38,8 @@ struct Example {
 }
 
 impl State for Example {
-    fn on_start(&mut self, world: &mut World) {
+    fn on_start(&mut self, data: StateData) {
+        let StateData { world, .. } = data;
         // Initialise the scene with an object, a light and a camera.
         initialise_sphere(world);
         initialise_lights(world);
@@ -237,7 +238,8 @@ impl State for Example {
         world.write_resource::<UiFocused>().entity = Some(text);
     }
 
-    fn update(&mut self, world: &mut World) -> Trans {
+    fn update(&mut self, data: StateData) -> Trans {
+        let StateData { world, .. } = data;
         let mut ui_text = world.write::<UiText>();
         if let Some(fps_display) = self.fps_display.and_then(|entity| ui_text.get_mut(entity)) {
             if world.read_resource::<Time>().frame_number() % 20 == 0 {
@@ -249,7 +251,7 @@ impl State for Example {
         Trans::None
     }
 
-    fn handle_event(&mut self, _: &mut World, event: Event) -> Trans {
+    fn handle_event(&mut self, _: StateData, event: Event) -> Trans {
         match event {
             Event::WindowEvent { event, .. } => match event {
                 WindowEvent::KeyboardInput {
@@ -276,12 +278,13 @@ fn run() -> Result<(), amethyst::Error> {
     let resources = format!("{}/examples/assets", env!("CARGO_MANIFEST_DIR"));
     let config = DisplayConfig::load(&display_config_path);
     let pipe = {
-        Pipeline::build().with_stage(
-            Stage::with_backbuffer()
-                .clear_target(BACKGROUND_COLOUR, 1.0)
-                .with_pass(DrawShaded::<PosNormTex>::new())
-                .with_pass(DrawUi::new()),
-        )
+        Pipeline::build()
+            .with_stage(
+                Stage::with_backbuffer()
+                    .clear_target(BACKGROUND_COLOUR, 1.0)
+                    .with_pass(DrawShaded::<PosNormTex>::new()),
+            )
+            .with_stage(DrawUi::stage())
     };
     let mut game = Application::build(resources, Example { fps_display: None })?
         .with_bundle(UiBundle::<String, String>::new())?
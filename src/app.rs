@@ -3,9 +3,9 @@
 use std::io;
 use std::path::Path;
 use std::sync::Arc;
-use std::time::Duration;
+use std::time::{Duration, Instant};
 
-use core::ECSBundle;
+use core::{CommandBuffer, ECSBundle};
 use fern;
 use log::LevelFilter;
 use rayon::ThreadPool;
@@ -17,11 +17,13 @@ use winit::{Event, WindowEvent};
 
 use assets::{Asset, Loader, Source};
 use core::frame_limiter::{FrameLimiter, FrameRateLimitConfig, FrameRateLimitStrategy};
-use core::timing::{Stopwatch, Time};
-use ecs::{Component, Dispatcher, DispatcherBuilder, System, World};
+use core::{Schedule, ScheduleBuilder};
+use core::rng::Rng;
+use core::timing::{StepMode, Stopwatch, Time};
+use ecs::{Component, Dispatcher, System, World};
 use ecs::common::Errors;
 use error::{Error, Result};
-use state::{State, StateMachine};
+use state::{State, StateData, StateMachine};
 use vergen;
 
 /// An Application is the root object of the game engine. It binds the OS
@@ -30,17 +32,23 @@ use vergen;
 /// Since Application functions as the root of the game, Amethyst does not need
 /// to use any global variables. Within this object is everything that your
 /// game needs to run.
+///
+/// `T` is the type of the data given to the states with the world, see
+/// `ApplicationBuilder::with_state_data`.
 #[derive(Derivative)]
-#[derivative(Debug)]
-pub struct Application<'a, 'b> {
+#[derivative(Debug(bound = ""))]
+pub struct Application<'a, 'b, T = ()> {
     /// The world
     #[derivative(Debug = "ignore")]
     pub world: World,
 
     #[derivative(Debug = "ignore")]
     dispatcher: Dispatcher<'a, 'b>,
+    schedule: Schedule,
     events_reader_id: ReaderId<Event>,
-    states: StateMachine<'a>,
+    states: StateMachine<'a, T>,
+    #[derivative(Debug = "ignore")]
+    data: T,
     ignore_window_close: bool,
 }
 
@@ -108,10 +116,55 @@ impl<'a, 'b> Application<'a, 'b> {
     pub fn build<P, S>(path: P, initial_state: S) -> Result<ApplicationBuilder<'a, 'b, S>>
     where
         P: AsRef<Path>,
-        S: State + 'a,
+        S: 'a,
     {
         ApplicationBuilder::new(path, initial_state)
     }
+}
+
+impl<'a, 'b, T> Application<'a, 'b, T> {
+    /// Returns the dependency graph of the systems in the DOT format of Graphviz, to check the
+    /// order they run in.
+    ///
+    /// Each system points to the systems depending on it, through explicit dependencies or
+    /// barriers. The systems added by bundles are recorded too, thread-local systems are
+    /// unnamed.
+    ///
+    /// # Examples
+    ///
+    /// ~~~no_run
+    /// use std::fs::File;
+    /// use std::io::Write;
+    ///
+    /// use amethyst::core::transform::TransformBundle;
+    /// use amethyst::prelude::*;
+    /// use amethyst::ecs::System;
+    ///
+    /// struct NullState;
+    /// impl State for NullState {}
+    ///
+    /// struct NopSystem;
+    /// impl<'a> System<'a> for NopSystem {
+    ///     type SystemData = ();
+    ///     fn run(&mut self, _: Self::SystemData) {}
+    /// }
+    ///
+    /// let game = Application::build("assets/", NullState)
+    ///     .expect("Failed to initialize")
+    ///     .with_bundle(TransformBundle::new())
+    ///     .expect("Failed to add transform bundle")
+    ///     .with(NopSystem, "follow_camera", &["transform_system"])
+    ///     .build()
+    ///     .expect("Failed to create Application");
+    ///
+    /// // Render with `dot -Tpng schedule.dot -o schedule.png`
+    /// File::create("schedule.dot")
+    ///     .and_then(|mut file| file.write_all(game.schedule_dot().as_bytes()))
+    ///     .expect("Failed to write the schedule");
+    /// ~~~
+    pub fn schedule_dot(&self) -> String {
+        self.schedule.to_dot()
+    }
 
     /// Run the gameloop until the game state indicates that the game is no
     /// longer running. This is done via the `State` returning `Trans::Quit` or
@@ -131,9 +184,17 @@ impl<'a, 'b> Application<'a, 'b> {
             self.world.write_resource::<FrameLimiter>().wait();
             {
                 let elapsed = self.world.read_resource::<Stopwatch>().elapsed();
+                let mut step_mode = self.world.write_resource::<StepMode>();
                 let mut time = self.world.write_resource::<Time>();
                 time.increment_frame_number();
-                time.set_delta_time(elapsed);
+                if !step_mode.next_frame() {
+                    time.set_delta_time(Duration::from_secs(0));
+                } else if step_mode.is_stepping() {
+                    let fixed = time.fixed_time();
+                    time.set_delta_time(fixed);
+                } else {
+                    time.set_delta_time(elapsed);
+                }
             }
             let mut stopwatch = self.world.write_resource::<Stopwatch>();
             stopwatch.stop();
@@ -147,7 +208,8 @@ impl<'a, 'b> Application<'a, 'b> {
     fn initialize(&mut self) {
         #[cfg(feature = "profiler")]
         profile_scope!("initialize");
-        self.states.start(&mut self.world);
+        self.states
+            .start(StateData::new(&mut self.world, &mut self.data));
     }
 
     /// Advances the game world by one tick.
@@ -156,6 +218,7 @@ impl<'a, 'b> Application<'a, 'b> {
 
         {
             let world = &mut self.world;
+            let data = &mut self.data;
             let states = &mut self.states;
             #[cfg(feature = "profiler")]
             profile_scope!("handle_event");
@@ -167,38 +230,52 @@ impl<'a, 'b> Application<'a, 'b> {
                 .collect::<Vec<_>>();
 
             for event in events {
-                states.handle_event(world, event.clone());
+                states.handle_event(StateData::new(world, data), event.clone());
                 if !self.ignore_window_close {
                     if let &Event::WindowEvent {
                         event: WindowEvent::Closed,
                         ..
                     } = &event
                     {
-                        states.stop(world);
+                        states.close_requested(StateData::new(world, data));
                     }
                 }
             }
         }
-        {
+        let (advancing, stepping) = {
+            let step_mode = self.world.read_resource::<StepMode>();
+            (step_mode.is_advancing(), step_mode.is_stepping())
+        };
+        if advancing {
             let do_fixed = {
-                let time = self.world.write_resource::<Time>();
+                let mut time = self.world.write_resource::<Time>();
+                if stepping {
+                    // A step runs exactly one fixed update, however long the game was paused.
+                    time.last_fixed_update = Instant::now() - time.fixed_time();
+                }
                 time.last_fixed_update().elapsed() >= time.fixed_time()
             };
             #[cfg(feature = "profiler")]
             profile_scope!("fixed_update");
             if do_fixed {
-                self.states.fixed_update(&mut self.world);
+                self.states
+                    .fixed_update(StateData::new(&mut self.world, &mut self.data));
                 self.world.write_resource::<Time>().finish_fixed_update();
             }
 
             #[cfg(feature = "profiler")]
             profile_scope!("update");
-            self.states.update(&mut self.world);
+            self.states
+                .update(StateData::new(&mut self.world, &mut self.data));
+        } else {
+            // Don't pile up fixed updates to catch up with once the game resumes.
+            self.world.write_resource::<Time>().last_fixed_update = Instant::now();
         }
 
         #[cfg(feature = "profiler")]
         profile_scope!("dispatch");
         self.dispatcher.dispatch(&mut self.world.res);
+        CommandBuffer::apply(&mut self.world);
 
         #[cfg(feature = "profiler")]
         profile_scope!("maintain");
@@ -219,7 +296,7 @@ impl<'a, 'b> Application<'a, 'b> {
 }
 
 #[cfg(feature = "profiler")]
-impl<'a, 'b> Drop for Application<'a, 'b> {
+impl<'a, 'b, T> Drop for Application<'a, 'b, T> {
     fn drop(&mut self) {
         // TODO: Specify filename in config.
         let path = format!("{}/thread_profile.json", env!("CARGO_MANIFEST_DIR"));
@@ -230,10 +307,11 @@ impl<'a, 'b> Drop for Application<'a, 'b> {
 /// `ApplicationBuilder` is an interface that allows for creation of an [`Application`](struct.Application.html)
 /// using a custom set of configuration. This is the normal way an [`Application`](struct.Application.html)
 /// object is created.
-pub struct ApplicationBuilder<'a, 'b, T> {
+pub struct ApplicationBuilder<'a, 'b, T, D = ()> {
     // config: Config,
-    disp_builder: DispatcherBuilder<'a, 'b>,
+    disp_builder: ScheduleBuilder<'a, 'b>,
     initial_state: T,
+    data: D,
     /// Used by bundles to access the world directly
     pub world: World,
     ignore_window_close: bool,
@@ -333,18 +411,20 @@ impl<'a, 'b, T> ApplicationBuilder<'a, 'b, T> {
             info!("Rustc git commit: {}", hash);
         }
 
-        let mut disp_builder = DispatcherBuilder::new();
         let mut world = World::new();
-        disp_builder = AppBundle::new(path).build(&mut world, disp_builder)?;
+        let disp_builder = AppBundle::new(path).build(&mut world, ScheduleBuilder::new())?;
 
         Ok(ApplicationBuilder {
             disp_builder,
             initial_state,
+            data: (),
             world,
             ignore_window_close: false,
         })
     }
+}
 
+impl<'a, 'b, T, D> ApplicationBuilder<'a, 'b, T, D> {
     /// Registers a component into the entity-component-system. This method
     /// takes no options other than the component type which is defined
     /// using a 'turbofish'. See the example for what this looks like.
@@ -550,6 +630,38 @@ impl<'a, 'b, T> ApplicationBuilder<'a, 'b, T> {
     ///     // It is legal to register a system with an empty name
     ///     .with(NopSystem, "", &[]);
     /// ~~~
+    ///
+    /// Systems added by bundles have names too, so custom systems can be ordered relative to
+    /// the engine systems. Bundles taking dependencies, like `TransformBundle::with_dep`, can
+    /// in turn run after custom systems, as long as they are added after them. Thread-local
+    /// systems, such as the render system, always run after all other systems.
+    ///
+    /// ~~~no_run
+    /// use amethyst::core::transform::TransformBundle;
+    /// use amethyst::input::InputBundle;
+    /// use amethyst::prelude::*;
+    /// use amethyst::ecs::System;
+    ///
+    /// struct NullState;
+    /// impl State for NullState {}
+    ///
+    /// struct NopSystem;
+    /// impl<'a> System<'a> for NopSystem {
+    ///     type SystemData = ();
+    ///     fn run(&mut self, _: Self::SystemData) {}
+    /// }
+    ///
+    /// Application::build("assets/", NullState)
+    ///     .expect("Failed to initialize")
+    ///     // Adds the "input_system"
+    ///     .with_bundle(InputBundle::<String, String>::new())
+    ///     .expect("Failed to add input bundle")
+    ///     .with(NopSystem, "movement", &["input_system"])
+    ///     .with(NopSystem, "collision", &["movement"])
+    ///     // The "transform_system" will use the moved and collided positions
+    ///     .with_bundle(TransformBundle::new().with_dep(&["collision"]))
+    ///     .expect("Failed to add transform bundle");
+    /// ~~~
     pub fn with<S>(mut self, system: S, name: &str, dependencies: &[&str]) -> Self
     where
         for<'c> S: System<'c> + Send + 'a,
@@ -677,7 +789,8 @@ impl<'a, 'b, T> ApplicationBuilder<'a, 'b, T> {
     ///
     /// struct LoadingState;
     /// impl State for LoadingState {
-    ///     fn on_start(&mut self, world: &mut World) {
+    ///     fn on_start(&mut self, data: StateData) {
+    ///         let StateData { world, .. } = data;
     ///         let storage = world.read_resource();
     ///
     ///         let loader = world.read_resource::<Loader>();
@@ -743,9 +856,26 @@ impl<'a, 'b, T> ApplicationBuilder<'a, 'b, T> {
         self
     }
 
+    /// Seeds the `Rng` resource, which is otherwise seeded from the system clock.
+    ///
+    /// Using the same seed makes every system drawing from the `Rng` resource deterministic.
+    ///
+    /// # Parameters
+    ///
+    /// `seed`: The seed of the random number generator.
+    ///
+    /// # Returns
+    ///
+    /// This function returns the ApplicationBuilder after modifying it.
+    pub fn with_rng_seed(mut self, seed: u64) -> Self {
+        self.world.add_resource(Rng::new(seed));
+        self
+    }
+
     /// Tells the resulting application window to ignore close events if ignore is true.
     /// This will make your game window unresponsive to operating system close commands.
-    /// Use with caution.
+    /// Use with caution, to ask for a confirmation before quitting see
+    /// `State::on_close_requested` instead.
     ///
     /// # Parameters
     ///
@@ -759,6 +889,52 @@ impl<'a, 'b, T> ApplicationBuilder<'a, 'b, T> {
         self
     }
 
+    /// Sets the data given to every callback of the states with the world, in the `StateData`.
+    ///
+    /// The data is shared by all the states, for what they need besides the resources of the
+    /// world, such as the progress of the player. The states then implement `State<D>`.
+    ///
+    /// # Parameters
+    ///
+    /// `data`: The data of the application.
+    ///
+    /// # Returns
+    ///
+    /// This function returns the ApplicationBuilder after modifying it.
+    ///
+    /// # Examples
+    ///
+    /// ~~~no_run
+    /// use amethyst::prelude::*;
+    ///
+    /// struct Progress {
+    ///     level: u32,
+    /// }
+    ///
+    /// struct Level;
+    /// impl State<Progress> for Level {
+    ///     fn on_start(&mut self, data: StateData<Progress>) {
+    ///         println!("Starting level {}", data.data.level);
+    ///     }
+    /// }
+    ///
+    /// let mut game = Application::build("assets/", Level)
+    ///     .expect("Failed to initialize")
+    ///     .with_state_data(Progress { level: 1 })
+    ///     .build()
+    ///     .expect("Failed to create Application");
+    /// game.run();
+    /// ~~~
+    pub fn with_state_data<E>(self, data: E) -> ApplicationBuilder<'a, 'b, T, E> {
+        ApplicationBuilder {
+            disp_builder: self.disp_builder,
+            initial_state: self.initial_state,
+            data,
+            world: self.world,
+            ignore_window_close: self.ignore_window_close,
+        }
+    }
+
     /// Register a new asset type with the Application. All required components
     /// related to the storage of this asset type will be registered. Since
     /// Amethyst uses AssetFutures to allow for async content loading, Amethyst
@@ -816,9 +992,9 @@ impl<'a, 'b, T> ApplicationBuilder<'a, 'b, T> {
     ///
     /// See the [example show for `ApplicationBuilder::new()`](struct.ApplicationBuilder.html#examples)
     /// for an example on how this method is used.
-    pub fn build(self) -> Result<Application<'a, 'b>>
+    pub fn build(self) -> Result<Application<'a, 'b, D>>
     where
-        T: State + 'a,
+        T: State<D> + 'a,
     {
         trace!("Entering `ApplicationBuilder::build`");
 
@@ -832,16 +1008,19 @@ impl<'a, 'b, T> ApplicationBuilder<'a, 'b, T> {
         let reader_id = self.world
             .write_resource::<EventChannel<Event>>()
             .register_reader();
+        let (disp_builder, schedule) = self.disp_builder.into_parts();
 
         Ok(Application {
             world: self.world,
             // config: self.config,
             states: StateMachine::new(self.initial_state),
+            data: self.data,
             events_reader_id: reader_id,
             #[cfg(not(no_threading))]
-            dispatcher: self.disp_builder.with_pool(pool).build(),
+            dispatcher: disp_builder.with_pool(pool).build(),
             #[cfg(no_threading)]
-            dispatcher: self.disp_builder.build(),
+            dispatcher: disp_builder.build(),
+            schedule,
             ignore_window_close: self.ignore_window_close,
         })
     }
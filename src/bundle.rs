@@ -3,9 +3,9 @@ use std::sync::Arc;
 use std::time::Duration;
 
 use assets::Loader;
-use core::{ECSBundle, Result, Stopwatch, Time};
+use core::{CommandBuffer, ECSBundle, Result, Rng, ScheduleBuilder, StepMode, Stopwatch, Time};
 use core::frame_limiter::FrameLimiter;
-use ecs::{DispatcherBuilder, World};
+use ecs::World;
 use ecs::common::Errors;
 use rayon::{Configuration, ThreadPool};
 use renderer::Event;
@@ -29,8 +29,8 @@ impl<'a, 'b> ECSBundle<'a, 'b> for AppBundle {
     fn build(
         self,
         world: &mut World,
-        dispatcher: DispatcherBuilder<'a, 'b>,
-    ) -> Result<DispatcherBuilder<'a, 'b>> {
+        dispatcher: ScheduleBuilder<'a, 'b>,
+    ) -> Result<ScheduleBuilder<'a, 'b>> {
         let cfg = Configuration::new();
         #[cfg(feature = "profiler")]
         let cfg = cfg.start_handler(|index| {
@@ -45,6 +45,9 @@ impl<'a, 'b> ECSBundle<'a, 'b> for AppBundle {
         world.add_resource(pool);
         world.add_resource(FrameLimiter::default());
         world.add_resource(Stopwatch::default());
+        world.add_resource(StepMode::default());
+        world.add_resource(Rng::default());
+        world.add_resource(CommandBuffer::new());
         let mut time = Time::default();
         time.set_fixed_time(Duration::new(0, 16666666));
         world.add_resource(time);
@@ -52,6 +52,12 @@ impl Display for Error {
     }
 }
 
+impl From<ConfigError> for Error {
+    fn from(e: ConfigError) -> Self {
+        Error::Config(e)
+    }
+}
+
 impl From<core::Error> for Error {
     fn from(e: core::Error) -> Self {
         Error::Core(e)
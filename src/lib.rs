@@ -20,11 +20,11 @@
 //! struct GameState;
 //!
 //! impl State for GameState {
-//!     fn on_start(&mut self, _: &mut World) {
+//!     fn on_start(&mut self, _: StateData) {
 //!         println!("Starting game!");
 //!     }
 //!
-//!     fn handle_event(&mut self, _: &mut World, event: Event) -> Trans {
+//!     fn handle_event(&mut self, _: StateData, event: Event) -> Trans {
 //!         match event {
 //!             Event::WindowEvent { event, .. } => match event {
 //!                 WindowEvent::KeyboardInput {
@@ -37,7 +37,7 @@
 //!         }
 //!     }
 //!
-//!     fn update(&mut self, _: &mut World) -> Trans {
+//!     fn update(&mut self, _: StateData) -> Trans {
 //!         println!("Computing some more whoop-ass...");
 //!         Trans::Quit
 //!     }
@@ -78,7 +78,7 @@ extern crate rustc_version_runtime;
 
 pub use self::app::{Application, ApplicationBuilder};
 pub use self::error::{Error, Result};
-pub use self::state::{State, StateMachine, Trans};
+pub use self::state::{State, StateData, StateMachine, StateResult, Trans};
 pub use core::shred;
 pub use core::specs as ecs;
 
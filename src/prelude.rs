@@ -1,8 +1,45 @@
 //! Contains common types that can be glob-imported (`*`) for convenience.
+//!
+//! Besides the application types, this contains what is needed to write most systems:
+//!
+//! ```rust,ignore
+//! use amethyst::prelude::*;
+//!
+//! struct Velocity(f32, f32);
+//!
+//! impl Component for Velocity {
+//!     type Storage = VecStorage<Self>;
+//! }
+//!
+//! struct MovementSystem;
+//!
+//! impl<'a> System<'a> for MovementSystem {
+//!     type SystemData = (
+//!         Fetch<'a, Time>,
+//!         ReadStorage<'a, Velocity>,
+//!         WriteStorage<'a, Transform>,
+//!         ReadStorage<'a, Disabled>,
+//!     );
+//!
+//!     fn run(&mut self, (time, velocities, mut transforms, disabled): Self::SystemData) {
+//!         let delta = time.delta_seconds();
+//!         // Use `for_each_enabled` instead for cheap loops over few entities.
+//!         let moving = (&velocities, &mut transforms);
+//!         par_for_each_enabled(moving, &disabled, |(velocity, transform)| {
+//!             transform.translation.x += velocity.0 * delta;
+//!             transform.translation.y += velocity.1 * delta;
+//!         });
+//!     }
+//! }
+//! ```
 
 pub use app::{Application, ApplicationBuilder};
 pub use config::Config;
-pub use ecs::World;
+pub use core::{for_each_enabled, par_for_each_enabled, Disabled, GlobalTransform, Time,
+               Transform};
+pub use ecs::{Component, DenseVecStorage, Entities, Entity, Fetch, FetchMut, Join, NullStorage,
+              ParJoin, ReadStorage, System, VecStorage, World, WriteStorage};
+pub use rayon::iter::ParallelIterator;
 //pub use renderer::input::*;
 
-pub use state::{State, Trans};
+pub use state::{State, StateData, StateResult, Trans};
@@ -1,66 +1,224 @@
 //! Utilities for game state management.
 
+use std::any::Any;
+
 use ecs::World;
 use renderer::Event;
 
+/// Data given back by a state removed with `Trans::PopWith` to the state it resumes.
+///
+/// # Examples
+///
+/// ~~~no_run
+/// use amethyst::prelude::*;
+///
+/// struct Inventory;
+///
+/// struct ItemPicker;
+///
+/// impl State for ItemPicker {
+///     fn update(&mut self, _: StateData) -> Trans {
+///         Trans::PopWith(StateResult::new(String::from("sword")))
+///     }
+/// }
+///
+/// impl State for Inventory {
+///     fn on_resume(&mut self, _: StateData, result: Option<StateResult>) {
+///         if let Some(Ok(item)) = result.map(|result| result.downcast::<String>()) {
+///             println!("Picked {}", item);
+///         }
+///     }
+/// }
+/// ~~~
+pub struct StateResult(Box<Any>);
+
+impl StateResult {
+    /// Creates a result carrying the given value.
+    pub fn new<T: Any>(value: T) -> Self {
+        StateResult(Box::new(value))
+    }
+
+    /// Checks whether the result carries a value of type `T`.
+    pub fn is<T: Any>(&self) -> bool {
+        self.0.is::<T>()
+    }
+
+    /// Takes the value out of the result, or gives the result back if it isn't of type `T`.
+    pub fn downcast<T: Any>(self) -> Result<T, StateResult> {
+        self.0.downcast().map(|value| *value).map_err(StateResult)
+    }
+}
+
+/// The data given to every `State` callback: the world, and the data of the application.
+///
+/// The data is set with `ApplicationBuilder::with_state_data`, and shared by all the states of
+/// the application. The `Time` and the other resources are in the world.
+///
+/// # Examples
+///
+/// ~~~no_run
+/// use amethyst::prelude::*;
+///
+/// struct Scores {
+///     best: u32,
+/// }
+///
+/// struct Results {
+///     score: u32,
+/// }
+///
+/// impl State<Scores> for Results {
+///     fn on_start(&mut self, data: StateData<Scores>) {
+///         data.data.best = data.data.best.max(self.score);
+///         let frame = data.world.read_resource::<Time>().frame_number();
+///         println!("Best score: {}, at frame {}", data.data.best, frame);
+///     }
+/// }
+/// ~~~
+pub struct StateData<'a, T: 'a = ()> {
+    /// The world of the application.
+    pub world: &'a mut World,
+    /// The data shared by the states of the application.
+    pub data: &'a mut T,
+}
+
+impl<'a, T> StateData<'a, T> {
+    /// Bundles the world and the data given to a state callback.
+    pub fn new(world: &'a mut World, data: &'a mut T) -> Self {
+        StateData { world, data }
+    }
+}
+
 /// Types of state transitions.
-pub enum Trans {
+///
+/// The states given to `Push` and `Switch` are constructed by the current state, so they can carry
+/// any data they need from it. Data shared by several states is better stored in the data of the
+/// application or as a resource in the `World`, both given to every state callback in the
+/// `StateData`.
+///
+/// # Examples
+///
+/// ~~~no_run
+/// use amethyst::prelude::*;
+///
+/// struct Game {
+///     score: u32,
+/// }
+///
+/// struct Results {
+///     score: u32,
+/// }
+///
+/// impl State for Game {
+///     fn update(&mut self, _: StateData) -> Trans {
+///         // Carry the final score to the results screen.
+///         Trans::Switch(Box::new(Results { score: self.score }))
+///     }
+/// }
+///
+/// impl State for Results {
+///     fn on_start(&mut self, _: StateData) {
+///         println!("Final score: {}", self.score);
+///     }
+/// }
+/// ~~~
+pub enum Trans<T = ()> {
     /// Continue as normal.
     None,
     /// Remove the active state and resume the next state on the stack or stop
     /// if there are none.
     Pop,
+    /// Like `Pop`, giving the result to the `on_resume` of the next state on the stack.
+    PopWith(StateResult),
     /// Pause the active state and push a new state onto the stack.
-    Push(Box<State>),
+    Push(Box<State<T>>),
     /// Remove the current state on the stack and insert a different one.
-    Switch(Box<State>),
+    Switch(Box<State<T>>),
     /// Stop and remove all states and shut down the engine.
     Quit,
 }
 
 /// A trait which defines game states that can be used by the state machine.
-pub trait State {
+///
+/// `T` is the type of the data of the application, given to every callback with the world in
+/// the `StateData`.
+pub trait State<T = ()> {
     /// Executed when the game state begins.
-    fn on_start(&mut self, _eng: &mut World) {}
+    fn on_start(&mut self, _data: StateData<T>) {}
 
     /// Executed when the game state exits.
-    fn on_stop(&mut self, _eng: &mut World) {}
+    fn on_stop(&mut self, _data: StateData<T>) {}
 
     /// Executed when a different game state is pushed onto the stack.
-    fn on_pause(&mut self, _eng: &mut World) {}
+    fn on_pause(&mut self, _data: StateData<T>) {}
 
     /// Executed when the application returns to this game state once again.
-    fn on_resume(&mut self, _eng: &mut World) {}
+    ///
+    /// `result` is the data given by the state above with `Trans::PopWith`, if any.
+    fn on_resume(&mut self, _data: StateData<T>, _result: Option<StateResult>) {}
+
+    /// Executed when the window is asked to close, after the window event was given to
+    /// `handle_event`.
+    ///
+    /// Quits by default. Returning `Trans::None` cancels the close, to ask for a confirmation
+    /// for example, and `Trans::Push` a state showing the dialog. Not called when the
+    /// application ignores window close events.
+    ///
+    /// # Examples
+    ///
+    /// ~~~no_run
+    /// use amethyst::prelude::*;
+    ///
+    /// struct Game;
+    ///
+    /// struct ConfirmQuit;
+    ///
+    /// impl State for Game {
+    ///     fn on_close_requested(&mut self, _: StateData) -> Trans {
+    ///         Trans::Push(Box::new(ConfirmQuit))
+    ///     }
+    /// }
+    ///
+    /// impl State for ConfirmQuit {
+    ///     fn on_close_requested(&mut self, _: StateData) -> Trans {
+    ///         // Closing the window again while asked for confirmation quits.
+    ///         Trans::Quit
+    ///     }
+    /// }
+    /// ~~~
+    fn on_close_requested(&mut self, _data: StateData<T>) -> Trans<T> {
+        Trans::Quit
+    }
 
     /// Executed on every frame before updating, for use in reacting to events.
-    fn handle_event(&mut self, _eng: &mut World, _event: Event) -> Trans {
+    fn handle_event(&mut self, _data: StateData<T>, _event: Event) -> Trans<T> {
         Trans::None
     }
 
     /// Executed repeatedly at stable, predictable intervals (1/60th of a second
     /// by default).
-    fn fixed_update(&mut self, _eng: &mut World) -> Trans {
+    fn fixed_update(&mut self, _data: StateData<T>) -> Trans<T> {
         Trans::None
     }
 
     /// Executed on every frame immediately, as fast as the engine will allow.
-    fn update(&mut self, _eng: &mut World) -> Trans {
+    fn update(&mut self, _data: StateData<T>) -> Trans<T> {
         Trans::None
     }
 }
 
 /// A simple stack-based state machine (pushdown automaton).
 #[derive(Derivative)]
-#[derivative(Debug)]
-pub struct StateMachine<'a> {
+#[derivative(Debug(bound = ""))]
+pub struct StateMachine<'a, T = ()> {
     running: bool,
     #[derivative(Debug = "ignore")]
-    state_stack: Vec<Box<State + 'a>>,
+    state_stack: Vec<Box<State<T> + 'a>>,
 }
 
-impl<'a> StateMachine<'a> {
+impl<'a, T> StateMachine<'a, T> {
     /// Creates a new state machine with the given initial state.
-    pub fn new<S: State + 'a>(initial_state: S) -> StateMachine<'a> {
+    pub fn new<S: State<T> + 'a>(initial_state: S) -> StateMachine<'a, T> {
         StateMachine {
             running: false,
             state_stack: vec![Box::new(initial_state)],
@@ -76,100 +234,120 @@ impl<'a> StateMachine<'a> {
     ///
     /// # Panics
     /// Panics if no states are present in the stack.
-    pub fn start(&mut self, engine: &mut World) {
+    pub fn start(&mut self, data: StateData<T>) {
         if !self.running {
             let state = self.state_stack.last_mut().unwrap();
-            state.on_start(engine);
+            state.on_start(data);
             self.running = true;
         }
     }
 
     /// Passes a single event to the active state to handle.
-    pub fn handle_event(&mut self, engine: &mut World, event: Event) {
+    pub fn handle_event(&mut self, data: StateData<T>, event: Event) {
+        let StateData { world, data } = data;
         if self.running {
             let trans = match self.state_stack.last_mut() {
-                Some(state) => state.handle_event(engine, event),
+                Some(state) => state.handle_event(StateData::new(world, data), event),
                 None => Trans::None,
             };
 
-            self.transition(trans, engine);
+            self.transition(trans, StateData::new(world, data));
+        }
+    }
+
+    /// Tells the active state that the window is asked to close.
+    pub fn close_requested(&mut self, data: StateData<T>) {
+        let StateData { world, data } = data;
+        if self.running {
+            let trans = match self.state_stack.last_mut() {
+                Some(state) => state.on_close_requested(StateData::new(world, data)),
+                None => Trans::None,
+            };
+
+            self.transition(trans, StateData::new(world, data));
         }
     }
 
     /// Updates the currently active state at a steady, fixed interval.
-    pub fn fixed_update(&mut self, engine: &mut World) {
+    pub fn fixed_update(&mut self, data: StateData<T>) {
+        let StateData { world, data } = data;
         if self.running {
             let trans = match self.state_stack.last_mut() {
-                Some(state) => state.fixed_update(engine),
+                Some(state) => state.fixed_update(StateData::new(world, data)),
                 None => Trans::None,
             };
 
-            self.transition(trans, engine);
+            self.transition(trans, StateData::new(world, data));
         }
     }
 
     /// Updates the currently active state immediately.
-    pub fn update(&mut self, engine: &mut World) {
+    pub fn update(&mut self, data: StateData<T>) {
+        let StateData { world, data } = data;
         if self.running {
             let trans = match self.state_stack.last_mut() {
-                Some(state) => state.update(engine),
+                Some(state) => state.update(StateData::new(world, data)),
                 None => Trans::None,
             };
 
-            self.transition(trans, engine);
+            self.transition(trans, StateData::new(world, data));
         }
     }
 
     /// Performs a state transition, if requested by either update() or
     /// fixed_update().
-    fn transition(&mut self, request: Trans, engine: &mut World) {
+    fn transition(&mut self, request: Trans<T>, data: StateData<T>) {
         if self.running {
             match request {
                 Trans::None => (),
-                Trans::Pop => self.pop(engine),
-                Trans::Push(state) => self.push(state, engine),
-                Trans::Switch(state) => self.switch(state, engine),
-                Trans::Quit => self.stop(engine),
+                Trans::Pop => self.pop(data, None),
+                Trans::PopWith(result) => self.pop(data, Some(result)),
+                Trans::Push(state) => self.push(state, data),
+                Trans::Switch(state) => self.switch(state, data),
+                Trans::Quit => self.stop(data),
             }
         }
     }
 
     /// Removes the current state on the stack and inserts a different one.
-    fn switch(&mut self, state: Box<State>, engine: &mut World) {
+    fn switch(&mut self, state: Box<State<T>>, data: StateData<T>) {
+        let StateData { world, data } = data;
         if self.running {
             if let Some(mut state) = self.state_stack.pop() {
-                state.on_stop(engine);
+                state.on_stop(StateData::new(world, data));
             }
 
             self.state_stack.push(state);
             let state = self.state_stack.last_mut().unwrap();
-            state.on_start(engine);
+            state.on_start(StateData::new(world, data));
         }
     }
 
     /// Pauses the active state and pushes a new state onto the state stack.
-    fn push(&mut self, state: Box<State>, engine: &mut World) {
+    fn push(&mut self, state: Box<State<T>>, data: StateData<T>) {
+        let StateData { world, data } = data;
         if self.running {
             if let Some(state) = self.state_stack.last_mut() {
-                state.on_pause(engine);
+                state.on_pause(StateData::new(world, data));
             }
 
             self.state_stack.push(state);
             let state = self.state_stack.last_mut().unwrap();
-            state.on_start(engine);
+            state.on_start(StateData::new(world, data));
         }
     }
 
     /// Stops and removes the active state and un-pauses the next state on the
-    /// stack (if any).
-    fn pop(&mut self, engine: &mut World) {
+    /// stack (if any), giving it the result of the removed state.
+    fn pop(&mut self, data: StateData<T>, result: Option<StateResult>) {
+        let StateData { world, data } = data;
         if self.running {
             if let Some(mut state) = self.state_stack.pop() {
-                state.on_stop(engine);
+                state.on_stop(StateData::new(world, data));
             }
 
             if let Some(state) = self.state_stack.last_mut() {
-                state.on_resume(engine);
+                state.on_resume(StateData::new(world, data), result);
             } else {
                 self.running = false;
             }
@@ -177,10 +355,11 @@ impl<'a> StateMachine<'a> {
     }
 
     /// Shuts the state machine down.
-    pub(crate) fn stop(&mut self, engine: &mut World) {
+    pub(crate) fn stop(&mut self, data: StateData<T>) {
+        let StateData { world, data } = data;
         if self.running {
             while let Some(mut state) = self.state_stack.pop() {
-                state.on_stop(engine);
+                state.on_stop(StateData::new(world, data));
             }
 
             self.running = false;
@@ -196,7 +375,7 @@ mod tests {
     struct State2;
 
     impl State for State1 {
-        fn update(&mut self, _: &mut World) -> Trans {
+        fn update(&mut self, _: StateData) -> Trans {
             if self.0 > 0 {
                 self.0 -= 1;
                 Trans::None
@@ -207,7 +386,7 @@ mod tests {
     }
 
     impl State for State2 {
-        fn update(&mut self, _: &mut World) -> Trans {
+        fn update(&mut self, _: StateData) -> Trans {
             Trans::Pop
         }
     }
@@ -219,14 +398,102 @@ mod tests {
         let mut world = World::new();
 
         let mut sm = StateMachine::new(State1(7));
-        sm.start(&mut world);
+        sm.start(StateData::new(&mut world, &mut ()));
 
         for _ in 0..8 {
-            sm.update(&mut world);
+            sm.update(StateData::new(&mut world, &mut ()));
             assert!(sm.is_running());
         }
 
-        sm.update(&mut world);
+        sm.update(StateData::new(&mut world, &mut ()));
+        assert!(!sm.is_running());
+    }
+
+    struct Picker;
+    struct Picked(Option<u32>);
+
+    impl State for Picker {
+        fn update(&mut self, _: StateData) -> Trans {
+            Trans::PopWith(StateResult::new(3u32))
+        }
+    }
+
+    impl State for Picked {
+        fn update(&mut self, _: StateData) -> Trans {
+            match self.0 {
+                Some(3) => Trans::Quit,
+                _ => Trans::Push(Box::new(Picker)),
+            }
+        }
+
+        fn on_resume(&mut self, _: StateData, result: Option<StateResult>) {
+            self.0 = result.and_then(|result| result.downcast().ok());
+        }
+    }
+
+    #[test]
+    fn pop_with_result() {
+        use ecs::World;
+
+        let mut world = World::new();
+
+        let mut sm = StateMachine::new(Picked(None));
+        sm.start(StateData::new(&mut world, &mut ()));
+
+        // Push the picker, which pops itself with the result.
+        sm.update(StateData::new(&mut world, &mut ()));
+        sm.update(StateData::new(&mut world, &mut ()));
+        assert!(sm.is_running());
+
+        sm.update(StateData::new(&mut world, &mut ()));
+        assert!(!sm.is_running());
+    }
+
+    struct Cancelling;
+
+    impl State for Cancelling {
+        fn on_close_requested(&mut self, _: StateData) -> Trans {
+            Trans::Push(Box::new(State2))
+        }
+    }
+
+    #[test]
+    fn close_request_can_be_cancelled() {
+        use ecs::World;
+
+        let mut world = World::new();
+
+        let mut sm = StateMachine::new(Cancelling);
+        sm.start(StateData::new(&mut world, &mut ()));
+
+        // The pushed state uses the default and quits on the second request.
+        sm.close_requested(StateData::new(&mut world, &mut ()));
+        assert!(sm.is_running());
+        sm.close_requested(StateData::new(&mut world, &mut ()));
         assert!(!sm.is_running());
     }
+
+    struct Counting;
+
+    impl State<u32> for Counting {
+        fn update(&mut self, data: StateData<u32>) -> Trans<u32> {
+            *data.data += 1;
+            Trans::None
+        }
+    }
+
+    #[test]
+    fn data_is_given_to_states() {
+        use ecs::World;
+
+        let mut world = World::new();
+        let mut updates = 0;
+
+        let mut sm = StateMachine::new(Counting);
+        sm.start(StateData::new(&mut world, &mut updates));
+        sm.update(StateData::new(&mut world, &mut updates));
+        sm.update(StateData::new(&mut world, &mut updates));
+
+        assert_eq!(updates, 2);
+    }
 }